@@ -0,0 +1,99 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Times bulk parsing of interval values, and counts the allocations made
+//!
+//! Run with `cargo bench --bench parse_interval`.
+
+// The benchmarks are run on a current compiler, not the minimum supported one
+#![allow(clippy::incompatible_msrv)]
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+use sqlparser::ast::DateTimeField;
+use sqlparser::parser::Parser;
+
+const ITERATIONS: u32 = 1_000_000;
+
+/// The system allocator, counting every allocation
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Run `parse` `ITERATIONS` times and print how long one took on average
+fn time<T>(value: &str, label: &str, parse: impl Fn() -> T) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(parse());
+    }
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations;
+    println!(
+        "{:>30} {:<9} {:>8.1} ns/iter {:>5.1} allocs/iter",
+        format!("'{}'", value),
+        label,
+        elapsed.as_nanos() as f64 / f64::from(ITERATIONS),
+        allocations as f64 / f64::from(ITERATIONS)
+    );
+}
+
+fn main() {
+    let cases = [
+        ("1-2-3 4:5:6.789", DateTimeField::Year),
+        ("-100 12:34:56", DateTimeField::Day),
+        ("12:30", DateTimeField::Hour),
+        ("59.999999", DateTimeField::Second),
+        // single integers skip the tokenizer
+        ("30", DateTimeField::Second),
+        ("-5", DateTimeField::Hour),
+        // while these go through it
+        ("30.0", DateTimeField::Second),
+        ("-5:00", DateTimeField::Hour),
+    ];
+    for (value, field) in &cases {
+        time(value, &field.to_string(), || {
+            Parser::parse_interval_string(black_box(value), field).unwrap()
+        });
+    }
+
+    // timestamps are split into the date and time and the timezone first
+    let timestamps = [
+        "2020-01-02 03:04:05.678 +05:30",
+        "2020-01-02 03:04:05-08:00",
+        "2020-01-02 03:04:05Z",
+        "2020-01-02 UTC",
+        "2020-01-02 03:04:05",
+    ];
+    for value in &timestamps {
+        time(value, "TIMESTAMP", || {
+            Parser::parse_timestamp_string(black_box(value), true).unwrap()
+        });
+    }
+}
@@ -11,7 +11,9 @@
 // limitations under the License.
 
 use super::ObjectName;
-use std::fmt;
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+use core::fmt;
 
 /// SQL data types
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
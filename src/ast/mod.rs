@@ -28,6 +28,9 @@ pub mod visit {
     // Disable lints that want us to rewrite `&Ident` as `&str`, as `&str` is not as
     // self-documenting as &Ident.
     #![allow(clippy::ptr_arg)]
+    #[cfg(not(feature = "std"))]
+    use alloc::{boxed::Box, string::String, vec::Vec};
+
     make_visitor!(Visit: &);
 }
 
@@ -35,10 +38,20 @@ pub mod visit_mut {
     // See justification for these attributes in the `visit` module.
     #![allow(clippy::too_many_arguments)]
     #![allow(clippy::ptr_arg)]
+    #[cfg(not(feature = "std"))]
+    use alloc::{boxed::Box, string::String, vec::Vec};
+
     make_visitor!(VisitMut: &mut);
 }
 
-use std::fmt;
+#[cfg(not(feature = "std"))]
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
 
 pub use self::data_type::DataType;
 pub use self::ddl::{
@@ -49,10 +62,14 @@ pub use self::query::{
     Cte, Fetch, Join, JoinConstraint, JoinOperator, OrderByExpr, Query, Select, SelectItem,
     SetExpr, SetOperator, TableAlias, TableFactor, TableWithJoins, Values,
 };
+#[cfg(feature = "arrow")]
+pub use self::value::IntervalMonthDayNano;
 pub use self::value::{
-    DateTimeField, ExtractField, Interval, IntervalValue, ParsedDate, ParsedDateTime,
-    ParsedTimestamp, Value,
+    CalendarKind, DateTimeField, DateTimeOrSpecial, DateTimeSpecial, ExtractField, Interval,
+    IntervalParts, IntervalStyle, IntervalValue, MonthPolicy, ParsedDate, ParsedDateTime,
+    ParsedDateTimeBuilder, ParsedTimestamp, PartSigns, Value,
 };
+pub(crate) use self::value::{DATE_TIME_FIELD_KEYWORDS, MAX_TIMEZONE_OFFSET_MINUTES};
 
 struct DisplaySeparated<'a, T>
 where
@@ -1066,7 +1083,7 @@ impl fmt::Display for FileFormat {
 }
 
 use crate::parser::ParserError;
-use std::str::FromStr;
+use core::str::FromStr;
 impl FromStr for FileFormat {
     type Err = ParserError;
 
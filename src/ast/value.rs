@@ -10,19 +10,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
 #[cfg(feature = "bigdecimal")]
 use bigdecimal::BigDecimal;
-use std::fmt;
+use core::fmt;
 
 mod datetime;
+#[cfg(feature = "arrow")]
+pub use datetime::IntervalMonthDayNano;
 pub use datetime::{
-    DateTimeField, ExtractField, Interval, IntervalValue, ParsedDate, ParsedDateTime,
-    ParsedTimestamp,
+    CalendarKind, DateTimeField, DateTimeOrSpecial, DateTimeSpecial, ExtractField, Interval,
+    IntervalParts, IntervalStyle, IntervalValue, MonthPolicy, ParsedDate, ParsedDateTime,
+    ParsedDateTimeBuilder, ParsedTimestamp, PartSigns,
 };
+pub(crate) use datetime::{DATE_TIME_FIELD_KEYWORDS, MAX_TIMEZONE_OFFSET_MINUTES};
 
 #[derive(Debug)]
 pub struct ValueError(String);
 
+#[cfg(feature = "std")]
 impl std::error::Error for ValueError {}
 
 impl fmt::Display for ValueError {
@@ -173,6 +180,9 @@ pub fn escape_single_quote_string(s: &str) -> EscapeSingleQuoteString<'_> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::parser::{Parser, ParserError};
+    #[cfg(not(feature = "std"))]
+    use alloc::string::ToString;
 
     /// An extremely default interval value
     fn ivalue() -> IntervalValue {
@@ -204,4 +214,1555 @@ mod test {
             vec![Month, Day, Hour, Minute, Second]
         )
     }
+
+    #[test]
+    fn datetimefield_from_keyword() {
+        use DateTimeField::*;
+        for (field, spellings) in &[
+            (Year, &["year", "years", "yr", "yrs"]),
+            (Quarter, &["quarter", "quarters", "qtr", "qtrs"]),
+            (Month, &["month", "months", "mon", "mons"]),
+            (Hour, &["hour", "hours", "hr", "hrs"]),
+            (Minute, &["minute", "minutes", "min", "mins"]),
+            (Second, &["second", "seconds", "sec", "secs"]),
+        ] {
+            for spelling in spellings.iter() {
+                assert_eq!(DateTimeField::from_keyword(spelling), Some(field.clone()));
+                assert_eq!(
+                    DateTimeField::from_keyword(&spelling.to_uppercase()),
+                    Some(field.clone())
+                );
+            }
+        }
+        assert_eq!(DateTimeField::from_keyword("Day"), Some(Day));
+        assert_eq!(DateTimeField::from_keyword("DAYS"), Some(Day));
+        assert_eq!(DateTimeField::from_keyword("week"), Some(Week));
+        assert_eq!(DateTimeField::from_keyword("Weeks"), Some(Week));
+        for word in &["", "d", "fortnight", "wk", "years_", "EPOCH"] {
+            assert_eq!(
+                DateTimeField::from_keyword(word),
+                None,
+                "looking up {}",
+                word
+            );
+        }
+    }
+
+    #[test]
+    fn parsed_datetime_fractional_and_whole_parts() {
+        let pdt = Parser::parse_interval_string("1.5", &DateTimeField::Second).unwrap();
+        assert_eq!(
+            pdt.fractional_part(),
+            ParsedDateTime {
+                nano: Some(500_000_000),
+                nano_digits: Some(1),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            pdt.whole_part(),
+            ParsedDateTime {
+                second: Some(1),
+                ..Default::default()
+            }
+        );
+
+        let pdt = Parser::parse_interval_string("-1.5", &DateTimeField::Second).unwrap();
+        assert!(!pdt.fractional_part().is_positive);
+        assert!(!pdt.whole_part().is_positive);
+    }
+
+    #[test]
+    fn parsed_datetime_split() {
+        let pdt = Parser::parse_interval_string("-1-2-3 4:05:06.7", &DateTimeField::Year).unwrap();
+        let (date, time) = pdt.split();
+        assert_eq!(
+            date,
+            ParsedDateTime {
+                is_positive: false,
+                year: Some(1),
+                month: Some(2),
+                day: Some(3),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            time,
+            ParsedDateTime {
+                is_positive: false,
+                hour: Some(4),
+                minute: Some(5),
+                second: Some(6),
+                nano: Some(700_000_000),
+                nano_digits: Some(1),
+                ..Default::default()
+            }
+        );
+        let recombined = ParsedDateTime {
+            year: date.year,
+            month: date.month,
+            day: date.day,
+            ..time.clone()
+        };
+        assert_eq!(recombined, pdt);
+        assert_eq!((date + time).unwrap(), pdt);
+
+        // the timezone goes with the time
+        let pdt = Parser::parse_timestamp_string("2020-01-02 03:04:05 +05:30", true).unwrap();
+        let (date, time) = pdt.split();
+        assert_eq!(date.timezone_offset_second, None);
+        assert_eq!(time.timezone_offset_second, pdt.timezone_offset_second);
+        assert_eq!((date.year, time.hour), (Some(2020), Some(3)));
+    }
+
+    #[test]
+    fn parsed_datetime_normalize() {
+        let pdt = Parser::parse_interval_string("90", &DateTimeField::Second).unwrap();
+        assert_eq!(
+            pdt.normalize(),
+            ParsedDateTime {
+                minute: Some(1),
+                second: Some(30),
+                ..Default::default()
+            }
+        );
+
+        let pdt = Parser::parse_interval_string("-3600", &DateTimeField::Second).unwrap();
+        assert_eq!(
+            pdt.normalize(),
+            ParsedDateTime {
+                is_positive: false,
+                hour: Some(1),
+                minute: Some(0),
+                second: Some(0),
+                ..Default::default()
+            }
+        );
+
+        let pdt = ParsedDateTime {
+            second: Some(59),
+            nano: Some(1_500_000_000),
+            ..Default::default()
+        };
+        assert_eq!(
+            pdt.normalize(),
+            ParsedDateTime {
+                minute: Some(1),
+                second: Some(0),
+                nano: Some(500_000_000),
+                ..Default::default()
+            }
+        );
+
+        // years, months and days are never touched
+        let pdt = Parser::parse_interval_string("1-14-40 70:00", &DateTimeField::Year).unwrap();
+        assert_eq!(
+            pdt.normalize(),
+            ParsedDateTime {
+                year: Some(1),
+                month: Some(14),
+                day: Some(40),
+                hour: Some(70),
+                minute: Some(0),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn parsed_datetime_semantically_eq() {
+        let parse = |value, field| Parser::parse_interval_string(value, &field).unwrap();
+        for (a, a_field, b, b_field) in &[
+            (
+                "90 seconds",
+                DateTimeField::Second,
+                "1:30",
+                DateTimeField::Minute,
+            ),
+            ("1:30", DateTimeField::Minute, "0:1:30", DateTimeField::Hour),
+            ("1", DateTimeField::Year, "12", DateTimeField::Month),
+            ("3600", DateTimeField::Second, "1", DateTimeField::Hour),
+            ("-0", DateTimeField::Day, "0", DateTimeField::Hour),
+        ] {
+            let (a, b) = (parse(a, a_field.clone()), parse(b, b_field.clone()));
+            assert_ne!(a, b);
+            assert!(a.semantically_eq(&b), "{:?} and {:?} should be equal", a, b);
+            assert!(b.semantically_eq(&a));
+        }
+        for (a, a_field, b, b_field) in &[
+            ("1", DateTimeField::Day, "24", DateTimeField::Hour),
+            ("1", DateTimeField::Month, "30", DateTimeField::Day),
+            ("1", DateTimeField::Second, "-1", DateTimeField::Second),
+            ("1.5", DateTimeField::Second, "1.05", DateTimeField::Second),
+        ] {
+            let (a, b) = (parse(a, a_field.clone()), parse(b, b_field.clone()));
+            assert!(!a.semantically_eq(&b), "{:?} and {:?} should differ", a, b);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn parsed_datetime_hash_set() {
+        use std::collections::HashSet;
+
+        let parse = |value, field| Parser::parse_interval_string(value, &field).unwrap();
+        let mut set = HashSet::new();
+        // the same fields, written two ways
+        assert!(set.insert(parse("1 day", DateTimeField::Day)));
+        assert!(!set.insert(parse("P1D", DateTimeField::Day)));
+        assert!(!set.insert(parse("1", DateTimeField::Day)));
+        assert_eq!(set.len(), 1);
+        // the digits of a fraction are only for display
+        assert!(set.insert(parse("1.5 second", DateTimeField::Second)));
+        assert!(!set.insert(parse("1.50 second", DateTimeField::Second)));
+        assert_eq!(set.len(), 2);
+
+        // the same interval, but with different fields
+        let seconds = parse("90 seconds", DateTimeField::Second);
+        let minutes = parse("1:30", DateTimeField::Minute);
+        assert!(seconds.semantically_eq(&minutes));
+        assert!(set.insert(seconds.clone()));
+        assert!(set.insert(minutes.clone()));
+        assert_eq!(set.len(), 4);
+        // unless they are normalized first
+        let mut normalized = HashSet::new();
+        normalized.insert(seconds.normalize());
+        assert!(!normalized.insert(minutes.normalize()));
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn parsed_datetime_between() {
+        use chrono::NaiveDate;
+
+        let ts = |year, month, day, hour, minute, second| {
+            NaiveDate::from_ymd_opt(year, month, day)
+                .unwrap()
+                .and_hms_opt(hour, minute, second)
+                .unwrap()
+        };
+        let between = |start, end| ParsedDateTime::between(&start, &end).unwrap();
+        let interval = |year, month, day, hour, minute, second| ParsedDateTime {
+            year: Some(year),
+            month: Some(month),
+            day: Some(day),
+            hour: Some(hour),
+            minute: Some(minute),
+            second: Some(second),
+            ..Default::default()
+        };
+
+        // the examples from PostgreSQL's documentation and its `age()` output
+        assert_eq!(
+            between(ts(1957, 6, 13, 0, 0, 0), ts(2001, 4, 10, 0, 0, 0)),
+            interval(43, 9, 27, 0, 0, 0)
+        );
+        assert_eq!(
+            between(ts(2020, 1, 1, 8, 30, 0), ts(2020, 1, 1, 12, 0, 0)),
+            interval(0, 0, 0, 3, 30, 0)
+        );
+        assert_eq!(
+            between(ts(2020, 1, 31, 0, 0, 0), ts(2020, 3, 1, 0, 0, 0)),
+            interval(0, 1, 1, 0, 0, 0)
+        );
+        // the borrowed month is February of a leap year
+        assert_eq!(
+            between(ts(2020, 2, 28, 12, 0, 0), ts(2020, 3, 1, 0, 0, 0)),
+            interval(0, 0, 1, 12, 0, 0)
+        );
+        assert_eq!(
+            between(ts(2019, 12, 31, 23, 59, 59), ts(2020, 1, 1, 0, 0, 0)),
+            interval(0, 0, 0, 0, 0, 1)
+        );
+
+        // reversed, like `age('2020-01-31', '2020-03-01')` is `-1 mons -1 days`
+        assert_eq!(
+            between(ts(2020, 3, 1, 0, 0, 0), ts(2020, 1, 31, 0, 0, 0)),
+            ParsedDateTime {
+                is_positive: false,
+                ..interval(0, 1, 1, 0, 0, 0)
+            }
+        );
+        assert!(between(ts(2020, 1, 1, 0, 0, 0), ts(2020, 1, 1, 0, 0, 0)).is_zero());
+
+        let with_nanos = NaiveDate::from_ymd_opt(2020, 1, 1)
+            .unwrap()
+            .and_hms_nano_opt(0, 0, 0, 500_000_000)
+            .unwrap();
+        assert_eq!(
+            between(with_nanos, ts(2020, 1, 1, 0, 0, 2)),
+            ParsedDateTime {
+                nano: Some(500_000_000),
+                ..interval(0, 0, 0, 0, 0, 1)
+            }
+        );
+        // a leap second is the 60th second of its minute
+        let leap = NaiveDate::from_ymd_opt(2016, 12, 31)
+            .unwrap()
+            .and_hms_nano_opt(23, 59, 59, 1_000_000_000)
+            .unwrap();
+        assert_eq!(
+            between(ts(2016, 12, 31, 23, 59, 0), leap),
+            interval(0, 0, 0, 0, 0, 60)
+        );
+
+        let last_month = NaiveDate::MAX.and_hms_opt(0, 0, 0).unwrap();
+        assert!(ParsedDateTime::between(&last_month, &last_month).is_err());
+    }
+
+    #[test]
+    fn parsed_datetime_scale() {
+        let parse = |value, field| Parser::parse_interval_string(value, &field).unwrap();
+        let scaled = (parse("1:30", DateTimeField::Hour) * 4).unwrap();
+        assert_eq!((scaled.hour, scaled.minute), (Some(4), Some(120)));
+        assert_eq!(scaled.normalize(), parse("6:00", DateTimeField::Hour));
+        assert_eq!(
+            (parse("1 day", DateTimeField::Day) * 7).unwrap(),
+            parse("7 days", DateTimeField::Day)
+        );
+        assert_eq!(
+            parse("1-2", DateTimeField::Year).scale(3).unwrap(),
+            parse("3-6", DateTimeField::Year)
+        );
+        // fractional seconds carry into the seconds
+        assert_eq!(
+            parse("1.5", DateTimeField::Second)
+                .scale(3)
+                .map(|pdt| (pdt.second, pdt.nano))
+                .unwrap(),
+            (Some(4), Some(500_000_000))
+        );
+
+        // a negative factor flips the sign, and zero is positive
+        assert_eq!(
+            (parse("1 02:03", DateTimeField::Day) * -2).unwrap(),
+            parse("-2 04:06", DateTimeField::Day)
+        );
+        assert_eq!(
+            (parse("-1 02:03", DateTimeField::Day) * -2).unwrap(),
+            parse("2 04:06", DateTimeField::Day)
+        );
+        let zero = (parse("-1 02:03", DateTimeField::Day) * 0).unwrap();
+        assert!(zero.is_positive && zero.is_zero());
+
+        let huge = ParsedDateTime {
+            day: Some(u64::MAX / 2),
+            ..Default::default()
+        };
+        assert!(huge.scale(3).is_err());
+        assert!((huge * i64::MIN).is_err());
+    }
+
+    #[test]
+    fn parsed_datetime_negate() {
+        let parse = |value, field| Parser::parse_interval_string(value, &field).unwrap();
+        let positive = parse("1 02:03", DateTimeField::Day);
+        let negative = parse("-1 02:03", DateTimeField::Day);
+        assert_eq!(positive.clone().negate(), negative);
+        assert_eq!(-negative.clone(), positive);
+        assert_eq!(-(-positive.clone()), positive);
+
+        let zero = parse("0", DateTimeField::Second);
+        assert_eq!(-zero.clone(), zero);
+        assert!(zero.is_positive);
+        let negative_zero = ParsedDateTime {
+            is_positive: false,
+            ..zero.clone()
+        };
+        assert_eq!(-negative_zero, zero);
+
+        let with_offset = ParsedDateTime {
+            hour: Some(1),
+            timezone_offset_second: Some(19_800),
+            ..Default::default()
+        };
+        assert_eq!(
+            -with_offset,
+            ParsedDateTime {
+                is_positive: false,
+                hour: Some(1),
+                timezone_offset_second: Some(-19_800),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn extract_field_indexing_base() {
+        let base = |field: &str| field.parse::<ExtractField>().unwrap().indexing_base();
+        assert_eq!(base("dow"), Some((0, 0..=6)));
+        assert_eq!(base("isodow"), Some((1, 1..=7)));
+        assert_eq!(base("doy"), Some((1, 1..=366)));
+        assert_eq!(base("month"), Some((1, 1..=12)));
+        assert_eq!(base("hour"), Some((0, 0..=23)));
+        assert_eq!(base("epoch"), None);
+        assert_eq!(base("year"), None);
+    }
+
+    #[test]
+    fn parsed_datetime_cmp_approx() {
+        use core::cmp::Ordering;
+
+        let day = Parser::parse_interval_string("1", &DateTimeField::Day).unwrap();
+        let hours = Parser::parse_interval_string("25", &DateTimeField::Hour).unwrap();
+        assert_eq!(day.cmp_approx(&hours), Ordering::Less);
+        assert_eq!(hours.cmp_approx(&day), Ordering::Greater);
+
+        let hours = Parser::parse_interval_string("24", &DateTimeField::Hour).unwrap();
+        assert_eq!(day.cmp_approx(&hours), Ordering::Equal);
+        assert_ne!(day, hours);
+
+        let positive = Parser::parse_interval_string("5", &DateTimeField::Minute).unwrap();
+        let negative = Parser::parse_interval_string("-5", &DateTimeField::Minute).unwrap();
+        assert_eq!(negative.cmp_approx(&positive), Ordering::Less);
+        assert_eq!(positive.cmp_approx(&negative), Ordering::Greater);
+
+        let zero = Parser::parse_interval_string("0", &DateTimeField::Minute).unwrap();
+        let negative_zero = Parser::parse_interval_string("-0", &DateTimeField::Minute).unwrap();
+        assert_eq!(negative_zero.cmp_approx(&zero), Ordering::Equal);
+
+        let month = Parser::parse_interval_string("1", &DateTimeField::Month).unwrap();
+        let days = Parser::parse_interval_string("30", &DateTimeField::Day).unwrap();
+        assert_eq!(month.cmp_approx(&days), Ordering::Equal);
+    }
+
+    #[test]
+    fn parsed_datetime_dominant_component() {
+        let dominant = |value: &str, field: DateTimeField| {
+            Parser::parse_interval_string(value, &field)
+                .unwrap()
+                .dominant_component()
+        };
+        assert_eq!(
+            dominant("1:45:00", DateTimeField::Hour),
+            Some((DateTimeField::Hour, 2))
+        );
+        assert_eq!(
+            dominant("1:29:59", DateTimeField::Hour),
+            Some((DateTimeField::Hour, 1))
+        );
+        assert_eq!(
+            dominant("1:30:00", DateTimeField::Hour),
+            Some((DateTimeField::Hour, 2))
+        );
+        assert_eq!(
+            dominant("0:20:00", DateTimeField::Hour),
+            Some((DateTimeField::Minute, 20))
+        );
+        assert_eq!(
+            dominant("-150", DateTimeField::Second),
+            Some((DateTimeField::Minute, 3))
+        );
+        assert_eq!(
+            dominant("2 12:00", DateTimeField::Day),
+            Some((DateTimeField::Day, 3))
+        );
+        assert_eq!(dominant("0.5", DateTimeField::Second), None);
+        assert_eq!(dominant("0:00:00", DateTimeField::Hour), None);
+        assert_eq!(dominant("1-2", DateTimeField::Year), None);
+        assert_eq!(ParsedDateTime::default().dominant_component(), None);
+    }
+
+    #[test]
+    fn parsed_datetime_floor_and_ceil_to() {
+        let pdt = Parser::parse_interval_string("1:45:00", &DateTimeField::Hour).unwrap();
+        let hours = |hour| ParsedDateTime {
+            hour: Some(hour),
+            ..Default::default()
+        };
+        assert_eq!(pdt.floor_to(DateTimeField::Hour), hours(1));
+        assert_eq!(pdt.ceil_to(DateTimeField::Hour), hours(2));
+
+        // finer fields are folded in rather than zeroed
+        let pdt = Parser::parse_interval_string("1:90:00", &DateTimeField::Hour).unwrap();
+        assert_eq!(pdt.floor_to(DateTimeField::Hour), hours(2));
+
+        // an exact value rounds to itself either way
+        let pdt = Parser::parse_interval_string("2:00:00", &DateTimeField::Hour).unwrap();
+        assert_eq!(pdt.floor_to(DateTimeField::Hour), hours(2));
+        assert_eq!(pdt.ceil_to(DateTimeField::Hour), hours(2));
+
+        let pdt = Parser::parse_interval_string("-1:45:00", &DateTimeField::Hour).unwrap();
+        let negative_hours = |hour| ParsedDateTime {
+            is_positive: false,
+            ..hours(hour)
+        };
+        assert_eq!(pdt.floor_to(DateTimeField::Hour), negative_hours(2));
+        assert_eq!(pdt.ceil_to(DateTimeField::Hour), negative_hours(1));
+
+        // coarser fields are kept, and fractional seconds count
+        let pdt = Parser::parse_interval_string("1 0:0:59.5", &DateTimeField::Day).unwrap();
+        let expected = ParsedDateTime {
+            day: Some(1),
+            hour: Some(0),
+            minute: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(pdt.ceil_to(DateTimeField::Minute), expected);
+
+        // days are folded into months as 30 days each
+        let pdt = Parser::parse_interval_string("1-1-45", &DateTimeField::Year).unwrap();
+        let expected = ParsedDateTime {
+            year: Some(1),
+            month: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(pdt.floor_to(DateTimeField::Month), expected);
+    }
+
+    #[cfg(feature = "arrow")]
+    #[test]
+    fn interval_month_day_nano_from_parsed_datetime() {
+        use core::convert::TryFrom;
+
+        let arrow = |value, field| {
+            IntervalMonthDayNano::try_from(Parser::parse_interval_string(value, &field).unwrap())
+        };
+        let arrow_unqualified = |value| {
+            IntervalMonthDayNano::try_from(
+                Parser::parse_unqualified_interval_string(value).unwrap(),
+            )
+        };
+        assert_eq!(
+            arrow("1-2-3 4:05:06.7000009", DateTimeField::Year).unwrap(),
+            IntervalMonthDayNano {
+                months: 14,
+                days: 3,
+                nanoseconds: ((4 * 60 + 5) * 60 + 6) * 1_000_000_000 + 700_000_900,
+            }
+        );
+        assert_eq!(
+            arrow_unqualified("-1 day 2.5 seconds").unwrap(),
+            IntervalMonthDayNano {
+                months: 0,
+                days: -1,
+                nanoseconds: 2_500_000_000,
+            }
+        );
+        assert_eq!(
+            arrow_unqualified("-1 day -2.5 seconds").unwrap(),
+            IntervalMonthDayNano {
+                months: 0,
+                days: -1,
+                nanoseconds: -2_500_000_000,
+            }
+        );
+
+        let too_many_days = ParsedDateTime {
+            day: Some(i32::MAX as u64 + 1),
+            ..Default::default()
+        };
+        assert!(IntervalMonthDayNano::try_from(too_many_days).is_err());
+        let fewest_days = ParsedDateTime {
+            is_positive: false,
+            day: Some(i32::MAX as u64 + 1),
+            ..Default::default()
+        };
+        assert_eq!(
+            IntervalMonthDayNano::try_from(fewest_days).unwrap().days,
+            i32::MIN
+        );
+        let too_many_months = ParsedDateTime {
+            year: Some(i32::MAX as u64 / 12 + 1),
+            ..Default::default()
+        };
+        assert_eq!(
+            IntervalMonthDayNano::try_from(too_many_months).unwrap_err(),
+            ParserError::ParserError(format!(
+                "interval {} years has too many months for an Arrow interval",
+                i32::MAX / 12 + 1
+            ))
+        );
+    }
+
+    #[test]
+    fn interval_parts_from_parsed_datetime() {
+        use core::convert::TryFrom;
+
+        let parts = |value, field| {
+            IntervalParts::try_from(Parser::parse_interval_string(value, &field).unwrap())
+        };
+        assert_eq!(
+            parts("1-2", DateTimeField::Year).unwrap(),
+            IntervalParts {
+                months: 14,
+                days: 0,
+                micros: 0,
+            }
+        );
+        assert_eq!(
+            parts("3 4:05:06.7000009", DateTimeField::Day).unwrap(),
+            IntervalParts {
+                months: 0,
+                days: 3,
+                micros: ((4 * 60 + 5) * 60 + 6) * 1_000_000 + 700_000,
+            }
+        );
+        assert_eq!(
+            parts("-1-2-3 4:05:06", DateTimeField::Year).unwrap(),
+            IntervalParts {
+                months: -14,
+                days: -3,
+                micros: -((4 * 60 + 5) * 60 + 6) * 1_000_000,
+            }
+        );
+        assert_eq!(
+            parts("0", DateTimeField::Second).unwrap(),
+            IntervalParts::default()
+        );
+
+        let huge = ParsedDateTime {
+            hour: Some(u64::MAX / 1_000_000),
+            ..Default::default()
+        };
+        assert!(IntervalParts::try_from(huge).is_err());
+        let huge = ParsedDateTime {
+            day: Some(i64::MAX as u64 + 1),
+            ..Default::default()
+        };
+        assert!(IntervalParts::try_from(huge).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn parsed_datetime_resolve_against() {
+        use chrono::NaiveDate;
+        use core::time::Duration;
+
+        let date = |year, month, day| NaiveDate::from_ymd_opt(year, month, day).unwrap();
+        let parse = |value| Parser::parse_unqualified_interval_string(value).unwrap();
+        let days = |days: u64| Duration::from_secs(days * 24 * 60 * 60);
+
+        let month = parse("1 month");
+        assert_eq!(month.resolve_against(&date(2021, 2, 1)).unwrap(), days(28));
+        assert_eq!(month.resolve_against(&date(2024, 2, 1)).unwrap(), days(29));
+        assert_eq!(month.resolve_against(&date(2021, 1, 1)).unwrap(), days(31));
+        // landing past the end of february clamps to its last day
+        assert_eq!(month.resolve_against(&date(2023, 1, 31)).unwrap(), days(28));
+        assert_eq!(month.resolve_against(&date(2024, 1, 31)).unwrap(), days(29));
+        assert_eq!(
+            parse("1 year").resolve_against(&date(2024, 2, 29)).unwrap(),
+            days(365)
+        );
+        assert_eq!(
+            parse("4 years")
+                .resolve_against(&date(2024, 2, 29))
+                .unwrap(),
+            days(4 * 365 + 1)
+        );
+        // negative intervals count backwards
+        assert_eq!(
+            parse("-1 month")
+                .resolve_against(&date(2024, 3, 31))
+                .unwrap(),
+            days(31)
+        );
+        assert_eq!(
+            parse("1 month 1 day 2.5 seconds")
+                .resolve_against(&date(2023, 2, 10))
+                .unwrap(),
+            days(29) + Duration::from_millis(2_500)
+        );
+        assert!(month.resolve_against(&NaiveDate::MAX).is_err());
+        assert!(parse("-1 month").resolve_against(&NaiveDate::MIN).is_err());
+    }
+
+    #[test]
+    fn parsed_datetime_month_policy() {
+        use core::cmp::Ordering;
+        use core::time::Duration;
+
+        let parse = |value| Parser::parse_unqualified_interval_string(value).unwrap();
+        let days = |days: u64| Duration::from_secs(days * 24 * 60 * 60);
+
+        let month = parse("1 month");
+        assert_eq!(month.to_duration(&MonthPolicy::Days30).unwrap(), days(30));
+        assert_eq!(
+            parse("-1 year -1.5 seconds")
+                .to_duration(&MonthPolicy::Days30)
+                .unwrap(),
+            days(360) + Duration::from_millis(1_500)
+        );
+
+        let days_29 = parse("29 days");
+        assert_eq!(
+            month.cmp_with(&days_29, &MonthPolicy::Days30).unwrap(),
+            Ordering::Greater
+        );
+        assert_eq!(
+            month.cmp_with(&days_29, &MonthPolicy::Days30).unwrap(),
+            month.cmp_approx(&days_29)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn parsed_datetime_month_policy_anchor_date() {
+        use chrono::NaiveDate;
+        use core::cmp::Ordering;
+        use core::time::Duration;
+
+        let parse = |value| Parser::parse_unqualified_interval_string(value).unwrap();
+        let days = |days: u64| Duration::from_secs(days * 24 * 60 * 60);
+        let february = MonthPolicy::AnchorDate(NaiveDate::from_ymd_opt(2023, 2, 1).unwrap());
+
+        let month = parse("1 month");
+        assert_eq!(month.to_duration(&february).unwrap(), days(28));
+        assert_eq!(
+            month.cmp_with(&parse("29 days"), &february).unwrap(),
+            Ordering::Less
+        );
+        // counting backwards from february 1st goes through january
+        assert_eq!(
+            parse("-1 month")
+                .cmp_with(&parse("-30 days"), &february)
+                .unwrap(),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn parsed_datetime_resolve_days_against() {
+        let date = |year, month, day| ParsedDate { year, month, day };
+        let days = |pdt: &ParsedDateTime, start: &ParsedDate, calendar| {
+            pdt.resolve_days_against(start, calendar).unwrap().day
+        };
+        let month = Parser::parse_interval_string("1 month", &DateTimeField::Month).unwrap();
+        let jan = date(2021, 1, 15);
+        assert_eq!(days(&month, &jan, CalendarKind::Gregorian), Some(31));
+        assert_eq!(days(&month, &jan, CalendarKind::Financial360), Some(30));
+        assert_eq!(days(&month, &jan, CalendarKind::Fixed365), Some(31));
+
+        let feb = date(2020, 2, 1);
+        assert_eq!(days(&month, &feb, CalendarKind::Gregorian), Some(29));
+        assert_eq!(days(&month, &feb, CalendarKind::Financial360), Some(30));
+        assert_eq!(days(&month, &feb, CalendarKind::Fixed365), Some(28));
+
+        // the end of the month is clamped
+        assert_eq!(
+            days(&month, &date(2020, 1, 31), CalendarKind::Gregorian),
+            Some(29)
+        );
+
+        // negative intervals count backwards, and the time is kept
+        let pdt = Parser::parse_interval_string("-1-1-2 3:00:00", &DateTimeField::Year).unwrap();
+        let resolved = pdt
+            .resolve_days_against(&date(2021, 3, 1), CalendarKind::Gregorian)
+            .unwrap();
+        let expected = ParsedDateTime {
+            is_positive: false,
+            day: Some(29 + 365 + 2),
+            hour: Some(3),
+            minute: Some(0),
+            second: Some(0),
+            ..Default::default()
+        };
+        assert_eq!(resolved, expected);
+        assert_eq!(
+            days(
+                &Parser::parse_interval_string("1", &DateTimeField::Year).unwrap(),
+                &jan,
+                CalendarKind::Financial360
+            ),
+            Some(360)
+        );
+
+        assert!(month
+            .resolve_days_against(&date(2021, 2, 29), CalendarKind::Gregorian)
+            .is_err());
+        assert!(month
+            .resolve_days_against(&date(2021, 2, 30), CalendarKind::Financial360)
+            .is_ok());
+    }
+
+    #[test]
+    fn parsed_datetime_timezone_offset() {
+        let pdt = Parser::parse_timestamp_string("2020-01-01 10:00:00 -05:30", true).unwrap();
+        assert!(pdt.has_timezone());
+        assert_eq!(
+            pdt.timezone_offset_seconds(),
+            Some(-(5 * 60 * 60 + 30 * 60))
+        );
+        let pdt = Parser::parse_timestamp_string("2020-01-01 10:00:00 UTC", true).unwrap();
+        assert!(pdt.has_timezone());
+        assert_eq!(pdt.timezone_offset_seconds(), Some(0));
+
+        let pdt = Parser::parse_interval_string("1 10:00:00", &DateTimeField::Day).unwrap();
+        assert!(!pdt.has_timezone());
+        assert_eq!(pdt.timezone_offset_seconds(), None);
+        let pdt = Parser::parse_timestamp_string("2020-01-01 10:00:00", true).unwrap();
+        assert!(!pdt.has_timezone());
+    }
+
+    #[test]
+    fn parsed_datetime_approx_total_seconds() {
+        let seconds = |value, field| {
+            Parser::parse_interval_string(value, &field)
+                .unwrap()
+                .approx_total_seconds()
+        };
+        for (value, field, expected) in &[
+            ("1 2:03:04.5", DateTimeField::Day, 93_784.5),
+            ("1", DateTimeField::Year, 31_536_000.0),
+            ("1", DateTimeField::Month, 2_592_000.0),
+            ("-1-6", DateTimeField::Year, -47_088_000.0),
+            ("-1.25", DateTimeField::Second, -1.25),
+            ("0.000000001", DateTimeField::Second, 1e-9),
+            ("0", DateTimeField::Second, 0.0),
+        ] {
+            let actual = seconds(value, field.clone());
+            assert!(
+                (actual - expected).abs() < 1e-12,
+                "{} {} is {} seconds, not {}",
+                value,
+                field,
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn parsed_datetime_apply_timezone_to() {
+        use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone};
+
+        let timestamp = |day, hour, minute, offset_second| -> DateTime<FixedOffset> {
+            let local = NaiveDate::from_ymd_opt(2020, 1, day)
+                .unwrap()
+                .and_hms_nano_opt(hour, minute, 0, 500_000_000)
+                .unwrap();
+            FixedOffset::east_opt(offset_second)
+                .unwrap()
+                .from_local_datetime(&local)
+                .unwrap()
+        };
+        let zone = |value| Parser::parse_timestamp_string(value, true).unwrap();
+        // a timestamp without a timezone, taken to be at UTC
+        let naive = timestamp(1, 22, 0, 0);
+
+        let india = zone("2000-01-01 00:00:00 +05:30");
+        let moved = india.apply_timezone_to(&naive).unwrap();
+        assert_eq!(moved.to_string(), "2020-01-02 03:30:00.500 +05:30");
+        assert_eq!(moved, timestamp(2, 3, 30, 19_800));
+        let pacific = ParsedDateTime::default()
+            .with_timezone_minutes(-480)
+            .unwrap();
+        assert_eq!(
+            pacific.apply_timezone_to(&naive).unwrap().to_string(),
+            "2020-01-01 14:00:00.500 -08:00"
+        );
+        // a zoned timestamp keeps its instant
+        let zoned = timestamp(1, 9, 30, -28_800);
+        assert_eq!(
+            india.apply_timezone_to(&zoned).unwrap().to_string(),
+            "2020-01-01 23:00:00.500 +05:30"
+        );
+        assert_eq!(pacific.apply_timezone_to(&zoned).unwrap(), zoned);
+
+        assert!(ParsedDateTime::default().apply_timezone_to(&naive).is_err());
+    }
+
+    #[test]
+    fn parsed_datetime_to_utc() {
+        let timestamp = |value| Parser::parse_timestamp_string(value, true).unwrap();
+        assert_eq!(
+            timestamp("2020-06-15 12:00:00 +05:30").to_utc().unwrap(),
+            timestamp("2020-06-15 06:30:00")
+        );
+        assert_eq!(
+            timestamp("2020-06-15 12:00:00.25 -05:30").to_utc().unwrap(),
+            timestamp("2020-06-15 17:30:00.25")
+        );
+        // across a day, a month and a year
+        assert_eq!(
+            timestamp("2020-06-15 02:00:00 +05:30").to_utc().unwrap(),
+            timestamp("2020-06-14 20:30:00")
+        );
+        assert_eq!(
+            timestamp("2020-03-01 02:00:00 +05:30").to_utc().unwrap(),
+            timestamp("2020-02-29 20:30:00")
+        );
+        assert_eq!(
+            timestamp("2020-01-01 02:00:00 +05:30").to_utc().unwrap(),
+            timestamp("2019-12-31 20:30:00")
+        );
+        assert_eq!(
+            timestamp("2019-12-31 20:00:00 -05:30").to_utc().unwrap(),
+            timestamp("2020-01-01 01:30:00")
+        );
+        assert_eq!(
+            timestamp("2020-06-15 02:00:00").to_utc().unwrap(),
+            timestamp("2020-06-15 02:00:00")
+        );
+
+        let plus_0530 = Some(5 * 60 * 60 + 30 * 60);
+        let time = |day, hour, minute, timezone_offset_second| ParsedDateTime {
+            day,
+            hour: Some(hour),
+            minute: Some(minute),
+            second: Some(0),
+            timezone_offset_second,
+            ..Default::default()
+        };
+        assert_eq!(
+            time(Some(1), 2, 0, plus_0530).to_utc().unwrap(),
+            time(Some(0), 20, 30, None)
+        );
+        assert_eq!(
+            time(None, 2, 0, plus_0530).to_utc().unwrap(),
+            time(None, 20, 30, None)
+        );
+        assert!(time(Some(0), 2, 0, plus_0530).to_utc().is_err());
+        let negative = ParsedDateTime {
+            is_positive: false,
+            ..time(None, 2, 0, plus_0530)
+        };
+        assert!(negative.to_utc().is_err());
+    }
+
+    #[test]
+    fn parsed_datetime_with_timezone_minutes() {
+        let pdt = ParsedDateTime::default();
+        assert_eq!(
+            pdt.clone()
+                .with_timezone_minutes(-480)
+                .unwrap()
+                .timezone_offset_second,
+            Some(-28_800)
+        );
+        let india = Parser::parse_timestamp_string("2020-01-01 10:00:00", true)
+            .unwrap()
+            .with_timezone_minutes(330)
+            .unwrap();
+        assert_eq!(
+            india,
+            Parser::parse_timestamp_string("2020-01-01 10:00:00 +05:30", true).unwrap()
+        );
+        assert!(pdt.clone().with_timezone_minutes(14 * 60).is_ok());
+        assert!(pdt.clone().with_timezone_minutes(-14 * 60).is_ok());
+        assert!(pdt.clone().with_timezone_minutes(14 * 60 + 1).is_err());
+        assert!(pdt.with_timezone_minutes(i32::MIN).is_err());
+    }
+
+    #[test]
+    fn parsed_datetime_timezone_is_whole_minutes() {
+        let pdt = Parser::parse_timestamp_string("2020-01-01 10:00:00 +05:30", true).unwrap();
+        assert_eq!(pdt.timezone_is_whole_minutes(), Some(true));
+        let pdt = Parser::parse_timestamp_string("2020-01-01 10:00:00 -05:30", true).unwrap();
+        assert_eq!(pdt.timezone_is_whole_minutes(), Some(true));
+
+        // +05:30:15
+        let pdt = ParsedDateTime {
+            timezone_offset_second: Some(5 * 60 * 60 + 30 * 60 + 15),
+            ..Default::default()
+        };
+        assert_eq!(pdt.timezone_is_whole_minutes(), Some(false));
+
+        let pdt = Parser::parse_timestamp_string("2020-01-01 10:00:00", true).unwrap();
+        assert_eq!(pdt.timezone_is_whole_minutes(), None);
+    }
+
+    #[test]
+    fn parsed_datetime_fields_set() {
+        use DateTimeField::*;
+
+        let pdt = Parser::parse_interval_string("1-2", &Year).unwrap();
+        assert!(pdt.has_date_part());
+        assert!(!pdt.has_time_part());
+        assert_eq!(pdt.fields_set(), vec![Year, Month]);
+
+        let pdt = Parser::parse_interval_string("4:05", &Minute).unwrap();
+        assert!(!pdt.has_date_part());
+        assert!(pdt.has_time_part());
+        assert_eq!(pdt.fields_set(), vec![Minute, Second]);
+
+        let pdt = Parser::parse_interval_string("3 4:05:06.7", &Day).unwrap();
+        assert!(pdt.has_date_part());
+        assert!(pdt.has_time_part());
+        assert_eq!(pdt.fields_set(), vec![Day, Hour, Minute, Second]);
+
+        let pdt = ParsedDateTime {
+            nano: Some(1),
+            ..Default::default()
+        };
+        assert!(pdt.has_time_part());
+        assert_eq!(pdt.fields_set(), vec![Second]);
+
+        let pdt = ParsedDateTime::default();
+        assert!(!pdt.has_date_part());
+        assert!(!pdt.has_time_part());
+        assert_eq!(pdt.fields_set(), vec![]);
+    }
+
+    #[test]
+    fn parsed_datetime_coarsest_whole_field() {
+        use DateTimeField::*;
+
+        let field = |value| {
+            Parser::parse_interval_string(value, &Second)
+                .unwrap()
+                .coarsest_whole_field()
+        };
+        assert_eq!(field("3600"), Some(Hour));
+        assert_eq!(field("3660"), Some(Minute));
+        assert_eq!(field("3661"), Some(Second));
+        assert_eq!(field("172800"), Some(Day));
+        assert_eq!(field("-7200"), Some(Hour));
+        assert_eq!(field("3600.5"), None);
+        assert_eq!(field("0"), None);
+        assert_eq!(
+            Parser::parse_interval_string("1", &Year)
+                .unwrap()
+                .coarsest_whole_field(),
+            None
+        );
+    }
+
+    #[test]
+    fn parsed_datetime_sign_constructors() {
+        let positive = ParsedDateTime::positive();
+        assert_eq!(ParsedDateTime::default(), positive);
+        assert!(positive.is_positive);
+        assert!(positive.fields_set().is_empty());
+        assert!(!positive.has_timezone());
+        assert_eq!(positive.nano_digits, None);
+
+        let negative = ParsedDateTime::negative();
+        assert!(!negative.is_positive);
+        assert_eq!(
+            negative,
+            ParsedDateTime {
+                is_positive: false,
+                ..positive.clone()
+            }
+        );
+        assert_eq!(ParsedDateTime::with_sign(true), positive);
+        assert_eq!(ParsedDateTime::with_sign(false), negative);
+    }
+
+    #[test]
+    fn parsed_datetime_justify() {
+        let postgres = |value, justify: fn(&ParsedDateTime) -> ParsedDateTime| {
+            justify(&Parser::parse_unqualified_interval_string(value).unwrap())
+                .to_style(IntervalStyle::Postgres)
+        };
+        let hours = ParsedDateTime::justify_hours;
+        let days = ParsedDateTime::justify_days;
+        let interval = ParsedDateTime::justify_interval;
+        assert_eq!(postgres("27 hours", hours), "1 day 03:00:00");
+        assert_eq!(postgres("-27 hours", hours), "-1 days -03:00:00");
+        assert_eq!(postgres("1500 minutes", hours), "1 day 01:00:00");
+        assert_eq!(postgres("1 mon 48 hours", hours), "1 mon 2 days");
+        assert_eq!(postgres("23 hours", hours), "23:00:00");
+        assert_eq!(postgres("35 days", days), "1 mon 5 days");
+        assert_eq!(postgres("400 days", days), "1 year 1 mon 10 days");
+        assert_eq!(postgres("35 days 27 hours", days), "1 mon 5 days 27:00:00");
+        assert_eq!(postgres("29 days", days), "29 days");
+        assert_eq!(postgres("29 days 27 hours", interval), "1 mon 03:00:00");
+        assert_eq!(
+            postgres("-1 mon -65 days -49 hours", interval),
+            "-3 mons -7 days -01:00:00"
+        );
+    }
+
+    #[test]
+    fn parsed_datetime_to_style() {
+        use IntervalStyle::*;
+
+        let styles = |value, field| {
+            let pdt = Parser::parse_interval_string(value, &field).unwrap();
+            [Postgres, PostgresVerbose, SqlStandard, Iso8601]
+                .iter()
+                .map(|style| pdt.to_style(*style))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            styles("1-2-3 4:5:6.5", DateTimeField::Year),
+            [
+                "1 year 2 mons 3 days 04:05:06.5",
+                "@ 1 year 2 mons 3 days 4 hours 5 mins 6.5 secs",
+                "+1-2 +3 +4:05:06.5",
+                "P1Y2M3DT4H5M6.5S",
+            ]
+        );
+        assert_eq!(
+            styles("-1-2-3 4:5:6.5", DateTimeField::Year),
+            [
+                "-1 years -2 mons -3 days -04:05:06.5",
+                "@ 1 year 2 mons 3 days 4 hours 5 mins 6.5 secs ago",
+                "-1-2 -3 -4:05:06.5",
+                "P-1Y-2M-3DT-4H-5M-6.5S",
+            ]
+        );
+        assert_eq!(
+            styles("-1 2:00:00", DateTimeField::Day),
+            [
+                "-1 days -02:00:00",
+                "@ 1 day 2 hours ago",
+                "-1 2:00:00",
+                "P-1DT-2H"
+            ]
+        );
+        assert_eq!(
+            styles("1-2", DateTimeField::Year),
+            ["1 year 2 mons", "@ 1 year 2 mons", "1-2", "P1Y2M"]
+        );
+        assert_eq!(
+            styles("0", DateTimeField::Second),
+            ["00:00:00", "@ 0", "0", "PT0S"]
+        );
+    }
+
+    #[test]
+    fn parsed_datetime_to_postgres_verbose() {
+        let verbose = |value, field| {
+            Parser::parse_interval_string(value, &field)
+                .unwrap()
+                .to_postgres_verbose()
+        };
+        assert_eq!(
+            verbose("1-2-3 4:5:6", DateTimeField::Year),
+            "@ 1 year 2 mons 3 days 4 hours 5 mins 6 secs"
+        );
+        assert_eq!(
+            verbose("2-1-1 1:1:1", DateTimeField::Year),
+            "@ 2 years 1 mon 1 day 1 hour 1 min 1 sec"
+        );
+        assert_eq!(
+            verbose("-1 2:00:00", DateTimeField::Day),
+            "@ 1 day 2 hours ago"
+        );
+        assert_eq!(verbose("1.5", DateTimeField::Second), "@ 1.5 secs");
+        assert_eq!(verbose("-0.25", DateTimeField::Second), "@ 0.25 secs ago");
+        assert_eq!(verbose("0:0", DateTimeField::Minute), "@ 0");
+        assert_eq!(verbose("-0", DateTimeField::Second), "@ 0");
+    }
+
+    #[test]
+    fn parsed_datetime_builder() {
+        let parse = |value, field| Parser::parse_interval_string(value, &field).unwrap();
+        assert_eq!(
+            ParsedDateTime::builder()
+                .years(1)
+                .months(2)
+                .negative()
+                .build()
+                .unwrap(),
+            parse("-1-2", DateTimeField::Year)
+        );
+        assert_eq!(
+            ParsedDateTime::builder()
+                .days(3)
+                .hours(4)
+                .minutes(5)
+                .seconds(6)
+                .build()
+                .unwrap(),
+            parse("3 4:05:06", DateTimeField::Day)
+        );
+        assert_eq!(
+            ParsedDateTime::builder().build().unwrap(),
+            ParsedDateTime::default()
+        );
+        // the months are only checked alongside years
+        assert!(ParsedDateTime::builder()
+            .years(1)
+            .months(13)
+            .build()
+            .is_err());
+        assert!(ParsedDateTime::builder()
+            .years(1)
+            .months(0)
+            .build()
+            .is_err());
+        assert_eq!(
+            ParsedDateTime::builder().months(13).build().unwrap().month,
+            Some(13)
+        );
+        assert!(ParsedDateTime::builder()
+            .seconds(1)
+            .nanos(1_000_000_000)
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn parsed_datetime_zero() {
+        use core::cmp::Ordering;
+
+        for (value, field) in &[
+            ("0", DateTimeField::Second),
+            ("-0", DateTimeField::Hour),
+            ("00:00:00.000", DateTimeField::Hour),
+            ("0 days", DateTimeField::Day),
+        ] {
+            let pdt = Parser::parse_interval_string(value, field).unwrap();
+            assert!(pdt.is_zero(), "{} is zero", value);
+            assert_eq!(pdt.cmp_approx(&ParsedDateTime::zero()), Ordering::Equal);
+        }
+        assert!(ParsedDateTime::zero().is_zero());
+        assert!(
+            !Parser::parse_interval_string("0:0:0.001", &DateTimeField::Hour)
+                .unwrap()
+                .is_zero()
+        );
+        // months are counted from one, so there is no `0-0` year-month value
+        assert!(Parser::parse_interval_string("0-0", &DateTimeField::Year).is_err());
+    }
+
+    #[test]
+    fn parsed_datetime_arithmetic() {
+        let parse = |value| Parser::parse_unqualified_interval_string(value).unwrap();
+
+        assert_eq!(
+            (parse("1 day") + parse("2 hours")).unwrap(),
+            ParsedDateTime {
+                day: Some(1),
+                hour: Some(2),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            (parse("1 hour 30 minutes") - parse("2 hours")).unwrap(),
+            ParsedDateTime {
+                is_positive: false,
+                hour: Some(0),
+                minute: Some(30),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            (parse("0.6 seconds") + parse("-0.7 seconds")).unwrap(),
+            ParsedDateTime {
+                is_positive: false,
+                second: Some(0),
+                nano: Some(100_000_000),
+                nano_digits: Some(1),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            (parse("0.6 seconds") + parse("0.7 seconds")).unwrap(),
+            ParsedDateTime {
+                second: Some(1),
+                nano: Some(300_000_000),
+                nano_digits: Some(1),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            (parse("1 year 1 month") - parse("2 months")).unwrap(),
+            ParsedDateTime {
+                year: Some(0),
+                month: Some(11),
+                ..Default::default()
+            }
+        );
+        // months and days are never combined, so they keep their own signs
+        let month_back = (parse("1 mon") - parse("10 days")).unwrap();
+        assert_eq!(
+            (
+                month_back.total_months().unwrap(),
+                month_back.days().unwrap()
+            ),
+            (1, -10)
+        );
+        assert_eq!(month_back, parse("1 mon -10 days"));
+        assert_eq!(
+            (parse("2 days -3 hours") + parse("-2 days")).unwrap(),
+            ParsedDateTime {
+                is_positive: false,
+                day: Some(0),
+                hour: Some(3),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            (parse("1 mon") + parse("10 days")).unwrap(),
+            ParsedDateTime {
+                month: Some(1),
+                day: Some(10),
+                ..Default::default()
+            }
+        );
+
+        let huge = ParsedDateTime {
+            day: Some(u64::MAX),
+            ..Default::default()
+        };
+        assert!((huge.clone() + parse("1 day")).is_err());
+        assert_eq!((huge.clone() - huge).unwrap().day, Some(0));
+    }
+
+    #[test]
+    fn parsed_datetime_part_signs() {
+        use core::convert::TryFrom;
+        use IntervalStyle::*;
+        let parse = |value| Parser::parse_unqualified_interval_string(value).unwrap();
+
+        let pdt = parse("2 days -3 hours");
+        assert!(pdt.has_mixed_signs());
+        assert_eq!(pdt.to_style(Postgres), "2 days -03:00:00");
+        assert_eq!(pdt.to_style(PostgresVerbose), "@ 2 days -3 hours");
+        assert_eq!(pdt.to_style(SqlStandard), "+0-0 +2 -3:00:00");
+        assert_eq!(pdt.to_style(Iso8601), "P2DT-3H");
+        assert_eq!(pdt.clone().negate().to_style(Postgres), "-2 days +03:00:00");
+        assert_eq!(
+            parse("-2 days 3 hours").to_style(PostgresVerbose),
+            "@ 2 days -3 hours ago"
+        );
+        assert_eq!(
+            (pdt.clone() * -2).unwrap().to_style(Postgres),
+            "-4 days +06:00:00"
+        );
+        assert!(pdt.semantically_eq(&parse("2 days -180 minutes")));
+        assert_eq!(pdt.approx_total_seconds(), 45.0 * 60.0 * 60.0);
+        assert_eq!(pdt.justify_hours().to_style(Postgres), "1 day 21:00:00");
+        assert_eq!(
+            parse("1 mon -1 day").justify_days().to_style(Postgres),
+            "29 days"
+        );
+        assert_eq!(pdt.floor_to(DateTimeField::Day).days().unwrap(), 1);
+        assert_eq!(pdt.ceil_to(DateTimeField::Day).days().unwrap(), 2);
+        assert_eq!(pdt.dominant_component(), Some((DateTimeField::Day, 2)));
+        assert_eq!(
+            pdt.to_qualified_sql(DateTimeField::Day, Some(DateTimeField::Second)),
+            "INTERVAL '2 days -03:00:00'"
+        );
+        assert_eq!(
+            IntervalParts::try_from(pdt.clone()).unwrap(),
+            IntervalParts {
+                months: 0,
+                days: 2,
+                micros: -3 * 60 * 60 * 1_000_000,
+            }
+        );
+        assert!(pdt.to_utc().is_ok());
+        assert!(parse("1 day -2 hours")
+            .with_timezone_minutes(60)
+            .unwrap()
+            .to_utc()
+            .is_err());
+
+        // equal values compare equal, however their signs were written
+        assert_eq!(
+            parse("0 days -3 hours"),
+            ParsedDateTime {
+                is_positive: false,
+                day: Some(0),
+                hour: Some(3),
+                ..Default::default()
+            }
+        );
+        assert_eq!(pdt.clone().with_part_signs(pdt.signs()), pdt);
+    }
+
+    #[test]
+    fn parsed_datetime_from_std_duration() {
+        use core::time::Duration;
+
+        let pdt = ParsedDateTime::from_std_duration(Duration::from_millis(250));
+        assert_eq!(
+            pdt,
+            ParsedDateTime {
+                hour: Some(0),
+                minute: Some(0),
+                second: Some(0),
+                nano: Some(250_000_000),
+                ..Default::default()
+            }
+        );
+        assert_eq!(pdt.to_style(IntervalStyle::Postgres), "00:00:00.25");
+
+        let pdt = ParsedDateTime::from_std_duration(Duration::new(90_061, 500_000_000));
+        assert_eq!(
+            (pdt.day, pdt.hour, pdt.minute, pdt.second, pdt.nano),
+            (None, Some(25), Some(1), Some(1), Some(500_000_000))
+        );
+        assert_eq!(
+            pdt.justify_hours().to_style(IntervalStyle::Postgres),
+            "1 day 01:01:01.5"
+        );
+
+        let whole = ParsedDateTime::from_std_duration(Duration::from_secs(3 * 60 * 60));
+        assert_eq!((whole.hour, whole.nano), (Some(3), None));
+        assert!(whole.is_positive);
+
+        // the round trip gives back the same length
+        for duration in &[
+            Duration::from_secs(0),
+            Duration::new(3_723, 500_000_000),
+            Duration::new(u64::MAX, 999_999_999),
+        ] {
+            assert_eq!(
+                ParsedDateTime::from_std_duration(*duration).try_into_std_duration(),
+                Ok(*duration)
+            );
+        }
+    }
+
+    #[test]
+    fn parsed_datetime_try_into_std_duration() {
+        use core::time::Duration;
+
+        let parse = |value, field| Parser::parse_interval_string(value, &field).unwrap();
+        assert_eq!(
+            parse("1:02:03.5", DateTimeField::Hour)
+                .try_into_std_duration()
+                .unwrap(),
+            Duration::new(3_723, 500_000_000)
+        );
+        assert_eq!(
+            parse("-0", DateTimeField::Second).try_into_std_duration(),
+            Ok(Duration::from_secs(0))
+        );
+        assert_eq!(
+            parse("-1:02:03.5", DateTimeField::Hour).try_into_std_duration(),
+            Err(ParserError::ParserError(
+                "interval -01:02:03.5 is negative, but a Duration can't be".to_string()
+            ))
+        );
+        assert_eq!(
+            parse("1 2:00:00", DateTimeField::Day).try_into_std_duration(),
+            Err(ParserError::ParserError(
+                "interval 1 day 02:00:00 has years, months or days, which have no fixed length"
+                    .to_string()
+            ))
+        );
+        let huge = ParsedDateTime {
+            hour: Some(u64::MAX),
+            ..Default::default()
+        };
+        assert!(huge.try_into_std_duration().is_err());
+    }
+
+    #[test]
+    fn parsed_datetime_signed_fields() {
+        let pdt = Parser::parse_interval_string("-1.5 seconds", &DateTimeField::Second).unwrap();
+        assert_eq!(pdt.signed_seconds().unwrap(), -1);
+        assert_eq!(pdt.signed_nanos(), -500_000_000);
+        assert_eq!(pdt.signed_minutes().unwrap(), 0);
+
+        let pdt = Parser::parse_interval_string("-1-2-3 4:05:06.7", &DateTimeField::Year).unwrap();
+        assert_eq!(
+            (
+                pdt.signed_years().unwrap(),
+                pdt.signed_months().unwrap(),
+                pdt.days().unwrap(),
+                pdt.signed_hours().unwrap(),
+                pdt.signed_minutes().unwrap(),
+                pdt.signed_seconds().unwrap(),
+                pdt.signed_nanos(),
+            ),
+            (-1, -2, -3, -4, -5, -6, -700_000_000)
+        );
+        let pdt = Parser::parse_interval_string("0.25", &DateTimeField::Second).unwrap();
+        assert_eq!(pdt.signed_nanos(), 250_000_000);
+
+        let huge = ParsedDateTime {
+            hour: Some(u64::MAX),
+            ..Default::default()
+        };
+        assert!(huge.signed_hours().is_err());
+    }
+
+    #[test]
+    fn parsed_datetime_total_parts() {
+        let parts = |value, field| {
+            let pdt = Parser::parse_interval_string(value, field).unwrap();
+            (
+                pdt.total_months().unwrap(),
+                pdt.days().unwrap(),
+                pdt.total_micros().unwrap(),
+            )
+        };
+        assert_eq!(
+            parts("1-2-3 4:05:06.7", &DateTimeField::Year),
+            (14, 3, 14_706_700_000)
+        );
+        assert_eq!(
+            parts("-1-2-3 4:05:06.7", &DateTimeField::Year),
+            (-14, -3, -14_706_700_000)
+        );
+        assert_eq!(parts("0.0000015", &DateTimeField::Second), (0, 0, 1));
+
+        let huge = ParsedDateTime {
+            year: Some(u64::MAX),
+            hour: Some(u64::MAX),
+            day: Some(u64::MAX),
+            ..Default::default()
+        };
+        assert!(huge.total_months().is_err());
+        assert!(huge.days().is_err());
+        assert!(huge.total_micros().is_err());
+    }
+
+    #[test]
+    fn parsed_datetime_seconds_literal() {
+        let seconds = |value, field| {
+            Parser::parse_interval_string(value, &field)
+                .unwrap()
+                .seconds_literal()
+        };
+        assert_eq!(seconds("1:02.050", DateTimeField::Minute).unwrap(), "2.050");
+        assert_eq!(seconds("1:02.05", DateTimeField::Minute).unwrap(), "2.05");
+        assert_eq!(seconds("PT1.500S", DateTimeField::Second).unwrap(), "1.500");
+        assert_eq!(seconds("3 seconds", DateTimeField::Second).unwrap(), "3");
+        assert_eq!(seconds("1:02", DateTimeField::Hour), None);
+        // the digits are only for display, the values are still equal
+        let (short, long) = (
+            Parser::parse_interval_string("1.05", &DateTimeField::Second).unwrap(),
+            Parser::parse_interval_string("1.050", &DateTimeField::Second).unwrap(),
+        );
+        assert_ne!(short.seconds_literal(), long.seconds_literal());
+        assert_eq!(short, long);
+
+        // rounding keeps the digits that are left
+        let options = crate::parser::DateTimeParseOptions {
+            fractional_seconds_precision: Some(2),
+            ..Default::default()
+        };
+        let pdt =
+            Parser::parse_interval_string_with_options("1.23456", &DateTimeField::Second, &options)
+                .unwrap();
+        assert_eq!(pdt.seconds_literal().unwrap(), "1.23");
+
+        // without a digit count, trailing zeros are dropped
+        let pdt = ParsedDateTime {
+            second: Some(1),
+            nano: Some(500_000_000),
+            ..Default::default()
+        };
+        assert_eq!(pdt.seconds_literal().unwrap(), "1.5");
+    }
+
+    #[test]
+    fn parsed_datetime_until() {
+        let clock =
+            |value: &str| Parser::parse_interval_string(value, &DateTimeField::Hour).unwrap();
+        let hms = |pdt: ParsedDateTime| (pdt.hour, pdt.minute, pdt.second, pdt.nano);
+
+        assert_eq!(
+            hms(clock("14:00").until(&clock("16:30")).unwrap()),
+            (Some(2), Some(30), Some(0), None)
+        );
+        assert_eq!(
+            hms(clock("23:00").until(&clock("01:00")).unwrap()),
+            (Some(2), Some(0), Some(0), None)
+        );
+        assert_eq!(
+            hms(clock("12:00").until(&clock("12:00")).unwrap()),
+            (Some(0), Some(0), Some(0), None)
+        );
+        assert_eq!(
+            hms(clock("23:59:59.5").until(&clock("00:00:00.25")).unwrap()),
+            (Some(0), Some(0), Some(0), Some(750_000_000))
+        );
+
+        let day = Parser::parse_interval_string("1 01:00", &DateTimeField::Day).unwrap();
+        assert!(day.until(&clock("02:00")).is_err());
+        assert!(clock("-01:00").until(&clock("02:00")).is_err());
+        assert!(clock("25:00").until(&clock("02:00")).is_err());
+    }
 }
@@ -1,7 +1,22 @@
-use std::fmt;
-use std::time::Duration;
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, Mul, Neg, RangeInclusive, Sub};
+use core::time::Duration;
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Datelike, FixedOffset, Months, NaiveDate, NaiveDateTime, Timelike};
 
 use super::ValueError;
+use crate::parser::ParserError;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct IntervalValue {
@@ -63,35 +78,50 @@ impl IntervalValue {
         use DateTimeField::*;
         match &self.leading_field {
             Year => match &self.last_field {
+                // The sign applies to both the years and the months
                 Some(Month) => Ok(Interval::Months(
-                    self.parsed.positivity() * self.parsed.year.unwrap_or(0) as i64 * 12
-                        + self.parsed.month.unwrap_or(0) as i64,
+                    self.parsed.part_positivity(&Month)
+                        * (self.parsed.year.unwrap_or(0) as i64 * 12
+                            + self.parsed.month.unwrap_or(0) as i64),
                 )),
                 Some(Year) | None => self
                     .parsed
                     .year
                     .ok_or_else(|| ValueError("No YEAR provided".into()))
-                    .map(|year| Interval::Months(self.parsed.positivity() * year as i64 * 12)),
+                    .map(|year| {
+                        Interval::Months(self.parsed.part_positivity(&Year) * year as i64 * 12)
+                    }),
                 Some(invalid) => Err(ValueError(format!(
                     "Invalid specifier for YEAR precision: {}",
                     &invalid
                 ))),
             },
-            Month => match &self.last_field {
-                Some(Month) | None => self
+            Quarter | Month => match &self.last_field {
+                Some(Quarter) | Some(Month) | None => self
                     .parsed
                     .month
-                    .ok_or_else(|| ValueError("No MONTH provided".into()))
-                    .map(|m| Interval::Months(self.parsed.positivity() * m as i64)),
+                    .ok_or_else(|| ValueError(format!("No {} provided", self.leading_field)))
+                    .map(|m| Interval::Months(self.parsed.part_positivity(&Month) * m as i64)),
                 Some(invalid) => Err(ValueError(format!(
                     "Invalid specifier for MONTH precision: {}",
                     &invalid
                 ))),
             },
             durationlike_field => {
-                let mut seconds = 0u64;
+                // weeks are stored as days
+                let durationlike_field = match durationlike_field {
+                    Week => &Day,
+                    field => field,
+                };
+                // the days and the time may have different signs
+                let signed = |field: &DateTimeField, units: u64| {
+                    i128::from(self.parsed.part_positivity(field))
+                        * i128::from(units)
+                        * i128::from(seconds_multiplier(field))
+                };
+                let mut seconds = 0i128;
                 match self.units_of(&durationlike_field) {
-                    Some(time) => seconds += time * seconds_multiplier(&durationlike_field),
+                    Some(time) => seconds += signed(&durationlike_field, time),
                     None => {
                         return Err(ValueError(format!(
                             "No {} provided in value string for {}",
@@ -109,15 +139,23 @@ impl IntervalValue {
                     .take_while(|f| f <= min_field)
                 {
                     if let Some(time) = self.units_of(&field) {
-                        seconds += time * seconds_multiplier(&field);
+                        seconds += signed(&field, time);
                     }
                 }
-                let duration = match (min_field, self.parsed.nano) {
-                    (DateTimeField::Second, Some(nanos)) => Duration::new(seconds, nanos),
-                    (_, _) => Duration::from_secs(seconds),
+                let mut nanos = seconds * NANOS_PER_SECOND as i128;
+                if let (DateTimeField::Second, Some(nano)) = (min_field, self.parsed.nano) {
+                    nanos += i128::from(self.parsed.part_positivity(&Second)) * i128::from(nano);
+                }
+                let is_positive = match self.parsed.has_mixed_signs() {
+                    true => nanos >= 0,
+                    false => self.parsed.is_positive,
                 };
+                let nanos = nanos.unsigned_abs();
+                let duration = u64::try_from(nanos / NANOS_PER_SECOND)
+                    .map(|seconds| Duration::new(seconds, (nanos % NANOS_PER_SECOND) as u32))
+                    .map_err(|_| ValueError(format!("Interval is too long: {}", self.value)))?;
                 Ok(Interval::Duration {
-                    is_positive: self.parsed.is_positive,
+                    is_positive,
                     duration,
                 })
             }
@@ -128,8 +166,8 @@ impl IntervalValue {
     fn units_of(&self, field: &DateTimeField) -> Option<u64> {
         match field {
             DateTimeField::Year => self.parsed.year,
-            DateTimeField::Month => self.parsed.month,
-            DateTimeField::Day => self.parsed.day,
+            DateTimeField::Quarter | DateTimeField::Month => self.parsed.month,
+            DateTimeField::Week | DateTimeField::Day => self.parsed.day,
             DateTimeField::Hour => self.parsed.hour,
             DateTimeField::Minute => self.parsed.minute,
             DateTimeField::Second => self.parsed.second,
@@ -159,10 +197,17 @@ impl IntervalValue {
         let mut extra_leading_fields = vec![];
         let mut extra_trailing_fields = vec![];
         // check for more data in the input string than was requested in <FIELD> TO <FIELD>
-        for field in std::iter::once(DateTimeField::Year).chain(DateTimeField::Year.into_iter()) {
+        for field in core::iter::once(DateTimeField::Year).chain(DateTimeField::Year.into_iter()) {
             if self.units_of(&field).is_none() {
                 continue;
             }
+            // The months are where the quarters are stored, and the days
+            // are where the weeks are
+            let field = match (field, &self.leading_field) {
+                (DateTimeField::Month, DateTimeField::Quarter) => DateTimeField::Quarter,
+                (DateTimeField::Day, DateTimeField::Week) => DateTimeField::Week,
+                (field, _) => field,
+            };
 
             if field < self.leading_field {
                 extra_leading_fields.push(field.clone());
@@ -213,7 +258,7 @@ impl IntervalValue {
 
     fn present_fields(&self) -> String {
         fields_msg(
-            std::iter::once(DateTimeField::Year)
+            core::iter::once(DateTimeField::Year)
                 .chain(DateTimeField::Year.into_iter())
                 .filter(|field| self.units_of(&field).is_some()),
         )
@@ -227,6 +272,13 @@ fn fields_msg(fields: impl Iterator<Item = DateTimeField>) -> String {
         .join(", ")
 }
 
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+/// The furthest a timezone offset may be from UTC, in minutes
+///
+/// The offsets in use go from -12:00 to +14:00.
+pub(crate) const MAX_TIMEZONE_OFFSET_MINUTES: i32 = 14 * 60;
+
 fn seconds_multiplier(field: &DateTimeField) -> u64 {
     match field {
         DateTimeField::Day => 60 * 60 * 24,
@@ -237,6 +289,17 @@ fn seconds_multiplier(field: &DateTimeField) -> u64 {
     }
 }
 
+/// Like [`seconds_multiplier`], but with 30 day months and 12 month years
+fn approx_seconds_multiplier(field: &DateTimeField) -> u64 {
+    match field {
+        DateTimeField::Year => 12 * 30 * 24 * 60 * 60,
+        DateTimeField::Quarter => 3 * 30 * 24 * 60 * 60,
+        DateTimeField::Month => 30 * 24 * 60 * 60,
+        DateTimeField::Week => 7 * 24 * 60 * 60,
+        other => seconds_multiplier(other),
+    }
+}
+
 /// The result of parsing an `INTERVAL '<value>' <unit> [TO <precision>]`
 ///
 /// Units of type `YEAR` or `MONTH` are semantically some multiple of months,
@@ -256,6 +319,131 @@ pub enum Interval {
     },
 }
 
+/// An interval split into months, days and microseconds
+///
+/// This is how PostgreSQL represents intervals internally: the three parts
+/// are kept apart because neither a month nor a day has a fixed length, and
+/// each part has its own sign, see [`ParsedDateTime::part_signs`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct IntervalParts {
+    /// Years and months, with a year as 12 months
+    pub months: i64,
+    pub days: i64,
+    /// Hours, minutes, seconds and fractional seconds
+    ///
+    /// Nanoseconds that don't make up a whole microsecond are truncated.
+    pub micros: i64,
+}
+
+impl TryFrom<ParsedDateTime> for IntervalParts {
+    type Error = ParserError;
+
+    fn try_from(pdt: ParsedDateTime) -> Result<IntervalParts, ParserError> {
+        let part = |part: Result<i64, ValueError>| part.map_err(|e| ParserError::ParserError(e.0));
+        Ok(IntervalParts {
+            months: part(pdt.total_months())?,
+            days: part(pdt.days())?,
+            micros: part(pdt.total_micros())?,
+        })
+    }
+}
+
+/// An interval laid out like Arrow's `IntervalMonthDayNano`
+///
+/// This is the columnar interval type of Arrow and Parquet, with the same
+/// three parts as [`IntervalParts`] but narrower months and days and the
+/// time kept to the nanosecond. Each part has its own sign, see
+/// [`ParsedDateTime::part_positivity`].
+#[cfg(feature = "arrow")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct IntervalMonthDayNano {
+    /// Years and months, with a year as 12 months
+    pub months: i32,
+    pub days: i32,
+    /// Hours, minutes, seconds and fractional seconds
+    pub nanoseconds: i64,
+}
+
+#[cfg(feature = "arrow")]
+impl TryFrom<ParsedDateTime> for IntervalMonthDayNano {
+    type Error = ParserError;
+
+    fn try_from(pdt: ParsedDateTime) -> Result<IntervalMonthDayNano, ParserError> {
+        let out_of_range = |part| {
+            ParserError::ParserError(format!(
+                "interval {} has too many {} for an Arrow interval",
+                pdt.to_style(IntervalStyle::Postgres),
+                part
+            ))
+        };
+        let field = |field: Option<u64>| i128::from(field.unwrap_or(0));
+        let sign = |field| i128::from(pdt.part_positivity(&field));
+        let months = field(pdt.year)
+            .checked_mul(12)
+            .and_then(|months| months.checked_add(field(pdt.month)))
+            .and_then(|months| i32::try_from(sign(DateTimeField::Month) * months).ok())
+            .ok_or_else(|| out_of_range("months"))?;
+        let days = i32::try_from(sign(DateTimeField::Day) * field(pdt.day))
+            .map_err(|_| out_of_range("days"))?;
+        let nanoseconds = ((field(pdt.hour) * 60 + field(pdt.minute)) * 60 + field(pdt.second))
+            .checked_mul(NANOS_PER_SECOND as i128)
+            .and_then(|nanos| nanos.checked_add(i128::from(pdt.nano.unwrap_or(0))))
+            .and_then(|nanos| i64::try_from(sign(DateTimeField::Second) * nanos).ok())
+            .ok_or_else(|| out_of_range("nanoseconds"))?;
+        Ok(IntervalMonthDayNano {
+            months,
+            days,
+            nanoseconds,
+        })
+    }
+}
+
+/// How [`ParsedDateTime::resolve_days_against`] counts the days in a month
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CalendarKind {
+    /// The usual calendar, with leap years
+    Gregorian,
+    /// Twelve 30 day months, as used by the 30/360 day count convention
+    Financial360,
+    /// The Gregorian months, but February always has 28 days
+    Fixed365,
+}
+
+impl CalendarKind {
+    fn days_in_month(self, year: i64, month: u8) -> u8 {
+        match (self, month) {
+            (CalendarKind::Financial360, _) => 30,
+            (_, 4) | (_, 6) | (_, 9) | (_, 11) => 30,
+            (CalendarKind::Gregorian, 2) if is_leap_year(year) => 29,
+            (_, 2) => 28,
+            (_, _) => 31,
+        }
+    }
+
+    /// The number of days from some fixed epoch until a date
+    fn day_number(self, year: i64, month: u8, day: u8) -> i64 {
+        // Cumulative days before each month, counting from March so that
+        // the leap day is at the end of the year
+        const DAYS_BEFORE: [i64; 12] = [0, 31, 61, 92, 122, 153, 184, 214, 245, 275, 306, 337];
+        let (year, month) = if month <= 2 {
+            (year - 1, month + 10)
+        } else {
+            (year, month - 2)
+        };
+        let leap_days = match self {
+            CalendarKind::Gregorian => {
+                year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400)
+            }
+            CalendarKind::Financial360 | CalendarKind::Fixed365 => 0,
+        };
+        year * 365 + leap_days + DAYS_BEFORE[usize::from(month - 1)] + i64::from(day)
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
 /// The fields of a Date
 ///
 /// This is not guaranteed to be a valid date
@@ -283,12 +471,136 @@ pub struct ParsedTimestamp {
     pub timezone_offset_second: i64,
 }
 
+/// A keyword that stands for a point in time relative to when it is read,
+/// like `'today'`
+///
+/// These aren't written out like a timestamp and aren't lengths of time, so
+/// the caller resolves them against its own clock, see
+/// [`Parser::parse_timestamp_or_special`].
+///
+/// [`Parser::parse_timestamp_or_special`]: crate::parser::Parser::parse_timestamp_or_special
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateTimeSpecial {
+    /// The current time
+    Now,
+    /// Midnight at the start of the current day
+    Today,
+    /// Midnight at the start of the next day
+    Tomorrow,
+    /// Midnight at the start of the previous day
+    Yesterday,
+}
+
+impl DateTimeSpecial {
+    /// The keyword `value` is, ignoring case and surrounding whitespace
+    pub fn from_keyword(value: &str) -> Option<DateTimeSpecial> {
+        [
+            ("now", DateTimeSpecial::Now),
+            ("today", DateTimeSpecial::Today),
+            ("tomorrow", DateTimeSpecial::Tomorrow),
+            ("yesterday", DateTimeSpecial::Yesterday),
+        ]
+        .iter()
+        .find(|(keyword, _)| keyword.eq_ignore_ascii_case(value.trim()))
+        .map(|(_, special)| *special)
+    }
+
+    /// How many days after the current one this is at midnight, or `None`
+    /// for [`DateTimeSpecial::Now`], which has a time of day
+    pub fn day_offset(self) -> Option<i64> {
+        match self {
+            DateTimeSpecial::Now => None,
+            DateTimeSpecial::Today => Some(0),
+            DateTimeSpecial::Tomorrow => Some(1),
+            DateTimeSpecial::Yesterday => Some(-1),
+        }
+    }
+}
+
+impl fmt::Display for DateTimeSpecial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DateTimeSpecial::Now => f.write_str("now"),
+            DateTimeSpecial::Today => f.write_str("today"),
+            DateTimeSpecial::Tomorrow => f.write_str("tomorrow"),
+            DateTimeSpecial::Yesterday => f.write_str("yesterday"),
+        }
+    }
+}
+
+/// A timestamp string, which is either written out or is one of the
+/// keywords in [`DateTimeSpecial`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DateTimeOrSpecial {
+    DateTime(ParsedDateTime),
+    Special(DateTimeSpecial),
+}
+
+/// How long the months of an interval are taken to be when it is turned into
+/// a length of time, see [`ParsedDateTime::to_duration`]
+///
+/// `MonthPolicy::AnchorDate` is only there with the `chrono` feature, so a
+/// `match` on a policy can't list every variant.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MonthPolicy {
+    /// Every month is 30 days and every year 12 months, like PostgreSQL
+    /// assumes when it compares intervals
+    Days30,
+    /// The months are counted on the Gregorian calendar from this date, see
+    /// [`ParsedDateTime::resolve_against`]
+    #[cfg(feature = "chrono")]
+    AnchorDate(NaiveDate),
+}
+
+/// The ways PostgreSQL can write out an interval, see [`ParsedDateTime::to_style`]
+///
+/// These are the values of its `IntervalStyle` setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntervalStyle {
+    /// `1 year 2 mons 3 days 04:05:06.5`
+    Postgres,
+    /// `@ 1 year 2 mons 3 days 4 hours 5 mins 6.5 secs`
+    PostgresVerbose,
+    /// `+1-2 +3 +4:05:06.5`
+    SqlStandard,
+    /// `P1Y2M3DT4H5M6.5S`
+    Iso8601,
+}
+
 /// All of the fields that can appear in a literal `DATE`, `TIMESTAMP` or `INTERVAL` string
 ///
 /// This is only used in an `Interval`, which can have any contiguous set of
 /// fields set, otherwise you are probably looking for [`ParsedDate`] or
 /// [`ParsedTimestamp`].
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///
+/// `Hash` agrees with `PartialEq`, so a value can be used as a `HashMap`
+/// key: values are the same key when every field is the same, however the
+/// string was written, as with `'1 day'` and `'P1D'`, or `'1.5 seconds'` and
+/// `'1.50 seconds'`, since [`ParsedDateTime::nano_digits`] is only kept for
+/// display and is left out of both.
+/// `'90 seconds'` and `'1:30'` are different keys, because they have
+/// different fields. Hashing does not normalize, since that would disagree
+/// with `PartialEq`; normalize the value first, or key by the parts that
+/// [`ParsedDateTime::semantically_eq`] compares, to cache by meaning.
+///
+/// The fields are public to read. The methods on it keep the sign in
+/// [`ParsedDateTime::positivity`] and fold the fields together, and
+/// [`ParsedDateTime::builder`] checks a new value as it is put together:
+///
+/// ```
+/// # use sqlparser::ast::{DateTimeField, ParsedDateTime};
+/// # use sqlparser::parser::Parser;
+/// let pdt = Parser::parse_interval_string("-1 2:03", &DateTimeField::Day).unwrap();
+/// assert_eq!((pdt.day, pdt.hour, pdt.minute), (Some(1), Some(2), Some(3)));
+/// assert_eq!(pdt.positivity(), -1);
+/// assert_eq!(
+///     pdt.fields_set(),
+///     vec![DateTimeField::Day, DateTimeField::Hour, DateTimeField::Minute]
+/// );
+/// assert_eq!(pdt.signed_minutes().unwrap(), -3);
+/// ```
+#[derive(Debug, Clone, Eq)]
 pub struct ParsedDateTime {
     pub is_positive: bool,
     pub year: Option<u64>,
@@ -298,21 +610,172 @@ pub struct ParsedDateTime {
     pub minute: Option<u64>,
     pub second: Option<u64>,
     pub nano: Option<u32>,
+    /// How many digits the fraction of a second was written with
+    ///
+    /// This tells `.05` apart from `.050`, which have the same `nano`, see
+    /// [`ParsedDateTime::seconds_literal`]. It is only kept for display, and
+    /// values that differ only here are equal.
+    pub nano_digits: Option<u8>,
     pub timezone_offset_second: Option<i64>,
+    /// The leading field of the qualifier the value was parsed with
+    ///
+    /// This is only kept when [`DateTimeParseOptions::record_qualifier`] is
+    /// set, see [`ParsedDateTime::leading_field`].
+    ///
+    /// [`DateTimeParseOptions::record_qualifier`]: crate::parser::DateTimeParseOptions::record_qualifier
+    pub leading_field: Option<DateTimeField>,
+    /// The trailing field of the qualifier the value was parsed with, like the
+    /// `SECOND` of `DAY TO SECOND`
+    ///
+    /// This is only kept when [`DateTimeParseOptions::record_qualifier`] is
+    /// set, and the qualifier had a trailing field.
+    ///
+    /// [`DateTimeParseOptions::record_qualifier`]: crate::parser::DateTimeParseOptions::record_qualifier
+    pub trailing_field: Option<DateTimeField>,
+    /// The signs of the months, the days and the time, when they are not
+    /// all the same
+    ///
+    /// PostgreSQL keeps these three parts of an interval apart, each with
+    /// its own sign, so `'2 days -3 hours'` is two days forward and three
+    /// hours back. This is `None` when every part that isn't zero has the
+    /// sign in `is_positive`. Otherwise `is_positive` is the sign of the
+    /// largest part that isn't zero. Read the signs with
+    /// [`ParsedDateTime::part_positivity`] or the signed accessors, like
+    /// [`ParsedDateTime::signed_hours`], and set them with
+    /// [`ParsedDateTime::with_part_signs`], which keeps them in this form.
+    pub part_signs: Option<PartSigns>,
+}
+
+/// The signs of the parts of a [`ParsedDateTime`] that doesn't have a single
+/// sign, see [`ParsedDateTime::part_signs`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PartSigns {
+    /// Whether the years and months are positive
+    pub months: bool,
+    /// Whether the days are positive
+    pub days: bool,
+    /// Whether the hours, minutes, seconds and fractional seconds are positive
+    pub time: bool,
+}
+
+impl PartSigns {
+    /// The same sign for every part
+    pub fn all(is_positive: bool) -> PartSigns {
+        PartSigns {
+            months: is_positive,
+            days: is_positive,
+            time: is_positive,
+        }
+    }
+
+    /// The sign of the part that `field` is in
+    pub fn of(&self, field: &DateTimeField) -> bool {
+        match field {
+            DateTimeField::Year | DateTimeField::Quarter | DateTimeField::Month => self.months,
+            DateTimeField::Week | DateTimeField::Day => self.days,
+            DateTimeField::Hour | DateTimeField::Minute | DateTimeField::Second => self.time,
+        }
+    }
+
+    /// Every sign the other way
+    fn flipped(self) -> PartSigns {
+        PartSigns {
+            months: !self.months,
+            days: !self.days,
+            time: !self.time,
+        }
+    }
 }
 
 impl ParsedDateTime {
-    /// `1` if is_positive, else `-1`
-    pub(crate) fn positivity(&self) -> i64 {
+    /// `1` if is_positive, else `-1`, to multiply the fields by
+    ///
+    /// When the parts have different signs, see
+    /// [`ParsedDateTime::part_positivity`].
+    pub fn positivity(&self) -> i64 {
         match self.is_positive {
             true => 1,
             false => -1,
         }
     }
-}
 
-impl Default for ParsedDateTime {
-    fn default() -> ParsedDateTime {
+    /// `1` if the part that `field` is in is positive, else `-1`
+    ///
+    /// This is the same as [`ParsedDateTime::positivity`] unless the parts
+    /// have different [signs](ParsedDateTime::part_signs).
+    pub fn part_positivity(&self, field: &DateTimeField) -> i64 {
+        match self.signs().of(field) {
+            true => 1,
+            false => -1,
+        }
+    }
+
+    /// The sign of each part, whether or not they are the same
+    pub fn signs(&self) -> PartSigns {
+        self.part_signs
+            .unwrap_or_else(|| PartSigns::all(self.is_positive))
+    }
+
+    /// Whether the months, days and time don't all have the same sign
+    pub fn has_mixed_signs(&self) -> bool {
+        self.part_signs.is_some()
+    }
+
+    /// This value with `signs` for its parts
+    ///
+    /// The signs are stored the way [`ParsedDateTime::part_signs`] says, so
+    /// that equal values compare equal: if the parts that aren't zero all
+    /// have the same sign, that is the sign of the value. Otherwise the
+    /// largest of them gives `is_positive`, and a part that is zero takes
+    /// that sign too. A value with every part zero keeps the sign of its
+    /// largest part that is set.
+    pub fn with_part_signs(self, signs: PartSigns) -> ParsedDateTime {
+        let field = |field: Option<u64>| field.unwrap_or(0);
+        let parts = [
+            (
+                self.year.is_some() || self.month.is_some(),
+                field(self.year) != 0 || field(self.month) != 0,
+                signs.months,
+            ),
+            (self.day.is_some(), field(self.day) != 0, signs.days),
+            (self.has_time_part(), self.has_time(), signs.time),
+        ];
+        let first = parts
+            .iter()
+            .find(|(_, nonzero, _)| *nonzero)
+            .or_else(|| parts.iter().find(|(set, _, _)| *set));
+        let is_positive = first.map_or(signs.months, |(_, _, is_positive)| *is_positive);
+        let sign = |(_, nonzero, part_is_positive): &(bool, bool, bool)| match nonzero {
+            true => *part_is_positive,
+            false => is_positive,
+        };
+        let canonical = PartSigns {
+            months: sign(&parts[0]),
+            days: sign(&parts[1]),
+            time: sign(&parts[2]),
+        };
+        ParsedDateTime {
+            is_positive,
+            part_signs: Some(canonical).filter(|signs| *signs != PartSigns::all(is_positive)),
+            ..self
+        }
+    }
+
+    /// Start building a value one field at a time
+    ///
+    /// ```
+    /// # use sqlparser::ast::ParsedDateTime;
+    /// let pdt = ParsedDateTime::builder().years(1).months(2).negative().build().unwrap();
+    /// assert_eq!((pdt.year, pdt.month, pdt.is_positive), (Some(1), Some(2), false));
+    /// ```
+    pub fn builder() -> ParsedDateTimeBuilder {
+        ParsedDateTimeBuilder::default()
+    }
+
+    /// A positive value with no fields set
+    ///
+    /// This is also what `Default` gives, but says which sign is meant.
+    pub fn positive() -> ParsedDateTime {
         ParsedDateTime {
             is_positive: true,
             year: None,
@@ -322,109 +785,2252 @@ impl Default for ParsedDateTime {
             minute: None,
             second: None,
             nano: None,
+            nano_digits: None,
             timezone_offset_second: None,
+            leading_field: None,
+            trailing_field: None,
+            part_signs: None,
         }
     }
-}
 
-#[derive(Debug, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
-pub enum DateTimeField {
-    Year,
-    Month,
-    Day,
-    Hour,
-    Minute,
-    Second,
-}
+    /// A negative value with no fields set
+    pub fn negative() -> ParsedDateTime {
+        ParsedDateTime {
+            is_positive: false,
+            ..ParsedDateTime::positive()
+        }
+    }
 
-impl fmt::Display for DateTimeField {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.write_str(match self {
-            DateTimeField::Year => "YEAR",
-            DateTimeField::Month => "MONTH",
-            DateTimeField::Day => "DAY",
-            DateTimeField::Hour => "HOUR",
-            DateTimeField::Minute => "MINUTE",
-            DateTimeField::Second => "SECOND",
-        })
+    /// A value with no fields set and the given sign, for when it comes from
+    /// a variable
+    pub fn with_sign(is_positive: bool) -> ParsedDateTime {
+        if is_positive {
+            ParsedDateTime::positive()
+        } else {
+            ParsedDateTime::negative()
+        }
     }
-}
 
-/// Iterate over `DateTimeField`s in descending significance
-impl IntoIterator for DateTimeField {
-    type Item = DateTimeField;
-    type IntoIter = DateTimeFieldIterator;
-    fn into_iter(self) -> DateTimeFieldIterator {
-        DateTimeFieldIterator(Some(self))
+    /// The canonical zero interval, a positive `0` seconds
+    ///
+    /// Any value that [`is_zero`](ParsedDateTime::is_zero) is equal to this
+    /// one when compared with [`ParsedDateTime::cmp_approx`].
+    pub fn zero() -> ParsedDateTime {
+        ParsedDateTime {
+            second: Some(0),
+            ..ParsedDateTime::positive()
+        }
     }
-}
 
-/// An iterator over DateTimeFields
-///
-/// Always starts with the value smaller than the current one.
-///
-/// ```
-/// use sqlparser::ast::DateTimeField::*;
-/// let mut itr = Hour.into_iter();
-/// assert_eq!(itr.next(), Some(Minute));
-/// assert_eq!(itr.next(), Some(Second));
-/// assert_eq!(itr.next(), None);
-/// ```
-pub struct DateTimeFieldIterator(Option<DateTimeField>);
+    /// Whether every component that was set is zero
+    ///
+    /// The sign is ignored, so `-0` is zero, and a value with no components
+    /// set at all is zero as well.
+    pub fn is_zero(&self) -> bool {
+        [
+            self.year,
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second,
+        ]
+        .iter()
+        .all(|v| v.unwrap_or(0) == 0)
+            && self.nano.unwrap_or(0) == 0
+    }
 
-/// Go through fields in descending significance order
-impl Iterator for DateTimeFieldIterator {
-    type Item = DateTimeField;
-    fn next(&mut self) -> Option<Self::Item> {
-        use DateTimeField::*;
-        self.0 = match self.0 {
-            Some(Year) => Some(Month),
-            Some(Month) => Some(Day),
-            Some(Day) => Some(Hour),
-            Some(Hour) => Some(Minute),
-            Some(Minute) => Some(Second),
-            Some(Second) => None,
-            None => None,
+    /// The same fields with the opposite sign, which is also what `-pdt` does
+    ///
+    /// A timezone offset is negated along with the sign, and so is the sign
+    /// of each part. Zero stays positive, so both `0` and `-0` negate to `0`.
+    pub fn negate(self) -> ParsedDateTime {
+        ParsedDateTime {
+            is_positive: !self.is_positive || self.is_zero(),
+            timezone_offset_second: self.timezone_offset_second.map(|offset| -offset),
+            part_signs: self.part_signs.map(PartSigns::flipped),
+            ..self
+        }
+    }
+
+    /// Every field multiplied by `factor`, which is also what `pdt * factor` does
+    ///
+    /// The same fields are set as before, so `'1:30' * 4` is `4:120`, which
+    /// [`ParsedDateTime::normalize`] turns into `6:00`, and years and months
+    /// scale like the other fields. Fractional seconds that add up to more
+    /// than a second are carried into the seconds. A negative `factor` flips
+    /// the sign, and a zero result is positive.
+    ///
+    /// # Errors
+    ///
+    /// If a field no longer fits in a `u64`.
+    pub fn scale(&self, factor: i64) -> Result<ParsedDateTime, ValueError> {
+        let out_of_range = || {
+            ValueError(format!(
+                "cannot scale {:?} by {}: out of range",
+                self, factor
+            ))
         };
-        self.0.clone()
+        let times = |field: Option<u64>| {
+            field
+                .map(|field| {
+                    field
+                        .checked_mul(factor.unsigned_abs())
+                        .ok_or_else(out_of_range)
+                })
+                .transpose()
+        };
+        let nanos = self
+            .nano
+            .map(|nano| u128::from(nano) * u128::from(factor.unsigned_abs()));
+        let mut second = times(self.second)?;
+        if let Some(nanos) = nanos {
+            let carried = u64::try_from(nanos / NANOS_PER_SECOND).map_err(|_| out_of_range())?;
+            if carried > 0 {
+                second = Some(
+                    second
+                        .unwrap_or(0)
+                        .checked_add(carried)
+                        .ok_or_else(out_of_range)?,
+                );
+            }
+        }
+        let flip = |is_positive: bool| is_positive == (factor >= 0);
+        let scaled = ParsedDateTime {
+            is_positive: flip(self.is_positive),
+            year: times(self.year)?,
+            month: times(self.month)?,
+            day: times(self.day)?,
+            hour: times(self.hour)?,
+            minute: times(self.minute)?,
+            second,
+            // always less than a second
+            nano: nanos.map(|nanos| (nanos % NANOS_PER_SECOND) as u32),
+            part_signs: self.part_signs.map(|signs| PartSigns {
+                months: flip(signs.months),
+                days: flip(signs.days),
+                time: flip(signs.time),
+            }),
+            ..self.clone()
+        };
+        if scaled.is_zero() {
+            return Ok(ParsedDateTime {
+                is_positive: true,
+                part_signs: None,
+                ..scaled
+            });
+        }
+        Ok(scaled)
     }
-}
 
-/// Similar to a [`DateTimeField`], but with a few more options
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum ExtractField {
-    Millenium,
-    Century,
-    Decade,
-    Year,
-    /// The ISO Week-Numbering year
+    /// Whether any of the year, month or day were set
+    pub fn has_date_part(&self) -> bool {
+        self.year.is_some() || self.month.is_some() || self.day.is_some()
+    }
+
+    /// Whether any of the hour, minute, second or fractional seconds were set
+    pub fn has_time_part(&self) -> bool {
+        self.hour.is_some() || self.minute.is_some() || self.second.is_some() || self.nano.is_some()
+    }
+
+    /// The fields that were set, most significant first
     ///
-    /// See https://en.wikipedia.org/wiki/ISO_week_date
-    IsoYear,
-    Quarter,
-    Month,
-    Day,
-    Hour,
-    Minute,
-    Second,
-    Milliseconds,
-    Microseconds,
-    // Weirder fields
-    Timezone,
-    TimezoneHour,
-    TimezoneMinute,
-    WeekOfYear,
-    /// The day of the year (1 - 365/366)
-    DayOfYear,
-    /// The day of the week (0 - 6; Sunday is 0)
-    DayOfWeek,
-    /// The day of the week (1 - 7; Sunday is 7)
-    IsoDayOfWeek,
-    /// The number of seconds
+    /// Fractional seconds count as [`DateTimeField::Second`], even if the
+    /// whole seconds were not set.
+    pub fn fields_set(&self) -> Vec<DateTimeField> {
+        vec![
+            (DateTimeField::Year, self.year.is_some()),
+            (DateTimeField::Month, self.month.is_some()),
+            (DateTimeField::Day, self.day.is_some()),
+            (DateTimeField::Hour, self.hour.is_some()),
+            (DateTimeField::Minute, self.minute.is_some()),
+            (
+                DateTimeField::Second,
+                self.second.is_some() || self.nano.is_some(),
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, set)| *set)
+        .map(|(field, _)| field)
+        .collect()
+    }
+
+    /// Whether a timezone offset was parsed, as at the end of a timestamp
+    pub fn has_timezone(&self) -> bool {
+        self.timezone_offset_second.is_some()
+    }
+
+    /// The timezone offset east of UTC, in seconds, if there is one
+    pub fn timezone_offset_seconds(&self) -> Option<i64> {
+        self.timezone_offset_second
+    }
+
+    /// This value with a timezone offset of `minutes` east of UTC
     ///
-    /// * for DateTime fields, the number of seconds since 1970-01-01 00:00:00-00
-    /// * for intervals, the total number of seconds in the interval
-    Epoch,
+    /// This is for offsets that are stored as a count of minutes, like `-480`
+    /// for Pacific Standard Time. See [`Parser::parse_timezone_minutes`] for
+    /// one written as a string.
+    ///
+    /// # Errors
+    ///
+    /// If the offset is more than 14 hours either way.
+    ///
+    /// [`Parser::parse_timezone_minutes`]: crate::parser::Parser::parse_timezone_minutes
+    pub fn with_timezone_minutes(self, minutes: i32) -> Result<ParsedDateTime, ValueError> {
+        if minutes.unsigned_abs() > MAX_TIMEZONE_OFFSET_MINUTES.unsigned_abs() {
+            return Err(ValueError(format!(
+                "a timezone offset of {} minutes is out of range, it must be at most {} minutes \
+                 from UTC",
+                minutes, MAX_TIMEZONE_OFFSET_MINUTES
+            )));
+        }
+        Ok(ParsedDateTime {
+            timezone_offset_second: Some(i64::from(minutes) * 60),
+            ..self
+        })
+    }
+
+    /// Whether the timezone offset is a whole number of minutes
+    ///
+    /// Returns `None` if there is no timezone offset.
+    pub fn timezone_is_whole_minutes(&self) -> Option<bool> {
+        self.timezone_offset_second.map(|offset| offset % 60 == 0)
+    }
+
+    /// The leading field of the qualifier this value was parsed with
+    ///
+    /// This is `None` unless the value was parsed with
+    /// [`DateTimeParseOptions::record_qualifier`]. Along with
+    /// [`ParsedDateTime::trailing_field`] it gives back the qualifier, so that
+    /// the value can be written out again as `INTERVAL '...' DAY TO SECOND`.
+    ///
+    /// [`DateTimeParseOptions::record_qualifier`]: crate::parser::DateTimeParseOptions::record_qualifier
+    pub fn leading_field(&self) -> Option<&DateTimeField> {
+        self.leading_field.as_ref()
+    }
+
+    /// The trailing field of the qualifier this value was parsed with
+    ///
+    /// This is `None` unless the value was parsed with a
+    /// [`DateTimeParseOptions::last_field`] and
+    /// [`DateTimeParseOptions::record_qualifier`].
+    ///
+    /// [`DateTimeParseOptions::last_field`]: crate::parser::DateTimeParseOptions::last_field
+    /// [`DateTimeParseOptions::record_qualifier`]: crate::parser::DateTimeParseOptions::record_qualifier
+    pub fn trailing_field(&self) -> Option<&DateTimeField> {
+        self.trailing_field.as_ref()
+    }
+
+    /// Turn a time of `24:00:00` into `00:00:00` of the next day
+    ///
+    /// A value with a valid year, month and day moves through the Gregorian
+    /// calendar, so `2020-12-31 24:00:00` becomes `2021-01-01 00:00:00`.
+    /// Otherwise the day is counted up by one, from zero if there is none.
+    /// A value at any other time is left alone.
+    pub(crate) fn roll_over_end_of_day(&mut self) {
+        if self.hour != Some(24) {
+            return;
+        }
+        self.hour = Some(0);
+        let calendar = CalendarKind::Gregorian;
+        let date = match (self.year, self.month, self.day) {
+            (Some(year), Some(month), Some(day)) => {
+                match (i64::try_from(year), u8::try_from(month), u8::try_from(day)) {
+                    (Ok(year), Ok(month), Ok(day))
+                        if (1..=12).contains(&month)
+                            && day >= 1
+                            && day <= calendar.days_in_month(year, month) =>
+                    {
+                        Some((year, month, day))
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+        match date {
+            Some((year, month, day)) if day < calendar.days_in_month(year, month) => {
+                self.day = Some(u64::from(day) + 1);
+            }
+            Some((_, month, _)) if month < 12 => {
+                self.month = Some(u64::from(month) + 1);
+                self.day = Some(1);
+            }
+            Some((year, _, _)) => {
+                self.year = Some(year as u64 + 1);
+                self.month = Some(1);
+                self.day = Some(1);
+            }
+            None => self.day = Some(self.day.unwrap_or(0) + 1),
+        }
+    }
+
+    /// This value at UTC, with its timezone offset folded into its time
+    ///
+    /// The offset is subtracted from the hours, minutes and seconds, which
+    /// are then brought back to between `00:00` and `24:00` as a clock would
+    /// be, and the result has no offset. The day that takes is carried:
+    ///
+    /// * A value with a year, month and day, as from a timestamp, moves
+    ///   through the Gregorian calendar, so `2020-01-01 02:00 +05:30` becomes
+    ///   `2019-12-31 20:30`.
+    /// * A value with a day but no year or month moves by a day, so
+    ///   `1 02:00 +05:30` becomes `0 20:30`.
+    /// * A value without a day is a time of day, and wraps past midnight, so
+    ///   `02:00 +05:30` becomes `20:30`.
+    ///
+    /// A value without an offset is returned as it is.
+    ///
+    /// # Errors
+    ///
+    /// If the value or any of its parts is negative, if its date is not valid or would move by
+    /// more than a day, or if its day would go below zero.
+    pub fn to_utc(&self) -> Result<ParsedDateTime, ValueError> {
+        const SECONDS_PER_DAY: i128 = 24 * 60 * 60;
+        let offset = match self.timezone_offset_second {
+            Some(offset) => offset,
+            None => return Ok(self.clone()),
+        };
+        if self.signs() != PartSigns::all(true) {
+            return Err(ValueError(format!(
+                "{:?} is negative, or has a negative part, so it has no time at UTC",
+                self
+            )));
+        }
+        let field = |field: Option<u64>| i128::from(field.unwrap_or(0));
+        let seconds = (field(self.hour) * 60 + field(self.minute)) * 60 + field(self.second)
+            - i128::from(offset);
+        let carry = seconds.div_euclid(SECONDS_PER_DAY);
+        let seconds = seconds.rem_euclid(SECONDS_PER_DAY) as u64;
+        let mut utc = ParsedDateTime {
+            hour: Some(seconds / (60 * 60)),
+            minute: Some(seconds / 60 % 60),
+            second: Some(seconds % 60),
+            timezone_offset_second: None,
+            ..self.clone()
+        };
+        match (self.year, self.month, self.day) {
+            (_, _, None) => {}
+            (Some(year), Some(month), Some(day)) => {
+                let calendar = CalendarKind::Gregorian;
+                let invalid = || {
+                    ValueError(format!(
+                        "{}-{:02}-{:02} is not a valid date",
+                        year, month, day
+                    ))
+                };
+                let (mut year, mut month, mut day) =
+                    match (i64::try_from(year), u8::try_from(month), u8::try_from(day)) {
+                        (Ok(year), Ok(month), Ok(day))
+                            if (1..=12).contains(&month)
+                                && day >= 1
+                                && day <= calendar.days_in_month(year, month) =>
+                        {
+                            (year, month, day)
+                        }
+                        _ => return Err(invalid()),
+                    };
+                match carry {
+                    0 => {}
+                    1 if day < calendar.days_in_month(year, month) => day += 1,
+                    1 if month < 12 => {
+                        month += 1;
+                        day = 1;
+                    }
+                    1 => {
+                        year += 1;
+                        month = 1;
+                        day = 1;
+                    }
+                    -1 if day > 1 => day -= 1,
+                    -1 => {
+                        if month > 1 {
+                            month -= 1;
+                        } else {
+                            year -= 1;
+                            month = 12;
+                        }
+                        day = calendar.days_in_month(year, month);
+                    }
+                    _ => {
+                        return Err(ValueError(format!(
+                            "{:?} is {} days from its date at UTC, but it may only move by one",
+                            self, carry
+                        )))
+                    }
+                }
+                utc.year = Some(u64::try_from(year).map_err(|_| invalid())?);
+                utc.month = Some(u64::from(month));
+                utc.day = Some(u64::from(day));
+            }
+            (_, _, Some(day)) => {
+                utc.day = Some(u64::try_from(i128::from(day) + carry).map_err(|_| {
+                    ValueError(format!(
+                        "{:?} is {} days from its day at UTC, which is before day 0",
+                        self, carry
+                    ))
+                })?);
+            }
+        }
+        Ok(utc)
+    }
+
+    /// The timestamp `ts` as it reads at this value's timezone offset, as
+    /// for `ts AT TIME ZONE '+05:30'`
+    ///
+    /// This is the same instant, moved to another offset, so
+    /// `2020-01-01 22:00:00 +00:00` becomes `2020-01-02 03:30:00 +05:30`, and
+    /// `2020-01-01 14:00:00 -08:00`. A `TIMESTAMP` without a timezone is
+    /// taken to be at UTC by giving it an offset of zero first. Only the
+    /// offset of this value is used, so it is typically one parsed with
+    /// [`Parser::parse_timestamp_string`] or given one with
+    /// [`with_timezone_minutes`].
+    ///
+    /// # Errors
+    ///
+    /// If this value has no timezone offset, or one that is a day or more.
+    ///
+    /// [`Parser::parse_timestamp_string`]: crate::parser::Parser::parse_timestamp_string
+    /// [`with_timezone_minutes`]: ParsedDateTime::with_timezone_minutes
+    #[cfg(feature = "chrono")]
+    pub fn apply_timezone_to(
+        &self,
+        ts: &DateTime<FixedOffset>,
+    ) -> Result<DateTime<FixedOffset>, ValueError> {
+        let offset = self.timezone_offset_second.ok_or_else(|| {
+            ValueError(format!(
+                "{:?} has no timezone offset to apply to a timestamp",
+                self
+            ))
+        })?;
+        let offset = i32::try_from(offset)
+            .ok()
+            .and_then(FixedOffset::east_opt)
+            .ok_or_else(|| {
+                ValueError(format!(
+                    "the timezone offset of {:?} is not less than a day",
+                    self
+                ))
+            })?;
+        Ok(ts.with_timezone(&offset))
+    }
+
+    /// A copy of this value with only the fractional seconds retained
+    ///
+    /// All of the whole-unit fields and the timezone are cleared, the sign
+    /// of the time is kept. See also [`ParsedDateTime::whole_part`].
+    pub fn fractional_part(&self) -> ParsedDateTime {
+        ParsedDateTime {
+            nano: self.nano,
+            nano_digits: self.nano_digits,
+            ..ParsedDateTime::with_sign(self.signs().time)
+        }
+    }
+
+    /// A copy of this value with the fractional seconds cleared
+    pub fn whole_part(&self) -> ParsedDateTime {
+        ParsedDateTime {
+            nano: None,
+            nano_digits: None,
+            ..self.clone()
+        }
+    }
+
+    /// Split this value into its date part and its time part
+    ///
+    /// The date part has the years, months and days, and the time part the
+    /// hours, minutes, seconds, fractional seconds and the timezone. Both
+    /// parts keep their signs, so that they can be applied to the date and
+    /// the time of a timestamp separately.
+    pub fn split(&self) -> (ParsedDateTime, ParsedDateTime) {
+        let date = ParsedDateTime {
+            year: self.year,
+            month: self.month,
+            day: self.day,
+            ..ParsedDateTime::positive()
+        };
+        let time = ParsedDateTime {
+            year: None,
+            month: None,
+            day: None,
+            ..self.clone()
+        };
+        (
+            date.with_part_signs(self.signs()),
+            time.with_part_signs(self.signs()),
+        )
+    }
+
+    /// This value as an `INTERVAL` literal with the qualifier `leading TO
+    /// trailing`, like `INTERVAL '1 2:03:04.5' DAY TO SECOND`
+    ///
+    /// The value is written in the positional layout of the qualifier, so
+    /// that the parser reads it back as this value. It has the fields from
+    /// `leading` down to `trailing`, or without a `trailing`, down to the
+    /// smallest field that is set. Fields larger than `leading` are folded
+    /// into it, so a day and 2 hours is `'26' HOUR`, and fields smaller than
+    /// `trailing` are left out. One literal can't have both months and days,
+    /// see [`ParsedDateTime::split`], so only the years and months are
+    /// written for a `YEAR` or `MONTH` qualifier, and only the days and time
+    /// for the others. `QUARTER` and `WEEK` are written as `MONTH` and `DAY`.
+    ///
+    /// The positional layout has a single sign, so if the days and the time
+    /// have different [signs](ParsedDateTime::part_signs), they are written
+    /// in the PostgreSQL style without a qualifier instead, like
+    /// `INTERVAL '1 day -03:00:00'`.
+    pub fn to_qualified_sql(
+        &self,
+        leading: DateTimeField,
+        trailing: Option<DateTimeField>,
+    ) -> String {
+        use DateTimeField::*;
+        // the fields in order, and their sizes in the next smaller field
+        const FIELDS: [(DateTimeField, u64); 6] = [
+            (Year, 0),
+            (Month, 12),
+            (Day, 0),
+            (Hour, 24),
+            (Minute, 60),
+            (Second, 60),
+        ];
+        let index = |field: &DateTimeField| match field {
+            Year => 0,
+            Quarter | Month => 1,
+            Week | Day => 2,
+            Hour => 3,
+            Minute => 4,
+            Second => 5,
+        };
+        let values = [
+            self.year,
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second.or_else(|| self.nano.map(|_| 0)),
+        ];
+        let first = index(&leading);
+        let group = if first < 2 { 0..2 } else { 2..6 };
+        let part = match first < 2 {
+            true => ParsedDateTime {
+                year: self.year,
+                month: self.month,
+                ..ParsedDateTime::positive()
+            },
+            false => ParsedDateTime {
+                year: None,
+                month: None,
+                ..self.clone()
+            },
+        }
+        .with_part_signs(self.signs());
+        if part.has_mixed_signs() {
+            return format!("INTERVAL '{}'", part.to_style(IntervalStyle::Postgres));
+        }
+        let last = match &trailing {
+            Some(trailing) => index(trailing),
+            None => (first..group.end)
+                .rev()
+                .find(|i| values[*i].is_some())
+                .unwrap_or(first),
+        }
+        .max(first)
+        .min(group.end - 1);
+
+        let mut folded = 0u64;
+        for (value, (_, size)) in values
+            .iter()
+            .zip(FIELDS.iter())
+            .take(first + 1)
+            .skip(group.start)
+        {
+            folded = folded
+                .saturating_mul(*size)
+                .saturating_add(value.unwrap_or(0));
+        }
+        let mut out = String::new();
+        if !part.is_positive && !part.is_zero() {
+            out.push('-');
+        }
+        for (i, value) in values.iter().enumerate().take(last + 1).skip(first) {
+            let value = if i == first {
+                folded
+            } else {
+                value.unwrap_or(0)
+            };
+            match i {
+                _ if i == first => {}
+                1 => out.push('-'),
+                3 => out.push(' '),
+                _ => out.push(':'),
+            }
+            let text = match i {
+                5 => ParsedDateTime {
+                    second: Some(value),
+                    ..self.clone()
+                }
+                .seconds_literal()
+                .unwrap_or_default(),
+                _ => value.to_string(),
+            };
+            if i > first && i > 3 && value < 10 {
+                out.push('0');
+            }
+            out += &text;
+        }
+        let qualifier = match first == last {
+            true => FIELDS[first].0.to_string(),
+            false => format!("{} TO {}", FIELDS[first].0, FIELDS[last].0),
+        };
+        format!("INTERVAL '{}' {}", out, qualifier)
+    }
+
+    /// Carry overflowing time components into the next larger unit
+    ///
+    /// Nanoseconds carry into seconds, seconds into minutes and minutes into
+    /// hours, so `90` seconds becomes `1` minute `30` seconds. Hours are not
+    /// carried into days because a day is not always 24 hours, and years and
+    /// months are left as they are because the length of a month is
+    /// ambiguous.
+    ///
+    /// The sign applies to the whole value, so negative intervals are
+    /// normalized by magnitude and keep their sign. A field that receives a
+    /// carry is set even if it was previously `None`, and hours saturate at
+    /// `u64::MAX`.
+    pub fn normalize(&self) -> ParsedDateTime {
+        let mut pdt = self.clone();
+        if let Some(nano) = pdt.nano {
+            if nano >= 1_000_000_000 {
+                pdt.nano = Some(nano % 1_000_000_000);
+                pdt.second = Some(
+                    pdt.second
+                        .unwrap_or(0)
+                        .saturating_add(u64::from(nano / 1_000_000_000)),
+                );
+            }
+        }
+        carry(&mut pdt.second, &mut pdt.minute, 60);
+        carry(&mut pdt.minute, &mut pdt.hour, 60);
+        pdt
+    }
+
+    /// Carry every 24 hours into a day, like PostgreSQL's `justify_hours`
+    ///
+    /// The time is [normalized](ParsedDateTime::normalize) first, so that
+    /// `'27 hours'` becomes `1 day 03:00:00` and `'1500 minutes'` becomes
+    /// `1 day 01:00:00`. Like `normalize`, this keeps the sign, sets a field
+    /// that receives a carry and saturates at `u64::MAX`.
+    ///
+    /// If the days and the time have different
+    /// [signs](ParsedDateTime::part_signs), the days carried from the time
+    /// are added to the days, and then a day is borrowed so that they have
+    /// the same sign, so `'2 days -3 hours'` becomes `1 day 21:00:00`.
+    pub fn justify_hours(&self) -> ParsedDateTime {
+        let mut pdt = self.normalize();
+        if !pdt.has_mixed_signs() {
+            carry(&mut pdt.hour, &mut pdt.day, 24);
+            return pdt;
+        }
+        // the days carried from the time have the sign of the time
+        let (date, mut time) = pdt.split();
+        carry(&mut time.hour, &mut time.day, 24);
+        let pdt = date.combine(&time, 1).unwrap_or(pdt);
+        let nonzero = pdt.day.unwrap_or(0) != 0 && pdt.has_time();
+        pdt.borrow(DateTimeField::Day, DateTimeField::Hour, 24, nonzero)
+    }
+
+    /// Carry every 30 days into a month, like PostgreSQL's `justify_days`
+    ///
+    /// So `'35 days'` becomes `1 mon 5 days`. PostgreSQL keeps the years and
+    /// months as a single count of months, so 12 months are carried into a
+    /// year as well, and `'400 days'` becomes `1 year 1 mon 10 days`. The
+    /// time is left as it is.
+    ///
+    /// Like [`ParsedDateTime::justify_hours`], if the months and the days
+    /// have different signs, a month is borrowed so that they have the same
+    /// sign, so `'1 mon -1 day'` becomes `29 days`.
+    pub fn justify_days(&self) -> ParsedDateTime {
+        let mut pdt = self.clone();
+        if pdt.has_mixed_signs() {
+            // the months carried from the days have the sign of the days
+            let mut days = ParsedDateTime {
+                day: pdt.day,
+                ..ParsedDateTime::with_sign(pdt.signs().days)
+            };
+            carry(&mut days.day, &mut days.month, 30);
+            let rest = ParsedDateTime {
+                day: None,
+                ..pdt.clone()
+            }
+            .with_part_signs(pdt.signs());
+            pdt = rest.combine(&days, 1).unwrap_or(pdt);
+            let nonzero = (pdt.year.unwrap_or(0) != 0 || pdt.month.unwrap_or(0) != 0)
+                && pdt.day.unwrap_or(0) != 0;
+            pdt = pdt.borrow(DateTimeField::Month, DateTimeField::Day, 30, nonzero);
+        }
+        carry(&mut pdt.day, &mut pdt.month, 30);
+        carry(&mut pdt.month, &mut pdt.year, 12);
+        pdt
+    }
+
+    /// Both [`justify_hours`] and then [`justify_days`], like PostgreSQL's
+    /// `justify_interval`
+    ///
+    /// So the days from the hours are carried into the months too:
+    /// `'29 days 27 hours'` becomes `1 mon 03:00:00`.
+    ///
+    /// [`justify_hours`]: ParsedDateTime::justify_hours
+    /// [`justify_days`]: ParsedDateTime::justify_days
+    pub fn justify_interval(&self) -> ParsedDateTime {
+        self.justify_hours().justify_days()
+    }
+
+    /// The forward interval from this clock time to a `later` one
+    ///
+    /// Both values are treated as times of day, so if `later` is earlier than
+    /// `self` the interval wraps past midnight: `23:00` until `01:00` is two
+    /// hours. The result has its hour, minute and second set, and the
+    /// nanoseconds if either input had them.
+    ///
+    /// # Errors
+    ///
+    /// If either value is negative, has a year, month or day, or is not a
+    /// valid time of day.
+    pub fn until(&self, later: &ParsedDateTime) -> Result<ParsedDateTime, ValueError> {
+        const NANOS_PER_DAY: u128 = 24 * 60 * 60 * NANOS_PER_SECOND;
+
+        fn clock_nanos(pdt: &ParsedDateTime) -> Result<u128, ValueError> {
+            if !pdt.is_positive || pdt.year.is_some() || pdt.month.is_some() || pdt.day.is_some() {
+                return Err(ValueError(format!(
+                    "{:?} is not a time of day: it must be positive and have no date fields",
+                    pdt
+                )));
+            }
+            let (hour, minute, second) = (
+                pdt.hour.unwrap_or(0),
+                pdt.minute.unwrap_or(0),
+                pdt.second.unwrap_or(0),
+            );
+            if hour > 23 || minute > 59 || second > 59 {
+                return Err(ValueError(format!(
+                    "{:02}:{:02}:{:02} is not a valid time of day",
+                    hour, minute, second
+                )));
+            }
+            Ok(
+                u128::from(hour * 60 * 60 + minute * 60 + second) * NANOS_PER_SECOND
+                    + u128::from(pdt.nano.unwrap_or(0)),
+            )
+        }
+
+        let start = clock_nanos(self)?;
+        let end = clock_nanos(later)?;
+        let nanos = (end + NANOS_PER_DAY - start) % NANOS_PER_DAY;
+        let seconds = (nanos / NANOS_PER_SECOND) as u64;
+        Ok(ParsedDateTime {
+            hour: Some(seconds / (60 * 60)),
+            minute: Some(seconds / 60 % 60),
+            second: Some(seconds % 60),
+            nano: if self.nano.is_some() || later.nano.is_some() {
+                Some((nanos % NANOS_PER_SECOND) as u32)
+            } else {
+                None
+            },
+            ..Default::default()
+        })
+    }
+
+    /// The interval from `start` to `end` in years, months, days and time,
+    /// like PostgreSQL's `age(end, start)`
+    ///
+    /// Each field of `start` is subtracted from the same field of `end`, and
+    /// a field that comes out negative borrows from the next larger one. A
+    /// month borrowed into the days is as long as the month of the earlier
+    /// timestamp, so from `2020-01-31` to `2020-03-01` is `1 mon 1 day`. If
+    /// `end` is before `start` the result is the same interval the other way
+    /// around, with a negative sign. Every field of the result is set, except
+    /// the nanoseconds when neither timestamp has any. A leap second is
+    /// counted as the 60th second of its minute.
+    ///
+    /// # Errors
+    ///
+    /// If the month of the earlier timestamp is the last one chrono has.
+    #[cfg(feature = "chrono")]
+    pub fn between(
+        start: &NaiveDateTime,
+        end: &NaiveDateTime,
+    ) -> Result<ParsedDateTime, ValueError> {
+        let is_positive = start <= end;
+        let (earlier, later) = if is_positive {
+            (start, end)
+        } else {
+            (end, start)
+        };
+        // chrono keeps a leap second in the nanoseconds
+        let second_and_nano = |ts: &NaiveDateTime| {
+            let nano = i64::from(ts.nanosecond());
+            let leap = nano / NANOS_PER_SECOND as i64;
+            (
+                i64::from(ts.second()) + leap,
+                nano % NANOS_PER_SECOND as i64,
+            )
+        };
+        let (later_second, later_nano) = second_and_nano(later);
+        let (earlier_second, earlier_nano) = second_and_nano(earlier);
+
+        let mut nano = later_nano - earlier_nano;
+        let mut second = later_second - earlier_second;
+        let mut minute = i64::from(later.minute()) - i64::from(earlier.minute());
+        let mut hour = i64::from(later.hour()) - i64::from(earlier.hour());
+        let mut day = i64::from(later.day()) - i64::from(earlier.day());
+        let mut month = i64::from(later.month()) - i64::from(earlier.month());
+        let mut year = i64::from(later.year()) - i64::from(earlier.year());
+        let borrow = |smaller: &mut i64, larger: &mut i64, size: i64| {
+            if *smaller < 0 {
+                *smaller += size;
+                *larger -= 1;
+            }
+        };
+        borrow(&mut nano, &mut second, NANOS_PER_SECOND as i64);
+        borrow(&mut second, &mut minute, 60);
+        borrow(&mut minute, &mut hour, 60);
+        borrow(&mut hour, &mut day, 24);
+        let first_of_month = earlier.date().with_day(1).expect("every month has a day 1");
+        let earlier_month_days = first_of_month
+            .checked_add_months(Months::new(1))
+            .map(|next| next.signed_duration_since(first_of_month).num_days())
+            .ok_or_else(|| {
+                ValueError(format!(
+                    "cannot take the interval between {} and {}: the month of {} is the last \
+                     one there is",
+                    start, end, earlier
+                ))
+            })?;
+        borrow(&mut day, &mut month, earlier_month_days);
+        borrow(&mut month, &mut year, 12);
+
+        // `later` is not before `earlier`, so nothing is left negative
+        let field = |value: i64| Some(value as u64);
+        Ok(ParsedDateTime {
+            is_positive,
+            year: field(year),
+            month: field(month),
+            day: field(day),
+            hour: field(hour),
+            minute: field(minute),
+            second: field(second),
+            nano: if earlier_nano != 0 || later_nano != 0 {
+                Some(nano as u32)
+            } else {
+                None
+            },
+            ..Default::default()
+        })
+    }
+
+    /// Whether two values are the same interval, however they were written
+    ///
+    /// The derived `PartialEq` compares the fields as they were parsed, so
+    /// `'90 seconds'` and `'1:30'` are different values. Here the years are
+    /// folded into the months and the hours, minutes and seconds into a
+    /// single span of time, with the sign applied, so those two are equal,
+    /// and so are `-0` and `0`. Fields that are unset count as zero, and how
+    /// many digits the fraction was written with is ignored.
+    ///
+    /// Days are kept apart from the time, because a day is not always 24
+    /// hours, and so are the months. See [`ParsedDateTime::cmp_approx`] for a
+    /// comparison that treats them as fixed lengths.
+    pub fn semantically_eq(&self, other: &ParsedDateTime) -> bool {
+        fn parts(pdt: &ParsedDateTime) -> (i128, i128, i128, Option<i64>) {
+            let field = |field: Option<u64>| i128::from(field.unwrap_or(0));
+            let sign = |field| i128::from(pdt.part_positivity(&field));
+            let months = field(pdt.year) * 12 + field(pdt.month);
+            let nanos = ((field(pdt.hour) * 60 + field(pdt.minute)) * 60 + field(pdt.second))
+                * NANOS_PER_SECOND as i128
+                + i128::from(pdt.nano.unwrap_or(0));
+            (
+                sign(DateTimeField::Month) * months,
+                sign(DateTimeField::Day) * field(pdt.day),
+                sign(DateTimeField::Second) * nanos,
+                pdt.timezone_offset_second,
+            )
+        }
+        parts(self) == parts(other)
+    }
+
+    /// Compare the magnitudes of two intervals, taking their signs into account
+    ///
+    /// This is **approximate** for intervals with calendar fields: like
+    /// PostgreSQL, a month is treated as 30 days and a year as 12 months, so
+    /// `1 month` and `30 days` compare as equal. It is deliberately not an
+    /// implementation of `Ord`, which would have to agree with `PartialEq`,
+    /// and here `1 day` and `24 hours` are equal.
+    ///
+    /// The timezone offset is ignored.
+    pub fn cmp_approx(&self, other: &ParsedDateTime) -> Ordering {
+        self.approx_nanos().cmp(&other.approx_nanos())
+    }
+
+    /// The signed length of this interval in seconds, for logs and metrics
+    ///
+    /// This is **approximate**: a year is counted as 365 days and a month as
+    /// 30 days, so it's not the same as [`cmp_approx`], which has 360 day
+    /// years, and shouldn't be used for arithmetic. Very long intervals also
+    /// lose precision in the nanoseconds, as an `f64` only has 53 bits. The
+    /// timezone offset is ignored.
+    ///
+    /// [`cmp_approx`]: ParsedDateTime::cmp_approx
+    pub fn approx_total_seconds(&self) -> f64 {
+        const SECONDS_PER_DAY: f64 = 24.0 * 60.0 * 60.0;
+        let field = |field: Option<u64>| field.unwrap_or(0) as f64;
+        let sign = |field| self.part_positivity(&field) as f64;
+        let months =
+            field(self.year) * 365.0 * SECONDS_PER_DAY + field(self.month) * 30.0 * SECONDS_PER_DAY;
+        let time = field(self.hour) * 60.0 * 60.0
+            + field(self.minute) * 60.0
+            + field(self.second)
+            + f64::from(self.nano.unwrap_or(0)) / NANOS_PER_SECOND as f64;
+        sign(DateTimeField::Month) * months
+            + sign(DateTimeField::Day) * field(self.day) * SECONDS_PER_DAY
+            + sign(DateTimeField::Second) * time
+    }
+
+    /// Compare two intervals by their lengths under the `policy`
+    ///
+    /// With [`MonthPolicy::Days30`] this is [`cmp_approx`].
+    ///
+    /// # Errors
+    ///
+    /// If [`to_duration`] fails for either value.
+    ///
+    /// [`cmp_approx`]: ParsedDateTime::cmp_approx
+    /// [`to_duration`]: ParsedDateTime::to_duration
+    pub fn cmp_with(
+        &self,
+        other: &ParsedDateTime,
+        policy: &MonthPolicy,
+    ) -> Result<Ordering, ValueError> {
+        Ok(self
+            .total_nanos_with(policy)?
+            .cmp(&other.total_nanos_with(policy)?))
+    }
+
+    /// How long this interval is, with its months as long as the `policy`
+    /// says
+    ///
+    /// The length is returned without its sign, which is in
+    /// [`is_positive`](ParsedDateTime::is_positive), or if the parts have
+    /// different [signs](ParsedDateTime::part_signs), is the sign of their
+    /// total.
+    ///
+    /// # Errors
+    ///
+    /// If the length doesn't fit in a `Duration`, or for a
+    /// `MonthPolicy::AnchorDate` if `resolve_against` fails, with the
+    /// `chrono` feature.
+    pub fn to_duration(&self, policy: &MonthPolicy) -> Result<Duration, ValueError> {
+        match policy {
+            MonthPolicy::Days30 => {
+                let nanos = self.approx_nanos().unsigned_abs();
+                let seconds = u64::try_from(nanos / NANOS_PER_SECOND)
+                    .map_err(|_| ValueError(format!("{:?} is out of range", self)))?;
+                Ok(Duration::new(seconds, (nanos % NANOS_PER_SECOND) as u32))
+            }
+            #[cfg(feature = "chrono")]
+            MonthPolicy::AnchorDate(anchor) => self.resolve_against(anchor),
+        }
+    }
+
+    /// The signed number of nanoseconds in this value under the `policy`
+    fn total_nanos_with(&self, policy: &MonthPolicy) -> Result<i128, ValueError> {
+        match policy {
+            MonthPolicy::Days30 => Ok(self.approx_nanos()),
+            #[cfg(feature = "chrono")]
+            MonthPolicy::AnchorDate(anchor) => self.resolve_nanos_against(anchor),
+        }
+    }
+
+    /// The largest time unit in this value and roughly how many of it there are
+    ///
+    /// This is meant for compact displays like "~2 hours": after
+    /// [normalizing](ParsedDateTime::normalize), the largest day or time field
+    /// that is at least `1` is returned, with everything smaller rounded
+    /// (half up) into it. So `1:45:00` is `(Hour, 2)` and `0:20:00` is
+    /// `(Minute, 20)`. The sign is not included in the count.
+    ///
+    /// Returns `None` if nothing is at least one second, or if the value has
+    /// years or months, which have no fixed length.
+    pub fn dominant_component(&self) -> Option<(DateTimeField, u64)> {
+        if self.year.unwrap_or(0) != 0 || self.month.unwrap_or(0) != 0 {
+            return None;
+        }
+        // the days and the time are brought to the same sign first
+        let pdt = match self.has_mixed_signs() {
+            true => self.justify_hours(),
+            false => self.normalize(),
+        };
+        let mut fields = vec![
+            (DateTimeField::Day, pdt.day),
+            (DateTimeField::Hour, pdt.hour),
+            (DateTimeField::Minute, pdt.minute),
+            (DateTimeField::Second, pdt.second),
+        ]
+        .into_iter();
+        let (field, count) = fields.find_map(|(field, value)| match value {
+            Some(value) if value >= 1 => Some((field, value)),
+            _ => None,
+        })?;
+        let remainder = fields
+            .map(|(field, value)| {
+                u128::from(value.unwrap_or(0)) * u128::from(seconds_multiplier(&field))
+            })
+            .sum::<u128>()
+            * NANOS_PER_SECOND
+            + u128::from(pdt.nano.unwrap_or(0));
+        let unit = u128::from(seconds_multiplier(&field)) * NANOS_PER_SECOND;
+        let rounded = (remainder + unit / 2) / unit;
+        Some((field, count.saturating_add(rounded as u64)))
+    }
+
+    /// This value rounded down to a whole number of `field`
+    ///
+    /// Everything finer than `field` is folded into it and the remainder is
+    /// dropped, so `1:45:00` floored to `Hour` is `1` hour and `1:90:00` is
+    /// `2` hours. That is different from zeroing the finer fields, which
+    /// would make the latter `1` hour as well. Coarser fields are kept as
+    /// they are, and the finer fields are cleared.
+    ///
+    /// Rounding is towards negative infinity, so `-1:45:00` floors to `-2`
+    /// hours. Folding days into months uses the same 30 day approximation as
+    /// [`ParsedDateTime::cmp_approx`]. See also [`ParsedDateTime::ceil_to`].
+    pub fn floor_to(&self, field: DateTimeField) -> ParsedDateTime {
+        self.round_to(field, false)
+    }
+
+    /// This value rounded up to a whole number of `field`
+    ///
+    /// Like [`ParsedDateTime::floor_to`], except that any remainder rounds
+    /// towards positive infinity: `1:45:00` ceiled to `Hour` is `2` hours and
+    /// `-1:45:00` is `-1` hour.
+    pub fn ceil_to(&self, field: DateTimeField) -> ParsedDateTime {
+        self.round_to(field, true)
+    }
+
+    /// The signed number of months in the years and months, with a year as
+    /// 12 months
+    ///
+    /// This and [`days`] and [`total_micros`] are the three parts PostgreSQL
+    /// keeps an interval in, see [`IntervalParts`].
+    ///
+    /// [`days`]: ParsedDateTime::days
+    /// [`total_micros`]: ParsedDateTime::total_micros
+    pub fn total_months(&self) -> Result<i64, ValueError> {
+        let months = i128::from(self.year.unwrap_or(0))
+            .checked_mul(12)
+            .and_then(|months| months.checked_add(i128::from(self.month.unwrap_or(0))));
+        self.signed_part(DateTimeField::Month, months)
+    }
+
+    /// The signed number of days, which are not folded into the other parts
+    pub fn days(&self) -> Result<i64, ValueError> {
+        self.signed_part(DateTimeField::Day, Some(i128::from(self.day.unwrap_or(0))))
+    }
+
+    /// The signed number of microseconds in the hours, minutes, seconds and
+    /// fractional seconds
+    ///
+    /// Nanoseconds that don't make up a whole microsecond are truncated.
+    pub fn total_micros(&self) -> Result<i64, ValueError> {
+        let micros = [
+            (self.hour, 60 * 60 * 1_000_000),
+            (self.minute, 60 * 1_000_000),
+            (self.second, 1_000_000),
+        ]
+        .iter()
+        .try_fold(
+            i128::from(self.nano.unwrap_or(0) / 1_000),
+            |micros, (value, multiplier)| {
+                i128::from(value.unwrap_or(0))
+                    .checked_mul(*multiplier)
+                    .and_then(|value| micros.checked_add(value))
+            },
+        );
+        self.signed_part(DateTimeField::Second, micros)
+    }
+
+    /// The years with the sign of the value applied, `0` if unset
+    ///
+    /// There is an accessor like this for every field, so that the sign in
+    /// [`is_positive`] can't be forgotten. For the days it is [`days`]. If
+    /// the parts have different [signs](ParsedDateTime::part_signs), each
+    /// field has the sign of its part, so `'2 days -3 hours'` has `-3` hours.
+    ///
+    /// [`is_positive`]: ParsedDateTime::is_positive
+    /// [`days`]: ParsedDateTime::days
+    pub fn signed_years(&self) -> Result<i64, ValueError> {
+        self.signed_part(
+            DateTimeField::Year,
+            Some(i128::from(self.year.unwrap_or(0))),
+        )
+    }
+
+    /// The months with the sign of the value applied, `0` if unset
+    pub fn signed_months(&self) -> Result<i64, ValueError> {
+        self.signed_part(
+            DateTimeField::Month,
+            Some(i128::from(self.month.unwrap_or(0))),
+        )
+    }
+
+    /// The hours with the sign of the value applied, `0` if unset
+    pub fn signed_hours(&self) -> Result<i64, ValueError> {
+        self.signed_part(
+            DateTimeField::Hour,
+            Some(i128::from(self.hour.unwrap_or(0))),
+        )
+    }
+
+    /// The minutes with the sign of the value applied, `0` if unset
+    pub fn signed_minutes(&self) -> Result<i64, ValueError> {
+        self.signed_part(
+            DateTimeField::Minute,
+            Some(i128::from(self.minute.unwrap_or(0))),
+        )
+    }
+
+    /// The whole seconds with the sign of the value applied, `0` if unset
+    pub fn signed_seconds(&self) -> Result<i64, ValueError> {
+        self.signed_part(
+            DateTimeField::Second,
+            Some(i128::from(self.second.unwrap_or(0))),
+        )
+    }
+
+    /// The fraction of a second in nanoseconds with the sign of the value
+    /// applied, `0` if unset
+    ///
+    /// So `-1.5` seconds has `-1` seconds and `-500_000_000` nanoseconds.
+    pub fn signed_nanos(&self) -> i64 {
+        self.part_positivity(&DateTimeField::Second) * i64::from(self.nano.unwrap_or(0))
+    }
+
+    /// Apply the sign of the part `field` is in to a `magnitude` in that
+    /// part, if it fits in an `i64`
+    fn signed_part(
+        &self,
+        field: DateTimeField,
+        magnitude: Option<i128>,
+    ) -> Result<i64, ValueError> {
+        let sign = i128::from(self.part_positivity(&field));
+        magnitude
+            .and_then(|magnitude| i64::try_from(sign * magnitude).ok())
+            .ok_or_else(|| ValueError(format!("interval out of range: {:?}", self)))
+    }
+
+    /// A copy of this value with the years and months converted into days
+    ///
+    /// Months don't have a fixed number of days, so they are counted from
+    /// `start` following the `calendar`, forwards for a positive interval
+    /// and backwards for a negative one. Like PostgreSQL, landing past the
+    /// end of a month clamps to its last day, so one month from January 31st
+    /// is 28 or 29 days depending on the year. The days are added to this
+    /// value's own days, with the sign of the months, and the time fields
+    /// are kept.
+    ///
+    /// # Errors
+    ///
+    /// If `start` is not a valid date or the result does not fit.
+    pub fn resolve_days_against(
+        &self,
+        start: &ParsedDate,
+        calendar: CalendarKind,
+    ) -> Result<ParsedDateTime, ValueError> {
+        if start.month < 1
+            || start.month > 12
+            || start.day < 1
+            || start.day > calendar.days_in_month(start.year, start.month)
+        {
+            return Err(ValueError(format!(
+                "{}-{:02}-{:02} is not a valid date in the {:?} calendar",
+                start.year, start.month, start.day, calendar
+            )));
+        }
+        let out_of_range = || ValueError(format!("{:?} is out of range", self));
+        let months = self
+            .year
+            .unwrap_or(0)
+            .checked_mul(12)
+            .and_then(|months| months.checked_add(self.month.unwrap_or(0)))
+            .and_then(|months| i64::try_from(months).ok())
+            .ok_or_else(out_of_range)?;
+        let days = match calendar {
+            CalendarKind::Financial360 => months.checked_mul(30).ok_or_else(out_of_range)?,
+            CalendarKind::Gregorian | CalendarKind::Fixed365 => {
+                let signed = months * self.part_positivity(&DateTimeField::Month);
+                let total = (start.year * 12 + i64::from(start.month) - 1)
+                    .checked_add(signed)
+                    .ok_or_else(out_of_range)?;
+                let (year, month) = (total.div_euclid(12), total.rem_euclid(12) as u8 + 1);
+                let day = start.day.min(calendar.days_in_month(year, month));
+                (calendar.day_number(year, month, day)
+                    - calendar.day_number(start.year, start.month, start.day))
+                .abs()
+            }
+        };
+        // the days from the months have the sign of the months
+        let days = i128::from(self.part_positivity(&DateTimeField::Month)) * i128::from(days)
+            + i128::from(self.part_positivity(&DateTimeField::Day))
+                * i128::from(self.day.unwrap_or(0));
+        let signs = PartSigns {
+            days: days > 0 || (days == 0 && self.signs().days),
+            ..self.signs()
+        };
+        Ok(ParsedDateTime {
+            year: None,
+            month: None,
+            day: Some(u64::try_from(days.unsigned_abs()).map_err(|_| out_of_range())?),
+            ..self.clone()
+        }
+        .with_part_signs(signs))
+    }
+
+    /// This interval as a `Duration`, if it is a non-negative length of time
+    ///
+    /// The hours, minutes, seconds and nanoseconds are added up. Unlike
+    /// [`to_duration`](ParsedDateTime::to_duration) there is nothing to
+    /// assume about the length of a month or a day, because values with
+    /// years, months or days are rejected.
+    ///
+    /// # Errors
+    ///
+    /// If the interval is negative, has years, months or days, or does not
+    /// fit in a `Duration`.
+    pub fn try_into_std_duration(&self) -> Result<Duration, ParserError> {
+        if !self.is_positive && !self.is_zero() {
+            return Err(ParserError::ParserError(format!(
+                "interval {} is negative, but a Duration can't be",
+                self.to_style(IntervalStyle::Postgres)
+            )));
+        }
+        if [self.year, self.month, self.day]
+            .iter()
+            .any(|field| field.unwrap_or(0) != 0)
+        {
+            return Err(ParserError::ParserError(format!(
+                "interval {} has years, months or days, which have no fixed length",
+                self.to_style(IntervalStyle::Postgres)
+            )));
+        }
+        let seconds = self
+            .hour
+            .unwrap_or(0)
+            .checked_mul(60 * 60)
+            .and_then(|s| s.checked_add(self.minute.unwrap_or(0).checked_mul(60)?))
+            .and_then(|s| s.checked_add(self.second.unwrap_or(0)))
+            .ok_or_else(|| {
+                ParserError::ParserError(format!("interval out of range: {:?}", self))
+            })?;
+        Ok(Duration::new(seconds, self.nano.unwrap_or(0)))
+    }
+
+    /// A positive interval as long as `duration`
+    ///
+    /// The seconds are cascaded into the time fields, so that the minutes
+    /// and seconds are below 60 and the rest is in the hours, which are not
+    /// carried into days: 90061.5 seconds is `25:01:01.5`. The hours,
+    /// minutes and seconds are always set, and the nanoseconds only when
+    /// there is a fraction of a second. Use
+    /// [`justify_hours`](ParsedDateTime::justify_hours) to fold whole days
+    /// out of the hours.
+    ///
+    /// This is the reverse of
+    /// [`try_into_std_duration`](ParsedDateTime::try_into_std_duration).
+    pub fn from_std_duration(duration: Duration) -> ParsedDateTime {
+        let seconds = duration.as_secs();
+        let nano = duration.subsec_nanos();
+        ParsedDateTime {
+            hour: Some(seconds / (60 * 60)),
+            minute: Some(seconds / 60 % 60),
+            second: Some(seconds % 60),
+            nano: Some(nano).filter(|nano| *nano != 0),
+            ..ParsedDateTime::positive()
+        }
+    }
+
+    /// How long this interval is when it is counted from `anchor`
+    ///
+    /// Unlike [`cmp_approx`], which counts every month as 30 days, the years
+    /// and months are counted by advancing the calendar from `anchor`: one
+    /// month from February 1st is 28 or 29 days, and one month from January
+    /// 31st ends on the last day of February. A negative interval is counted
+    /// backwards, and the length is returned without its sign, which is in
+    /// [`is_positive`], or if the parts have different
+    /// [signs](ParsedDateTime::part_signs), is the sign of their total.
+    ///
+    /// # Errors
+    ///
+    /// If the months go past the dates chrono has, or the result does not
+    /// fit in a `Duration`.
+    ///
+    /// [`cmp_approx`]: ParsedDateTime::cmp_approx
+    /// [`is_positive`]: ParsedDateTime::is_positive
+    #[cfg(feature = "chrono")]
+    pub fn resolve_against(&self, anchor: &NaiveDate) -> Result<Duration, ValueError> {
+        let nanos = self.resolve_nanos_against(anchor)?.unsigned_abs();
+        let seconds = u64::try_from(nanos / NANOS_PER_SECOND)
+            .map_err(|_| ValueError(format!("{:?} is out of range", self)))?;
+        Ok(Duration::new(seconds, (nanos % NANOS_PER_SECOND) as u32))
+    }
+
+    /// The signed number of nanoseconds in this value counted from `anchor`,
+    /// see [`ParsedDateTime::resolve_against`]
+    #[cfg(feature = "chrono")]
+    fn resolve_nanos_against(&self, anchor: &NaiveDate) -> Result<i128, ValueError> {
+        let out_of_range = || ValueError(format!("{:?} is out of range", self));
+        let months = self
+            .year
+            .unwrap_or(0)
+            .checked_mul(12)
+            .and_then(|months| months.checked_add(self.month.unwrap_or(0)))
+            .and_then(|months| u32::try_from(months).ok())
+            .map(Months::new)
+            .ok_or_else(out_of_range)?;
+        // like PostgreSQL, landing past the end of a month clamps to its
+        // last day
+        let moved = match self.signs().months {
+            true => anchor.checked_add_months(months),
+            false => anchor.checked_sub_months(months),
+        }
+        .ok_or_else(out_of_range)?;
+        let month_days = moved.signed_duration_since(*anchor).num_days();
+        let seconds = |fields: &[(Option<u64>, DateTimeField)]| {
+            fields
+                .iter()
+                .try_fold(0u64, |total, (value, field)| {
+                    value
+                        .unwrap_or(0)
+                        .checked_mul(seconds_multiplier(field))
+                        .and_then(|seconds| total.checked_add(seconds))
+                })
+                .ok_or_else(out_of_range)
+        };
+        let days = seconds(&[(self.day, DateTimeField::Day)])?;
+        let time = seconds(&[
+            (self.hour, DateTimeField::Hour),
+            (self.minute, DateTimeField::Minute),
+            (self.second, DateTimeField::Second),
+        ])?;
+        let nanos_per_second = NANOS_PER_SECOND as i128;
+        let days = i128::from(month_days) * i128::from(seconds_multiplier(&DateTimeField::Day))
+            + i128::from(self.part_positivity(&DateTimeField::Day)) * i128::from(days);
+        let time = i128::from(time) * nanos_per_second + i128::from(self.nano.unwrap_or(0));
+        Ok(days * nanos_per_second
+            + i128::from(self.part_positivity(&DateTimeField::Second)) * time)
+    }
+
+    /// Fold the fields finer than `field` into it, rounding `up` towards
+    /// positive infinity or down towards negative infinity
+    fn round_to(&self, field: DateTimeField, up: bool) -> ParsedDateTime {
+        if self.has_mixed_signs() {
+            return self.round_mixed_to(field, up);
+        }
+        let away_from_zero = up == self.is_positive;
+        let unit = u128::from(approx_seconds_multiplier(&field)) * NANOS_PER_SECOND;
+        let mut remainder = u128::from(self.nano.unwrap_or(0));
+        let mut pdt = ParsedDateTime {
+            nano: None,
+            nano_digits: None,
+            ..self.clone()
+        };
+        for finer in field.clone() {
+            let value = pdt.field_mut(&finer).take().unwrap_or(0);
+            remainder += u128::from(value)
+                * u128::from(approx_seconds_multiplier(&finer))
+                * NANOS_PER_SECOND;
+        }
+        let mut count = remainder / unit;
+        if away_from_zero && remainder % unit != 0 {
+            count += 1;
+        }
+        match field {
+            DateTimeField::Quarter => count *= 3,
+            DateTimeField::Week => count *= 7,
+            _ => {}
+        }
+        let value = pdt.field_mut(&field);
+        *value = Some(
+            value
+                .unwrap_or(0)
+                .saturating_add(count.min(u128::from(u64::MAX)) as u64),
+        );
+        pdt
+    }
+
+    /// [`ParsedDateTime::round_to`] for a value whose parts have different
+    /// signs, which are added up with their signs before rounding
+    fn round_mixed_to(&self, field: DateTimeField, up: bool) -> ParsedDateTime {
+        let nanos_per_second = NANOS_PER_SECOND as i128;
+        let signed = |field: &DateTimeField, value: u64| {
+            i128::from(self.part_positivity(field))
+                * i128::from(value)
+                * i128::from(approx_seconds_multiplier(field))
+                * nanos_per_second
+        };
+        let unit = i128::from(approx_seconds_multiplier(&field)) * nanos_per_second;
+        let mut total = i128::from(self.part_positivity(&DateTimeField::Second))
+            * i128::from(self.nano.unwrap_or(0));
+        let mut pdt = ParsedDateTime {
+            nano: None,
+            nano_digits: None,
+            ..self.clone()
+        };
+        for finer in field.clone() {
+            let value = pdt.field_mut(&finer).take().unwrap_or(0);
+            total += signed(&finer, value);
+        }
+        let value = pdt.field_mut(&field).replace(0).unwrap_or(0);
+        total += signed(&field, value);
+        let mut count = match up {
+            true => -(-total).div_euclid(unit),
+            false => total.div_euclid(unit),
+        };
+        match field {
+            DateTimeField::Quarter => count *= 3,
+            DateTimeField::Week => count *= 7,
+            _ => {}
+        }
+        let mut rounded = ParsedDateTime::with_sign(count >= 0);
+        *rounded.field_mut(&field) = Some(count.unsigned_abs().min(u128::from(u64::MAX)) as u64);
+        pdt.combine(&rounded, 1).unwrap_or(pdt)
+    }
+
+    /// Borrow one `larger` as `size` of the `smaller` field, when the parts
+    /// they are in are both `nonzero` and have different signs, so that the
+    /// two parts end up with the same sign
+    fn borrow(
+        &self,
+        larger: DateTimeField,
+        smaller: DateTimeField,
+        size: u64,
+        nonzero: bool,
+    ) -> ParsedDateTime {
+        let signs = self.signs();
+        if !nonzero || signs.of(&larger) == signs.of(&smaller) {
+            return self.clone();
+        }
+        let mut borrowed = ParsedDateTime::positive();
+        *borrowed.field_mut(&larger) = Some(1);
+        *borrowed.field_mut(&smaller) = Some(size);
+        // one less of the `larger`, and that many more of the `smaller`
+        let borrowed = borrowed.with_part_signs(signs.flipped());
+        self.combine(&borrowed, 1).unwrap_or_else(|_| self.clone())
+    }
+
+    /// Where the `field` is stored, which for quarters is the months and for
+    /// weeks the days
+    fn field_mut(&mut self, field: &DateTimeField) -> &mut Option<u64> {
+        match field {
+            DateTimeField::Year => &mut self.year,
+            DateTimeField::Quarter | DateTimeField::Month => &mut self.month,
+            DateTimeField::Week | DateTimeField::Day => &mut self.day,
+            DateTimeField::Hour => &mut self.hour,
+            DateTimeField::Minute => &mut self.minute,
+            DateTimeField::Second => &mut self.second,
+        }
+    }
+
+    /// The largest field that this value is a whole number of
+    ///
+    /// A value of `3600` seconds is a whole number of hours, `3660` seconds a
+    /// whole number of minutes, and `3661` seconds only of seconds. Days
+    /// count as 24 hours.
+    ///
+    /// Returns `None` if the value is zero, has years or months, which have no
+    /// fixed length, or has a fraction of a second.
+    pub fn coarsest_whole_field(&self) -> Option<DateTimeField> {
+        if self.year.unwrap_or(0) != 0 || self.month.unwrap_or(0) != 0 || self.is_zero() {
+            return None;
+        }
+        let nanos = self.approx_nanos().unsigned_abs();
+        DateTimeField::Month
+            .into_iter()
+            .find(|field| nanos % (u128::from(seconds_multiplier(field)) * NANOS_PER_SECOND) == 0)
+    }
+
+    /// Render this value the way PostgreSQL does with its `IntervalStyle`
+    /// set to `style`
+    ///
+    /// Like PostgreSQL, the `postgres` and `iso_8601` styles put the sign on
+    /// every part of a negative interval, as in `-1 days -02:00:00`, and the
+    /// `sql_standard` style once in front, as in `-1 2:00:00`, unless the
+    /// interval has both year-month and day-time parts. Fields are rendered
+    /// as they are, without [normalizing](ParsedDateTime::normalize) them
+    /// first.
+    pub fn to_style(&self, style: IntervalStyle) -> String {
+        match style {
+            IntervalStyle::Postgres => self.to_postgres(),
+            IntervalStyle::PostgresVerbose => self.to_postgres_verbose(),
+            IntervalStyle::SqlStandard => self.to_sql_standard(),
+            IntervalStyle::Iso8601 => self.to_iso_8601(),
+        }
+    }
+
+    fn to_postgres(&self) -> String {
+        let signs = self.signs();
+        // like PostgreSQL, a positive part after a negative one has a `+`
+        let mut after_negative = false;
+        let mut sign = |is_positive| {
+            let sign = match (is_positive, after_negative) {
+                (false, _) => "-",
+                (true, true) => "+",
+                (true, false) => "",
+            };
+            after_negative |= !is_positive;
+            sign
+        };
+        let mut parts = vec![];
+        let units = [
+            (self.year, "year", signs.months),
+            (self.month, "mon", signs.months),
+            (self.day, "day", signs.days),
+        ];
+        for (value, unit, is_positive) in units.iter() {
+            match value.unwrap_or(0) {
+                0 => {}
+                1 if *is_positive => parts.push(format!("{}1 {}", sign(true), unit)),
+                value => parts.push(format!("{}{} {}s", sign(*is_positive), value, unit)),
+            }
+        }
+        if parts.is_empty() || self.has_time() {
+            parts.push(format!(
+                "{}{:02}:{:02}:{}",
+                sign(signs.time),
+                self.hour.unwrap_or(0),
+                self.minute.unwrap_or(0),
+                self.seconds_text(2)
+            ));
+        }
+        parts.join(" ")
+    }
+
+    fn to_sql_standard(&self) -> String {
+        let year_month = self.year.unwrap_or(0) != 0 || self.month.unwrap_or(0) != 0;
+        let day_time = self.day.unwrap_or(0) != 0 || self.has_time();
+        let signs = self.signs();
+        let sign = |is_positive| if is_positive { "+" } else { "-" };
+        let time = format!(
+            "{}:{:02}:{}",
+            self.hour.unwrap_or(0),
+            self.minute.unwrap_or(0),
+            self.seconds_text(2)
+        );
+        let year_month_text = format!("{}-{}", self.year.unwrap_or(0), self.month.unwrap_or(0));
+        // like PostgreSQL, every part is written with its sign when there
+        // are both kinds of parts, or the parts don't have the same sign
+        if (year_month && day_time) || self.has_mixed_signs() {
+            return format!(
+                "{}{} {}{} {}{}",
+                sign(signs.months),
+                year_month_text,
+                sign(signs.days),
+                self.day.unwrap_or(0),
+                sign(signs.time),
+                time
+            );
+        }
+        let out = match (year_month, day_time) {
+            (false, false) => return "0".to_string(),
+            (true, _) => year_month_text,
+            (false, true) => match self.day.unwrap_or(0) {
+                0 => time,
+                day => format!("{} {}", day, time),
+            },
+        };
+        match self.is_positive {
+            true => out,
+            false => format!("-{}", out),
+        }
+    }
+
+    fn to_iso_8601(&self) -> String {
+        let signs = self.signs();
+        let sign = |is_positive| if is_positive { "" } else { "-" };
+        let mut out = String::from("P");
+        let date = [
+            (self.year, 'Y', signs.months),
+            (self.month, 'M', signs.months),
+            (self.day, 'D', signs.days),
+        ];
+        let time = [(self.hour, 'H'), (self.minute, 'M')];
+        for (value, designator, is_positive) in date.iter() {
+            if let Some(value) = value.filter(|value| *value != 0) {
+                out += &format!("{}{}{}", sign(*is_positive), value, designator);
+            }
+        }
+        if self.has_time() {
+            out.push('T');
+            for (value, designator) in time.iter() {
+                if let Some(value) = value.filter(|value| *value != 0) {
+                    out += &format!("{}{}{}", sign(signs.time), value, designator);
+                }
+            }
+            if self.second.unwrap_or(0) != 0 || self.nano.unwrap_or(0) != 0 {
+                out += &format!("{}{}S", sign(signs.time), self.seconds_text(1));
+            }
+        }
+        if out.len() == 1 {
+            out += "T0S";
+        }
+        out
+    }
+
+    /// Whether any of the hours, minutes, seconds or nanoseconds aren't zero
+    fn has_time(&self) -> bool {
+        self.hour.unwrap_or(0) != 0
+            || self.minute.unwrap_or(0) != 0
+            || self.second.unwrap_or(0) != 0
+            || self.nano.unwrap_or(0) != 0
+    }
+
+    /// The seconds, padded to `width` digits, and their fraction without
+    /// trailing zeros
+    fn seconds_text(&self, width: usize) -> String {
+        let second = self.second.unwrap_or(0);
+        match self.nano.unwrap_or(0) {
+            0 => format!("{:0width$}", second, width = width),
+            nano => {
+                let fraction = format!("{:09}", nano);
+                format!(
+                    "{:0width$}.{}",
+                    second,
+                    fraction.trim_end_matches('0'),
+                    width = width
+                )
+            }
+        }
+    }
+
+    /// Render this value like PostgreSQL's `postgres_verbose` interval style
+    ///
+    /// For example `@ 1 year 2 mons 3 days 4 hours 5 mins 6.5 secs`, or
+    /// `@ 1 day ago` for a negative interval. Fields that are unset or zero
+    /// are left out, and a zero interval is `@ 0`. Fields are rendered as
+    /// they are, without [normalizing](ParsedDateTime::normalize) them first.
+    /// A part with the other [sign](ParsedDateTime::part_signs) than the
+    /// value has a `-`, so `'2 days -3 hours'` is `@ 2 days -3 hours`.
+    pub fn to_postgres_verbose(&self) -> String {
+        let signs = self.signs();
+        let sign = |is_positive| {
+            if is_positive == self.is_positive {
+                ""
+            } else {
+                "-"
+            }
+        };
+        let mut out = String::from("@");
+        let units = [
+            (self.year, "year", signs.months),
+            (self.month, "mon", signs.months),
+            (self.day, "day", signs.days),
+            (self.hour, "hour", signs.time),
+            (self.minute, "min", signs.time),
+        ];
+        for (value, unit, is_positive) in units.iter() {
+            match value.unwrap_or(0) {
+                0 => {}
+                1 => out += &format!(" {}1 {}", sign(*is_positive), unit),
+                value => out += &format!(" {}{} {}s", sign(*is_positive), value, unit),
+            }
+        }
+        let second = self.second.unwrap_or(0);
+        let second_sign = sign(signs.time);
+        match self.nano.unwrap_or(0) {
+            0 if second == 0 => {}
+            0 if second == 1 => out += &format!(" {}1 sec", second_sign),
+            0 => out += &format!(" {}{} secs", second_sign, second),
+            nano => {
+                let fraction = format!("{:09}", nano);
+                out += &format!(
+                    " {}{}.{} secs",
+                    second_sign,
+                    second,
+                    fraction.trim_end_matches('0')
+                );
+            }
+        }
+        if out.len() == 1 {
+            out += " 0";
+        } else if !self.is_positive {
+            out += " ago";
+        }
+        out
+    }
+
+    /// The seconds with their fraction, written the way they were parsed
+    ///
+    /// The fraction keeps as many digits as [`nano_digits`] says it was
+    /// written with, so `'1:02.050' MINUTE TO SECOND` has `"2.050"`. Without
+    /// a digit count, trailing zeros are dropped. A fraction is never cut
+    /// short to fit the digit count. Returns `None` if there is no second.
+    ///
+    /// [`nano_digits`]: ParsedDateTime::nano_digits
+    pub fn seconds_literal(&self) -> Option<String> {
+        let second = self.second?;
+        let digits = usize::from(self.nano_digits.unwrap_or(0));
+        let fraction = match self.nano {
+            Some(nano) => format!("{:09}", nano),
+            None => String::new(),
+        };
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.is_empty() && digits == 0 {
+            return Some(second.to_string());
+        }
+        Some(format!("{}.{:0<width$}", second, fraction, width = digits))
+    }
+
+    /// The signed number of nanoseconds in this value, with 30 day months
+    fn approx_nanos(&self) -> i128 {
+        let seconds = [
+            (self.year, DateTimeField::Year),
+            (self.month, DateTimeField::Month),
+            (self.day, DateTimeField::Day),
+            (self.hour, DateTimeField::Hour),
+            (self.minute, DateTimeField::Minute),
+            (self.second, DateTimeField::Second),
+        ]
+        .iter()
+        .map(|(value, field)| {
+            i128::from(self.part_positivity(field))
+                * i128::from(value.unwrap_or(0))
+                * i128::from(approx_seconds_multiplier(field))
+        })
+        .sum::<i128>();
+        let nano = i128::from(self.part_positivity(&DateTimeField::Second))
+            * i128::from(self.nano.unwrap_or(0));
+        seconds * NANOS_PER_SECOND as i128 + nano
+    }
+}
+
+/// Values are equal when every field but `nano_digits` is, so `'1.5 seconds'`
+/// equals `'1.50 seconds'`
+impl PartialEq for ParsedDateTime {
+    fn eq(&self, other: &ParsedDateTime) -> bool {
+        // destructured so that a new field has to be compared here too
+        let ParsedDateTime {
+            is_positive,
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            nano,
+            nano_digits: _,
+            timezone_offset_second,
+            leading_field,
+            trailing_field,
+            part_signs,
+        } = self;
+        *is_positive == other.is_positive
+            && *year == other.year
+            && *month == other.month
+            && *day == other.day
+            && *hour == other.hour
+            && *minute == other.minute
+            && *second == other.second
+            && *nano == other.nano
+            && *timezone_offset_second == other.timezone_offset_second
+            && *leading_field == other.leading_field
+            && *trailing_field == other.trailing_field
+            && *part_signs == other.part_signs
+    }
+}
+
+/// Hashes the fields that `PartialEq` compares
+impl Hash for ParsedDateTime {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let ParsedDateTime {
+            is_positive,
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            nano,
+            nano_digits: _,
+            timezone_offset_second,
+            leading_field,
+            trailing_field,
+            part_signs,
+        } = self;
+        is_positive.hash(state);
+        [year, month, day, hour, minute, second].hash(state);
+        nano.hash(state);
+        timezone_offset_second.hash(state);
+        leading_field.hash(state);
+        trailing_field.hash(state);
+        part_signs.hash(state);
+    }
+}
+
+/// The same as [`ParsedDateTime::positive`]
+impl Default for ParsedDateTime {
+    fn default() -> ParsedDateTime {
+        ParsedDateTime::positive()
+    }
+}
+
+/// Add two intervals field by field
+///
+/// Each field of the result is the signed sum of that field of both values,
+/// and is set if it is set in either of them. Years and months are only ever
+/// combined with each other, and hours, minutes, seconds and nanoseconds with
+/// each other, but days stay on their own, so `1 mon + 1 day` is not turned
+/// into some number of days.
+///
+/// When the fields of a group end up with different signs, as with `1 hour -
+/// 30 minutes`, the group is rewritten into the same fields with one sign,
+/// here `0 hours 30 minutes`. The months, days and time may still have
+/// different signs, as with `1 mon - 10 days`, which is kept as it is, see
+/// [`ParsedDateTime::part_signs`]. A field that doesn't fit in a `u64` is an
+/// error.
+impl Add for ParsedDateTime {
+    type Output = Result<ParsedDateTime, ValueError>;
+
+    fn add(self, other: ParsedDateTime) -> Self::Output {
+        self.combine(&other, 1)
+    }
+}
+
+impl Neg for ParsedDateTime {
+    type Output = ParsedDateTime;
+
+    fn neg(self) -> ParsedDateTime {
+        self.negate()
+    }
+}
+
+/// Multiply every field by a factor, see [`ParsedDateTime::scale`]
+impl Mul<i64> for ParsedDateTime {
+    type Output = Result<ParsedDateTime, ValueError>;
+
+    fn mul(self, factor: i64) -> Self::Output {
+        self.scale(factor)
+    }
+}
+
+/// Subtract one interval from another field by field, see the [`Add`] impl
+impl Sub for ParsedDateTime {
+    type Output = Result<ParsedDateTime, ValueError>;
+
+    fn sub(self, other: ParsedDateTime) -> Self::Output {
+        self.combine(&other, -1)
+    }
+}
+
+impl ParsedDateTime {
+    fn combine(
+        &self,
+        other: &ParsedDateTime,
+        other_sign: i128,
+    ) -> Result<ParsedDateTime, ValueError> {
+        use DateTimeField::*;
+        let sum = |field, a: Option<u64>, b: Option<u64>| match (a, b) {
+            (None, None) => None,
+            (a, b) => Some(
+                i128::from(self.part_positivity(&field)) * i128::from(a.unwrap_or(0))
+                    + other_sign
+                        * i128::from(other.part_positivity(&field))
+                        * i128::from(b.unwrap_or(0)),
+            ),
+        };
+        let nano = |pdt: &ParsedDateTime| pdt.nano.map(u64::from);
+        let mut date = [
+            sum(Year, self.year, other.year),
+            sum(Month, self.month, other.month),
+        ];
+        let day = sum(Day, self.day, other.day);
+        let mut time = [
+            sum(Hour, self.hour, other.hour),
+            sum(Minute, self.minute, other.minute),
+            sum(Second, self.second, other.second),
+            sum(Second, nano(self), nano(other)),
+        ];
+        align_signs(&mut date, &[12, 1]);
+        let nanos_per_second = NANOS_PER_SECOND as i128;
+        align_signs(
+            &mut time,
+            &[
+                60 * 60 * nanos_per_second,
+                60 * nanos_per_second,
+                nanos_per_second,
+                1,
+            ],
+        );
+        if let Some(nanos) = time[3] {
+            if nanos.abs() >= nanos_per_second {
+                time[2] = Some(time[2].unwrap_or(0) + nanos / nanos_per_second);
+                time[3] = Some(nanos % nanos_per_second);
+            }
+        }
+
+        // each part has a single sign after `align_signs`
+        let is_positive = |part: &[Option<i128>]| !part.iter().flatten().any(|field| *field < 0);
+        let signs = PartSigns {
+            months: is_positive(&date),
+            days: is_positive(&[day]),
+            time: is_positive(&time),
+        };
+        let magnitude = |field: Option<i128>| {
+            field
+                .map(|field| u64::try_from(field.unsigned_abs()))
+                .transpose()
+                .map_err(|_| ValueError(format!("interval out of range: {:?}", field)))
+        };
+        let pdt = ParsedDateTime {
+            year: magnitude(date[0])?,
+            month: magnitude(date[1])?,
+            day: magnitude(day)?,
+            hour: magnitude(time[0])?,
+            minute: magnitude(time[1])?,
+            second: magnitude(time[2])?,
+            // always less than a second
+            nano: magnitude(time[3])?.map(|nano| nano as u32),
+            nano_digits: self.nano_digits.max(other.nano_digits),
+            ..ParsedDateTime::positive()
+        };
+        Ok(pdt.with_part_signs(signs))
+    }
+}
+
+/// Carry the whole multiples of `limit` in `value` into the `larger` field
+fn carry(value: &mut Option<u64>, larger: &mut Option<u64>, limit: u64) {
+    if let Some(v) = *value {
+        if v >= limit {
+            *value = Some(v % limit);
+            *larger = Some(larger.unwrap_or(0).saturating_add(v / limit));
+        }
+    }
+}
+
+/// Rewrite `fields`, which are in `units` each, to have a single sign if
+/// they don't already, keeping their total and which of them are set
+fn align_signs(fields: &mut [Option<i128>], units: &[i128]) {
+    let mixed = fields.iter().flatten().any(|field| *field < 0)
+        && fields.iter().flatten().any(|field| *field > 0);
+    if !mixed {
+        return;
+    }
+    let mut total: i128 = fields
+        .iter()
+        .zip(units)
+        .map(|(field, unit)| field.unwrap_or(0) * unit)
+        .sum();
+    // the smallest unit divides the larger ones, so nothing is left over
+    for (field, unit) in fields.iter_mut().zip(units) {
+        if field.is_some() {
+            let value = total / unit;
+            *field = Some(value);
+            total -= value * unit;
+        }
+    }
+}
+
+/// Build a [`ParsedDateTime`] field by field, see [`ParsedDateTime::builder`]
+#[derive(Debug, Clone, Default)]
+pub struct ParsedDateTimeBuilder {
+    pdt: ParsedDateTime,
+}
+
+impl ParsedDateTimeBuilder {
+    pub fn years(mut self, years: u64) -> Self {
+        self.pdt.year = Some(years);
+        self
+    }
+
+    pub fn months(mut self, months: u64) -> Self {
+        self.pdt.month = Some(months);
+        self
+    }
+
+    pub fn days(mut self, days: u64) -> Self {
+        self.pdt.day = Some(days);
+        self
+    }
+
+    pub fn hours(mut self, hours: u64) -> Self {
+        self.pdt.hour = Some(hours);
+        self
+    }
+
+    pub fn minutes(mut self, minutes: u64) -> Self {
+        self.pdt.minute = Some(minutes);
+        self
+    }
+
+    pub fn seconds(mut self, seconds: u64) -> Self {
+        self.pdt.second = Some(seconds);
+        self
+    }
+
+    /// The fraction of a second, in nanoseconds
+    pub fn nanos(mut self, nanos: u32) -> Self {
+        self.pdt.nano = Some(nanos);
+        self
+    }
+
+    /// Make the whole value negative, the default is positive
+    pub fn negative(mut self) -> Self {
+        self.pdt.is_positive = false;
+        self
+    }
+
+    /// Check the fields and produce the value
+    ///
+    /// # Errors
+    ///
+    /// If the fraction is a whole second or more, or if the months are not
+    /// between `1` and `12` alongside a number of years, as they would be
+    /// in a `YEAR TO MONTH` value.
+    pub fn build(self) -> Result<ParsedDateTime, ValueError> {
+        let pdt = self.pdt;
+        if let (Some(_), Some(month)) = (pdt.year, pdt.month) {
+            if !(1..=12).contains(&month) {
+                return Err(ValueError(format!(
+                    "a month must be between 1 and 12 alongside years, got {}",
+                    month
+                )));
+            }
+        }
+        if let Some(nano) = pdt.nano {
+            if nano >= 1_000_000_000 {
+                return Err(ValueError(format!(
+                    "the fraction of a second must be less than 1_000_000_000 nanoseconds, \
+                     got {}",
+                    nano
+                )));
+            }
+        }
+        Ok(pdt)
+    }
+}
+
+#[derive(Debug, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+pub enum DateTimeField {
+    Year,
+    /// Three months
+    ///
+    /// There is no quarter field in a [`ParsedDateTime`], quarters are
+    /// stored as months. Iterating over the fields skips it.
+    Quarter,
+    Month,
+    /// Seven days
+    ///
+    /// There is no week field in a [`ParsedDateTime`], weeks are stored as
+    /// days. Iterating over the fields skips it.
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+impl fmt::Display for DateTimeField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            DateTimeField::Year => "YEAR",
+            DateTimeField::Quarter => "QUARTER",
+            DateTimeField::Month => "MONTH",
+            DateTimeField::Week => "WEEK",
+            DateTimeField::Day => "DAY",
+            DateTimeField::Hour => "HOUR",
+            DateTimeField::Minute => "MINUTE",
+            DateTimeField::Second => "SECOND",
+        })
+    }
+}
+
+/// Every accepted spelling of a field, in lowercase
+///
+/// These are the singular, plural and abbreviated forms that PostgreSQL
+/// accepts in its own interval output, and the same for quarters and weeks.
+pub(crate) const DATE_TIME_FIELD_KEYWORDS: &[(&str, DateTimeField)] = &[
+    ("year", DateTimeField::Year),
+    ("years", DateTimeField::Year),
+    ("yr", DateTimeField::Year),
+    ("yrs", DateTimeField::Year),
+    ("quarter", DateTimeField::Quarter),
+    ("quarters", DateTimeField::Quarter),
+    ("qtr", DateTimeField::Quarter),
+    ("qtrs", DateTimeField::Quarter),
+    ("month", DateTimeField::Month),
+    ("months", DateTimeField::Month),
+    ("mon", DateTimeField::Month),
+    ("mons", DateTimeField::Month),
+    ("week", DateTimeField::Week),
+    ("weeks", DateTimeField::Week),
+    ("day", DateTimeField::Day),
+    ("days", DateTimeField::Day),
+    ("hour", DateTimeField::Hour),
+    ("hours", DateTimeField::Hour),
+    ("hr", DateTimeField::Hour),
+    ("hrs", DateTimeField::Hour),
+    ("minute", DateTimeField::Minute),
+    ("minutes", DateTimeField::Minute),
+    ("min", DateTimeField::Minute),
+    ("mins", DateTimeField::Minute),
+    ("second", DateTimeField::Second),
+    ("seconds", DateTimeField::Second),
+    ("sec", DateTimeField::Second),
+    ("secs", DateTimeField::Second),
+];
+
+impl DateTimeField {
+    /// The field named by a keyword like `MONTH`, `months` or `mon`
+    ///
+    /// The lookup ignores case and accepts the plural and abbreviated forms
+    /// of every field.
+    ///
+    /// ```
+    /// use sqlparser::ast::DateTimeField;
+    /// assert_eq!(DateTimeField::from_keyword("Hrs"), Some(DateTimeField::Hour));
+    /// assert_eq!(DateTimeField::from_keyword("fortnight"), None);
+    /// ```
+    pub fn from_keyword(s: &str) -> Option<DateTimeField> {
+        DATE_TIME_FIELD_KEYWORDS
+            .iter()
+            .find(|(spelling, _)| spelling.eq_ignore_ascii_case(s))
+            .map(|(_, field)| field.clone())
+    }
+
+    /// The field after this one in descending significance
+    ///
+    /// ```
+    /// use sqlparser::ast::DateTimeField;
+    /// assert_eq!(DateTimeField::Day.next_smaller_field(), Some(DateTimeField::Hour));
+    /// assert_eq!(DateTimeField::Second.next_smaller_field(), None);
+    /// ```
+    pub fn next_smaller_field(&self) -> Option<DateTimeField> {
+        use DateTimeField::*;
+        match self {
+            Year | Quarter => Some(Month),
+            Month | Week => Some(Day),
+            Day => Some(Hour),
+            Hour => Some(Minute),
+            Minute => Some(Second),
+            Second => None,
+        }
+    }
+}
+
+/// Iterate over `DateTimeField`s in descending significance
+impl IntoIterator for DateTimeField {
+    type Item = DateTimeField;
+    type IntoIter = DateTimeFieldIterator;
+    fn into_iter(self) -> DateTimeFieldIterator {
+        DateTimeFieldIterator(Some(self))
+    }
+}
+
+/// An iterator over DateTimeFields
+///
+/// Always starts with the value smaller than the current one.
+///
+/// ```
+/// use sqlparser::ast::DateTimeField::*;
+/// let mut itr = Hour.into_iter();
+/// assert_eq!(itr.next(), Some(Minute));
+/// assert_eq!(itr.next(), Some(Second));
+/// assert_eq!(itr.next(), None);
+/// ```
+pub struct DateTimeFieldIterator(Option<DateTimeField>);
+
+/// Go through fields in descending significance order
+impl Iterator for DateTimeFieldIterator {
+    type Item = DateTimeField;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0 = self.0.as_ref().and_then(DateTimeField::next_smaller_field);
+        self.0.clone()
+    }
+}
+
+/// Similar to a [`DateTimeField`], but with a few more options
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExtractField {
+    Millenium,
+    Century,
+    Decade,
+    Year,
+    /// The ISO Week-Numbering year
+    ///
+    /// See https://en.wikipedia.org/wiki/ISO_week_date
+    IsoYear,
+    Quarter,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    Milliseconds,
+    Microseconds,
+    // Weirder fields
+    Timezone,
+    TimezoneHour,
+    TimezoneMinute,
+    WeekOfYear,
+    /// The day of the year (1 - 365/366)
+    DayOfYear,
+    /// The day of the week (0 - 6; Sunday is 0), `DOW`
+    ///
+    /// This counts from 0 and starts the week on Sunday, unlike
+    /// [`ExtractField::IsoDayOfWeek`], see [`ExtractField::indexing_base`].
+    DayOfWeek,
+    /// The day of the week (1 - 7; Monday is 1 and Sunday is 7), `ISODOW`
+    IsoDayOfWeek,
+    /// The number of seconds
+    ///
+    /// * for DateTime fields, the number of seconds since 1970-01-01 00:00:00-00
+    /// * for intervals, the total number of seconds in the interval
+    Epoch,
+}
+
+impl ExtractField {
+    /// The number that the values of this field count from, and the range
+    /// that they are in
+    ///
+    /// This is for the fields that number a position, like the day of the
+    /// week, so that an evaluator doesn't have to guess whether they count
+    /// from 0 or 1. `DOW` counts from 0 with Sunday as 0, and `ISODOW` from 1
+    /// with Monday as 1 and Sunday as 7. The seconds go up to 60 for a leap
+    /// second, and have a fraction. Fields that are amounts rather than
+    /// positions, like the year or the epoch, give `None`.
+    pub fn indexing_base(&self) -> Option<(u32, RangeInclusive<u32>)> {
+        let range = match self {
+            ExtractField::Quarter => 1..=4,
+            ExtractField::Month => 1..=12,
+            ExtractField::Day => 1..=31,
+            ExtractField::Hour => 0..=23,
+            ExtractField::Minute => 0..=59,
+            ExtractField::Second => 0..=60,
+            ExtractField::WeekOfYear => 1..=53,
+            ExtractField::DayOfYear => 1..=366,
+            ExtractField::DayOfWeek => 0..=6,
+            ExtractField::IsoDayOfWeek => 1..=7,
+            ExtractField::Millenium
+            | ExtractField::Century
+            | ExtractField::Decade
+            | ExtractField::Year
+            | ExtractField::IsoYear
+            | ExtractField::Milliseconds
+            | ExtractField::Microseconds
+            | ExtractField::Timezone
+            | ExtractField::TimezoneHour
+            | ExtractField::TimezoneMinute
+            | ExtractField::Epoch => return None,
+        };
+        Some((*range.start(), range))
+    }
 }
 
 impl fmt::Display for ExtractField {
@@ -456,7 +3062,7 @@ impl fmt::Display for ExtractField {
     }
 }
 
-use std::str::FromStr;
+use core::str::FromStr;
 
 impl FromStr for ExtractField {
     type Err = ValueError;
@@ -1674,7 +1674,8 @@ macro_rules! make_visitor {
     }
 }
 
-#[cfg(test)]
+// `ParserError` is only a `std::error::Error` with std
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use crate::ast::visit::Visit;
     use crate::ast::visit_mut::VisitMut;
@@ -17,7 +17,7 @@ mod mssql;
 mod mysql;
 mod postgresql;
 
-use std::fmt::Debug;
+use core::fmt::Debug;
 
 pub use self::ansi::AnsiDialect;
 pub use self::generic::GenericDialect;
@@ -25,6 +25,28 @@ pub use self::mssql::MsSqlDialect;
 pub use self::mysql::MySqlDialect;
 pub use self::postgresql::PostgreSqlDialect;
 
+/// Which forms of `INTERVAL` literal a dialect accepts, see
+/// [`Dialect::interval_grammar`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntervalGrammar {
+    /// A quoted value in any of the forms the interval parser knows, like
+    /// `INTERVAL '1-2' YEAR TO MONTH`, `INTERVAL '1 day 2 hours'` or
+    /// `INTERVAL 'P1D'`
+    ///
+    /// The qualifier may be left out after a compound or ISO 8601 value,
+    /// whose units are in the value itself, but not after any other value.
+    Permissive,
+    /// PostgreSQL's, which is a quoted value in any of those forms, with the
+    /// qualifier optional in the same way
+    Postgres,
+    /// MySQL's, where the value may be a bare integer, as in
+    /// `INTERVAL 1 DAY`, and a quoted value is only numbers and separators
+    ///
+    /// The unit always goes after the value, so unit words inside the quotes,
+    /// as in `INTERVAL '1 day'`, and ISO 8601 durations are rejected.
+    MySql,
+}
+
 pub trait Dialect: Debug {
     /// Determine if a character starts a quoted identifier. The default
     /// implementation, accepting "double quoted" ids is both ANSI-compliant
@@ -38,4 +60,9 @@ pub trait Dialect: Debug {
     fn is_identifier_start(&self, ch: char) -> bool;
     /// Determine if a character is a valid unquoted identifier character
     fn is_identifier_part(&self, ch: char) -> bool;
+    /// The forms of `INTERVAL` literal that the dialect accepts. The default
+    /// accepts a quoted value in any form.
+    fn interval_grammar(&self) -> IntervalGrammar {
+        IntervalGrammar::Permissive
+    }
 }
@@ -10,7 +10,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::dialect::Dialect;
+use crate::dialect::{Dialect, IntervalGrammar};
 
 #[derive(Debug)]
 pub struct MySqlDialect {}
@@ -30,4 +30,8 @@ impl Dialect for MySqlDialect {
     fn is_identifier_part(&self, ch: char) -> bool {
         self.is_identifier_start(ch) || (ch >= '0' && ch <= '9')
     }
+
+    fn interval_grammar(&self) -> IntervalGrammar {
+        IntervalGrammar::MySql
+    }
 }
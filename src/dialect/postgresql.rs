@@ -10,7 +10,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::dialect::Dialect;
+use crate::dialect::{Dialect, IntervalGrammar};
 
 #[derive(Debug)]
 pub struct PostgreSqlDialect {}
@@ -30,4 +30,8 @@ impl Dialect for PostgreSqlDialect {
             || ch == '$'
             || ch == '_'
     }
+
+    fn interval_grammar(&self) -> IntervalGrammar {
+        IntervalGrammar::Postgres
+    }
 }
@@ -32,13 +32,22 @@
 //!
 //! println!("AST: {:?}", ast);
 //! ```
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(clippy::all)]
 
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+// The unit tests may use std, even when the crate itself doesn't
+#[cfg(all(test, not(feature = "std")))]
+#[macro_use]
+extern crate std;
+
 pub mod ast;
 pub mod dialect;
 pub mod parser;
 pub mod tokenizer;
 
+#[cfg(any(test, feature = "std"))]
 #[doc(hidden)]
 // This is required to make utilities accessible by both the crate-internal
 // unit-tests and by the integration tests <https://stackoverflow.com/a/44541071/1026>
@@ -12,14 +12,21 @@
 
 //! SQL Parser
 
+#[cfg(not(feature = "std"))]
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 use log::debug;
 
 use super::ast::*;
 use super::dialect::keywords;
-use super::dialect::Dialect;
+use super::dialect::{Dialect, IntervalGrammar};
 use super::tokenizer::*;
-use std::error::Error;
-use std::fmt;
+use core::fmt;
 
 use crate::ast::{ParsedDate, ParsedTimestamp};
 
@@ -34,6 +41,10 @@ macro_rules! parser_err {
 }
 
 mod datetime;
+pub use self::datetime::{
+    DateTimeError, DateTimeParseOptions, ErrorKind, ErrorPhase, FractionRounding, IntervalIssue,
+    IntervalToken, IssueSeverity,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum ParserError {
@@ -73,26 +84,42 @@ impl fmt::Display for ParserError {
     }
 }
 
-impl Error for ParserError {}
+#[cfg(feature = "std")]
+impl std::error::Error for ParserError {}
 
 /// SQL Parser
 pub struct Parser {
     tokens: Vec<Token>,
     /// The index of the first unprocessed token in `self.tokens`
     index: usize,
+    /// The forms of `INTERVAL` literal to accept
+    interval_grammar: IntervalGrammar,
 }
 
 impl Parser {
     /// Parse the specified tokens
     pub fn new(tokens: Vec<Token>) -> Self {
-        Parser { tokens, index: 0 }
+        Parser {
+            tokens,
+            index: 0,
+            interval_grammar: IntervalGrammar::Permissive,
+        }
+    }
+
+    /// Create a parser that follows the grammar of `dialect` where it differs,
+    /// as in [`Dialect::interval_grammar`]
+    pub fn new_with_dialect(tokens: Vec<Token>, dialect: &dyn Dialect) -> Self {
+        Parser {
+            interval_grammar: dialect.interval_grammar(),
+            ..Parser::new(tokens)
+        }
     }
 
     /// Parse a SQL statement and produce an Abstract Syntax Tree (AST)
     pub fn parse_sql(dialect: &dyn Dialect, sql: String) -> Result<Vec<Statement>, ParserError> {
         let mut tokenizer = Tokenizer::new(dialect, &sql);
         let tokens = tokenizer.tokenize()?;
-        let mut parser = Parser::new(tokens);
+        let mut parser = Parser::new_with_dialect(tokens, dialect);
         let mut stmts = Vec::new();
         let mut expecting_statement_delimiter = false;
         debug!("Parsing sql '{}'...", sql);
@@ -455,14 +482,9 @@ impl Parser {
     pub fn parse_date_time_field(&mut self) -> Result<DateTimeField, ParserError> {
         let tok = self.next_token();
         if let Some(Token::Word(ref k)) = tok {
-            match k.keyword.as_ref() {
-                "YEAR" => Ok(DateTimeField::Year),
-                "MONTH" => Ok(DateTimeField::Month),
-                "DAY" => Ok(DateTimeField::Day),
-                "HOUR" => Ok(DateTimeField::Hour),
-                "MINUTE" => Ok(DateTimeField::Minute),
-                "SECOND" => Ok(DateTimeField::Second),
-                _ => self.expected("date/time field", tok)?,
+            match DateTimeField::from_keyword(&k.keyword) {
+                Some(field) => Ok(field),
+                None => self.expected("date/time field", tok)?,
             }
         } else {
             self.expected("date/time field", tok)?
@@ -474,14 +496,9 @@ impl Parser {
         &mut self,
         s: &str,
     ) -> Result<DateTimeField, ParserError> {
-        match s {
-            "YEAR" => Ok(DateTimeField::Year),
-            "MONTH" => Ok(DateTimeField::Month),
-            "DAY" => Ok(DateTimeField::Day),
-            "HOUR" => Ok(DateTimeField::Hour),
-            "MINUTE" => Ok(DateTimeField::Minute),
-            "SECOND" => Ok(DateTimeField::Second),
-            _ => parser_err!("Expected date/time field, found: {}", s),
+        match DateTimeField::from_keyword(s) {
+            Some(field) => Ok(field),
+            None => parser_err!("Expected date/time field, found: {}", s),
         }
     }
 
@@ -501,7 +518,9 @@ impl Parser {
 
     pub fn contains_date_time_str(&mut self, interval: &str) -> Result<bool, ParserError> {
         let upper_case_interval = interval.to_uppercase();
-        let date_time_strs = ["YEAR", "MONTH", "DAY", "HOUR", "MINUTE", "SECOND"];
+        let date_time_strs = [
+            "YEAR", "QUARTER", "MONTH", "WEEK", "DAY", "HOUR", "MINUTE", "SECOND",
+        ];
         for dts in &date_time_strs {
             if upper_case_interval.contains(dts) {
                 return Ok(true);
@@ -511,14 +530,14 @@ impl Parser {
     }
 
     fn parse_date(&mut self) -> Result<Value, ParserError> {
-        use std::convert::TryInto;
+        use core::convert::TryInto;
 
         let value = self.parse_literal_string()?;
         let pdt = Self::parse_interval_string(&value, &DateTimeField::Year)?;
 
         match (pdt.year, pdt.month, pdt.day, pdt.hour) {
             (Some(year), Some(month), Some(day), None) => {
-                let p_err = |e: std::num::TryFromIntError, field: &str| {
+                let p_err = |e: core::num::TryFromIntError, field: &str| {
                     ParserError::ParserError(format!(
                         "{} in date '{}' is invalid: {}",
                         field, value, e
@@ -569,7 +588,7 @@ impl Parser {
     }
 
     fn parse_timestamp_inner(&mut self, parse_timezone: bool) -> Result<Value, ParserError> {
-        use std::convert::TryInto;
+        use core::convert::TryInto;
 
         let value = self.parse_literal_string()?;
         let pdt = Self::parse_timestamp_string(&value, parse_timezone)?;
@@ -594,7 +613,7 @@ impl Parser {
                 nano,
                 timezone_offset_second,
             ) => {
-                let p_err = |e: std::num::TryFromIntError, field: &str| {
+                let p_err = |e: core::num::TryFromIntError, field: &str| {
                     ParserError::ParserError(format!(
                         "{} in date '{}' is invalid: {}",
                         field, value, e
@@ -688,29 +707,68 @@ impl Parser {
         // inside the raw_value string.)
 
         // The first token in an interval is a string literal which specifies
-        // the duration of the interval.
-        let mut raw_value = self.parse_literal_string()?;
-        let leading_field = if self.contains_date_time_str(&raw_value)? {
-            // Hack to allow INTERVAL types like:
-            // INTERVAL '-30 day'
-            let (new_raw_value, leading_field) = {
-                let split = raw_value.split(" ").collect::<Vec<&str>>();
-                if split.len() == 2 {
-                    (
-                        String::from(split[0]),
-                        self.parse_date_time_field_given_str(&split[1].to_uppercase())?,
-                    )
+        // the duration of the interval. MySQL also allows a bare integer,
+        // which is kept as a number so that it isn't tokenized all over again.
+        let mut integer = None;
+        let mut raw_value = match self.peek_token() {
+            Some(Token::Number(_)) | Some(Token::Minus)
+                if self.interval_grammar == IntervalGrammar::MySql =>
+            {
+                let sign = if self.consume_token(&Token::Minus) {
+                    "-"
                 } else {
-                    return parser_err!("Invalid INTERVAL: {:#?}", raw_value);
+                    ""
+                };
+                match self.next_token() {
+                    Some(Token::Number(n)) if n.bytes().all(|b| b.is_ascii_digit()) => {
+                        let raw_value = format!("{}{}", sign, n);
+                        integer = raw_value.parse::<i64>().ok();
+                        raw_value
+                    }
+                    other => return self.expected("an integer interval value", other),
                 }
+            }
+            _ => self.parse_literal_string()?,
+        };
+        datetime::check_interval_grammar(&raw_value, self.interval_grammar)?;
+        // A value with units in it may still be followed by a qualifier, which
+        // the units then have to be within
+        let qualified = match self.peek_token() {
+            Some(Token::Word(word)) => self.parse_date_time_field_given_str(&word.keyword).is_ok(),
+            _ => false,
+        };
+        // PostgreSQL also allows leaving the qualifier out after a value that
+        // has its units in it
+        let unqualified = !qualified
+            && self.interval_grammar != IntervalGrammar::MySql
+            && (datetime::is_iso8601_duration(raw_value.trim())
+                || datetime::is_compound_interval(&raw_value));
+        let leading_field = if !qualified && self.contains_date_time_str(&raw_value)? {
+            // Hack to allow INTERVAL types like:
+            // INTERVAL '-30 day'
+            let split = raw_value.split(' ').collect::<Vec<&str>>();
+            let single_unit = match split[..] {
+                [number, unit] => self
+                    .parse_date_time_field_given_str(&unit.to_uppercase())
+                    .map(|field| (String::from(number), field)),
+                _ => parser_err!("Invalid INTERVAL: {:#?}", raw_value),
             };
-            raw_value = new_raw_value;
-            leading_field
+            match single_unit {
+                Ok((new_raw_value, leading_field)) => {
+                    raw_value = new_raw_value;
+                    leading_field
+                }
+                Err(_) if unqualified => return Self::unqualified_interval_literal(raw_value),
+                Err(e) => return Err(e),
+            }
+        } else if unqualified {
+            return Self::unqualified_interval_literal(raw_value);
         } else {
             // Following the string literal is a qualifier which indicates the units
             // of the duration specified in the string literal.
             //
-            // Note that PostgreSQL allows omitting the qualifier, but we currently
+            // Note that PostgreSQL allows omitting the qualifier of any value, but
+            // we only allow that for the values with units in them, and otherwise
             // require at least the leading field, in accordance with the ANSI spec.
             self.parse_date_time_field()?
         };
@@ -740,7 +798,7 @@ impl Parser {
                 }
             };
 
-        let value = Self::parse_interval_string(&raw_value, &leading_field)?;
+        let value = Self::interval_literal_value(&raw_value, integer, &leading_field, &last_field)?;
 
         Ok(Expr::Value(Value::Interval(IntervalValue {
             value: raw_value,
@@ -752,6 +810,57 @@ impl Parser {
         })))
     }
 
+    /// An `INTERVAL` literal without a qualifier, as PostgreSQL allows for a
+    /// compound or ISO 8601 value like `'1 hour 30 minutes'`
+    ///
+    /// The qualifier comes from the fields the value sets instead, so the
+    /// literal is shown as `INTERVAL '1 hour 30 minutes' HOUR TO MINUTE`.
+    fn unqualified_interval_literal(raw_value: String) -> Result<Expr, ParserError> {
+        let parsed = Self::parse_unqualified_interval_string(&raw_value)?;
+        let fields = parsed.fields_set();
+        let leading_field = fields.first().cloned().unwrap_or(DateTimeField::Second);
+        let last_field = fields
+            .last()
+            .filter(|last| **last != leading_field)
+            .cloned();
+        Ok(Expr::Value(Value::Interval(IntervalValue {
+            value: raw_value,
+            parsed,
+            leading_field,
+            leading_precision: None,
+            last_field,
+            fractional_seconds_precision: None,
+        })))
+    }
+
+    /// The parsed value of an `INTERVAL` literal, which the SQL parser shares
+    /// with [`Parser::parse_interval_string`]
+    ///
+    /// An `integer` that was already read as a number token, as in MySQL's
+    /// `INTERVAL 5 HOUR`, is built from the number, without going back over
+    /// the characters of `raw_value`, see [`Parser::parsed_datetime_from_int`].
+    /// Everything else is parsed from `raw_value` by `parse_interval_string`,
+    /// so a literal has the same value as its string does on its own. The
+    /// value may not go on past the `last_field` of the qualifier, whether
+    /// it is positional, like `'1 2:03'`, or has units in it.
+    fn interval_literal_value(
+        raw_value: &str,
+        integer: Option<i64>,
+        leading_field: &DateTimeField,
+        last_field: &Option<DateTimeField>,
+    ) -> Result<ParsedDateTime, ParserError> {
+        match integer {
+            Some(integer) => Self::parsed_datetime_from_int(integer, leading_field.clone()),
+            None => {
+                let options = DateTimeParseOptions {
+                    last_field: last_field.clone(),
+                    ..Default::default()
+                };
+                Self::parse_interval_string_with_options(raw_value, leading_field, &options)
+            }
+        }
+    }
+
     /// Parse an operator following an expression
     pub fn parse_infix(&mut self, expr: Expr, precedence: u8) -> Result<Expr, ParserError> {
         debug!("parsing infix");
@@ -884,17 +993,403 @@ impl Parser {
     ///   | <minutes value> [ <colon> <seconds value> ]
     ///   | <seconds value>
     /// ```
+    ///
+    /// The `<space>` between the days and the hours may also be written as a
+    /// `T`, as in `1-2-3T4:5:6`. A lone `DAY`, `HOUR` or `MINUTE` value may
+    /// have a fraction, which is spread into the smaller time fields, so
+    /// `-1.25` days is minus 1 day 6 hours and `1.5` minutes is a minute and
+    /// 30 seconds. The parts of a compound value or an ISO 8601 duration may
+    /// have fractions in the same way.
+    ///
+    /// ISO 8601 durations like `P1Y2M3DT4H5M6.7S` are also accepted, in which
+    /// case the designators determine the fields. So are PostgreSQL style
+    /// compound values like `1 day 2 hours`, where a unit word without a
+    /// number in front of it, as in `day`, means one of that unit. Their
+    /// units must be within the qualifier: from `leading_field` down to the
+    /// [`DateTimeParseOptions::last_field`], or without one, down to the
+    /// months for `YEAR`, `QUARTER` and `MONTH` and down to the seconds for
+    /// the others. So `'1 day'` is an error for an `HOUR`, and
+    /// `'1 day 2 hours'` for a `YEAR`. Use
+    /// [`Parser::parse_unqualified_interval_string`] for a value that has no
+    /// qualifier.
+    ///
+    /// A positional value may end in `AM` or `PM`, as in `4:05 PM`, in which
+    /// case its hour is read on a 12-hour clock: `12 AM` is `0` hours.
+    ///
+    /// Every number must fit in a `u64`, the years and months together must
+    /// be at most `i64::MAX` months, and the days, hours, minutes and seconds
+    /// together at most `u64::MAX` seconds. Anything larger is an "interval
+    /// field out of range" error. Values that are longer than 4096 bytes, or
+    /// that have more than 256 digits in a row, are rejected without being
+    /// parsed, see [`DateTimeParseOptions::max_length`].
+    ///
+    /// A value that is zero is always positive, so `-0` and `0` are equal.
     pub fn parse_interval_string(
         value: &str,
         leading_field: &DateTimeField,
     ) -> Result<ParsedDateTime, ParserError> {
-        if value.is_empty() {
-            return Err(ParserError::ParserError(
-                "Interval date string is empty!".to_string(),
+        Self::parse_interval_string_with_options(
+            value,
+            leading_field,
+            &DateTimeParseOptions::default(),
+        )
+    }
+
+    /// Like [`Parser::parse_interval_string`], but with control over how
+    /// strictly the value is interpreted
+    pub fn parse_interval_string_with_options(
+        value: &str,
+        leading_field: &DateTimeField,
+        options: &DateTimeParseOptions,
+    ) -> Result<ParsedDateTime, ParserError> {
+        Ok(Self::parse_interval_string_with_kind(
+            value,
+            leading_field,
+            options,
+        )?)
+    }
+
+    /// Like [`Parser::parse_interval_string_with_options`], but the error
+    /// says what kind of problem the value has, so that it can be handled
+    /// without looking at the message
+    ///
+    /// Its [`phase`](DateTimeError::phase) also tells a value whose
+    /// characters can't be read, like `'1!'`, from one that reads fine but
+    /// has fields that make no sense, like a month of `0`.
+    pub fn parse_interval_string_with_kind(
+        value: &str,
+        leading_field: &DateTimeField,
+        options: &DateTimeParseOptions,
+    ) -> Result<ParsedDateTime, DateTimeError> {
+        Self::parse_interval_with_qualifier(value, Some(leading_field), options)
+    }
+
+    /// Like [`Parser::parse_interval_string`], for a value that has no
+    /// qualifier, as in PostgreSQL's `INTERVAL '1 year 2 days'`
+    ///
+    /// The unit words of a compound value and the designators of an ISO 8601
+    /// duration may be any of the units. Any other value is read as if the
+    /// qualifier were `SECOND`, so a bare number is a number of seconds.
+    pub fn parse_unqualified_interval_string(value: &str) -> Result<ParsedDateTime, ParserError> {
+        Self::parse_unqualified_interval_string_with_options(
+            value,
+            &DateTimeParseOptions::default(),
+        )
+    }
+
+    /// Like [`Parser::parse_unqualified_interval_string`], with the options
+    /// of [`Parser::parse_interval_string_with_options`]
+    pub fn parse_unqualified_interval_string_with_options(
+        value: &str,
+        options: &DateTimeParseOptions,
+    ) -> Result<ParsedDateTime, ParserError> {
+        Ok(Self::parse_unqualified_interval_string_with_kind(
+            value, options,
+        )?)
+    }
+
+    /// Like [`Parser::parse_unqualified_interval_string_with_options`], but
+    /// the error says what kind of problem the value has, like
+    /// [`Parser::parse_interval_string_with_kind`]
+    pub fn parse_unqualified_interval_string_with_kind(
+        value: &str,
+        options: &DateTimeParseOptions,
+    ) -> Result<ParsedDateTime, DateTimeError> {
+        Self::parse_interval_with_qualifier(value, None, options)
+    }
+
+    /// Parse an interval value, checking the units in it against the leading
+    /// field of the `qualifier` if there is one
+    fn parse_interval_with_qualifier(
+        value: &str,
+        qualifier: Option<&DateTimeField>,
+        options: &DateTimeParseOptions,
+    ) -> Result<ParsedDateTime, DateTimeError> {
+        let leading_field = qualifier.unwrap_or(&DateTimeField::Second);
+        options.validate()?;
+        datetime::check_input_limits(value, options)?;
+        let value = if options.strip_quotes {
+            datetime::strip_quotes(value)
+        } else {
+            value
+        };
+        let ungrouped;
+        let value = match options.group_separator {
+            Some(separator) => {
+                ungrouped = datetime::strip_group_separators(value, separator)?;
+                ungrouped.as_str()
+            }
+            None => value,
+        };
+        let truncated = datetime::truncate_fraction_digits(value, options)?;
+        let value = truncated.as_deref().unwrap_or(value);
+        let (value, precision) = if options.embedded_precision {
+            datetime::split_precision_marker(value)?
+        } else {
+            (value, options.fractional_seconds_precision)
+        };
+        if value.trim().is_empty() {
+            if options.empty_is_zero {
+                let mut pdt = ParsedDateTime::zero();
+                if let Some(leading_field) = qualifier {
+                    datetime::record_qualifier(&mut pdt, leading_field, options);
+                }
+                return Ok(pdt);
+            }
+            return Err(DateTimeError::new(
+                ErrorKind::EmptyField,
+                ParserError::ParserError("Interval date string is empty!".to_string()),
             ));
         }
-        let toks = datetime::tokenize_interval(value)?;
-        datetime::build_parsed_datetime(&toks, leading_field, value)
+        let full_value = value;
+        let (value, meridiem) = datetime::split_meridiem(value);
+        let mut pdt = if options.unit_suffixes && datetime::is_unit_suffix_interval(value) {
+            datetime::parse_unit_suffix_interval(value)?
+        } else if datetime::is_iso8601_duration(value) {
+            let toks = datetime::tokenize_iso8601_duration(value)?;
+            if let Some(leading_field) = qualifier {
+                datetime::check_units_in_qualifier(&toks, leading_field, options, value)?;
+            }
+            // a sign before the `P` is for the whole duration
+            match toks.split_first() {
+                Some((IntervalToken::Dash, toks)) => {
+                    datetime::build_parsed_datetime_from_units(toks, value)?.negate()
+                }
+                _ => datetime::build_parsed_datetime_from_units(&toks, value)?,
+            }
+        } else if datetime::is_compound_interval(value) {
+            let toks = datetime::tokenize_compound_interval(value, options)?;
+            if let Some(leading_field) = qualifier {
+                datetime::check_units_in_qualifier(&toks, leading_field, options, value)?;
+            }
+            datetime::build_parsed_datetime_from_units(&toks, value)?
+        } else {
+            if !value.chars().any(|chr| chr.is_ascii_digit()) {
+                return Err(DateTimeError::new(
+                    ErrorKind::EmptyField,
+                    ParserError::ParserError(format!(
+                        "Invalid interval '{}': no numeric value",
+                        value
+                    )),
+                ));
+            }
+            match datetime::build_integer_interval(value, leading_field, options) {
+                Some(pdt) => pdt,
+                None => {
+                    let toks = datetime::interval_tokens(value, options);
+                    let mut pdt =
+                        datetime::build_parsed_datetime(toks, leading_field, value, options)?;
+                    if options.clock_fields && value.contains(':') {
+                        datetime::check_clock_fields(&pdt, value, options)?;
+                        if options.end_of_day && options.normalize {
+                            pdt.roll_over_end_of_day();
+                        }
+                    }
+                    pdt
+                }
+            }
+        };
+        if let Some(meridiem) = meridiem {
+            datetime::apply_meridiem(&mut pdt, meridiem, full_value)?;
+        }
+        if let Some(precision) = precision {
+            datetime::round_fractional_seconds(&mut pdt, precision, options)?;
+        }
+        if let Some(precision) = options.leading_precision {
+            datetime::check_leading_precision(&pdt, leading_field, precision, full_value)?;
+        }
+        if options.normalize {
+            pdt = pdt.normalize();
+        }
+        datetime::check_interval_range(&pdt, full_value)?;
+        if let Some(bound) = &options.max_magnitude {
+            datetime::check_max_magnitude(&pdt, bound, full_value)?;
+        }
+        // There is no negative zero, so that `-0` and `0` are equal
+        if pdt.is_zero() {
+            pdt.is_positive = true;
+        }
+        if let Some(leading_field) = qualifier {
+            datetime::record_qualifier(&mut pdt, leading_field, options);
+        }
+        Ok(pdt)
+    }
+
+    /// Like [`Parser::parse_interval_string`], but only accepting the forms
+    /// of value that `dialect` does, see [`Dialect::interval_grammar`]
+    ///
+    /// With [`MySqlDialect`](crate::dialect::MySqlDialect), `'1 day'` and
+    /// `'P1D'` are rejected, while they are accepted with
+    /// [`PostgreSqlDialect`](crate::dialect::PostgreSqlDialect).
+    pub fn parse_interval_string_for_dialect(
+        value: &str,
+        leading_field: &DateTimeField,
+        dialect: &dyn Dialect,
+    ) -> Result<ParsedDateTime, ParserError> {
+        datetime::check_interval_grammar(value, dialect.interval_grammar())?;
+        Self::parse_interval_string(value, leading_field)
+    }
+
+    /// Build an interval from an integer of the leading field, as in
+    /// `INTERVAL 5 HOUR` in dialects that allow a number instead of a string
+    ///
+    /// The number is placed as if it were the whole value of a string, so
+    /// `parsed_datetime_from_int(-5, DateTimeField::Hour)` is the same as
+    /// parsing `'-5'` as an `HOUR` interval, without formatting and
+    /// tokenizing it first.
+    pub fn parsed_datetime_from_int(
+        value: i64,
+        field: DateTimeField,
+    ) -> Result<ParsedDateTime, ParserError> {
+        let sign = if value < 0 {
+            Some(IntervalToken::Dash)
+        } else {
+            None
+        };
+        let tokens = sign
+            .into_iter()
+            .chain(core::iter::once(IntervalToken::Num(value.unsigned_abs())))
+            .map(Ok);
+        let text = value.to_string();
+        let mut pdt = datetime::build_parsed_datetime(
+            tokens,
+            &field,
+            &text,
+            &DateTimeParseOptions::default(),
+        )?;
+        datetime::check_interval_range(&pdt, &text)?;
+        if pdt.is_zero() {
+            pdt.is_positive = true;
+        }
+        Ok(pdt)
+    }
+
+    /// The leading field that a value without a qualifier is read with
+    ///
+    /// PostgreSQL allows `INTERVAL '30'` without a qualifier and works out
+    /// the layout from the value itself. The first of these that applies
+    /// decides:
+    ///
+    /// | value                          | example     | leading field |
+    /// |--------------------------------|-------------|---------------|
+    /// | ISO 8601 or with unit words    | `'1 day'`   | `SECOND`      |
+    /// | a `-` after the first number   | `'1-2'`     | `YEAR`        |
+    /// | a number, a space and a time   | `'1 2:00'`  | `DAY`         |
+    /// | a `:`                          | `'1:00'`    | `HOUR`        |
+    /// | anything else, like a number   | `'30'`      | `SECOND`      |
+    ///
+    /// Values with unit words carry their own fields, so their leading field
+    /// makes no difference.
+    pub fn infer_interval_leading_field(value: &str) -> DateTimeField {
+        datetime::infer_leading_field(value)
+    }
+
+    /// Parse each of a list of interval values with the same leading field
+    ///
+    /// This stops at the first value that doesn't parse. Its error is
+    /// prefixed with the value's index in the list, counting from 0, so
+    /// `["1 day", "x"]` fails with a message starting `interval 1 of the
+    /// list:`.
+    pub fn parse_interval_list(
+        values: &[&str],
+        leading_field: DateTimeField,
+    ) -> Result<Vec<ParsedDateTime>, ParserError> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| {
+                Self::parse_interval_string(value, &leading_field).map_err(|e| match e {
+                    ParserError::TokenizerError(s) => {
+                        ParserError::TokenizerError(format!("interval {} of the list: {}", i, s))
+                    }
+                    ParserError::ParserError(s) => {
+                        ParserError::ParserError(format!("interval {} of the list: {}", i, s))
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Find every problem with an interval value, without stopping at the first
+    ///
+    /// This is meant for editor integrations: along with the errors that
+    /// would make [`Parser::parse_interval_string`] fail, it warns about
+    /// things like stray whitespace, fields that are out of their usual
+    /// range (`'1:75'`), and `YEAR` or `MONTH` values that go on to specify
+    /// days and times. Every issue has the byte range of `value` it is about,
+    /// and the issues are sorted by where they start. A value that parses
+    /// cleanly has no issues.
+    pub fn lint_interval(value: &str, leading_field: &DateTimeField) -> Vec<IntervalIssue> {
+        datetime::lint_interval(value, leading_field)
+    }
+
+    /// Parse an interval like [`Parser::parse_interval_string`], and also
+    /// say what about it looks odd
+    ///
+    /// The warnings are for values that parse but may not be what was meant:
+    /// minutes or seconds of 60 or more, as in `'90 seconds'`, which are
+    /// kept as they are unless the value is normalized, a value that is
+    /// zero, and a leading field of zero, as in `'0 12:00' DAY`. A value that
+    /// doesn't parse is an error, as usual. See [`Parser::lint_interval`]
+    /// for the problems with how a value is written.
+    pub fn parse_interval_with_warnings(
+        value: &str,
+        leading_field: &DateTimeField,
+    ) -> Result<(ParsedDateTime, Vec<String>), ParserError> {
+        let pdt = Self::parse_interval_string(value, leading_field)?;
+        let warnings = datetime::interval_warnings(&pdt, value);
+        Ok((pdt, warnings))
+    }
+
+    /// Split an interval value into the tokens it is parsed from
+    ///
+    /// This is meant for looking into why a value doesn't parse the way it
+    /// was expected to. The value is tokenized like it is by
+    /// [`Parser::parse_interval_string`], as an ISO 8601 duration, a compound
+    /// value or a positional one, but nothing is checked beyond what the
+    /// tokenizers themselves reject. The tokens can be rendered compactly
+    /// with their `Display` impl.
+    pub fn debug_tokenize_interval(value: &str) -> Result<Vec<IntervalToken>, ParserError> {
+        let options = DateTimeParseOptions::default();
+        let toks = if datetime::is_iso8601_duration(value) {
+            datetime::tokenize_iso8601_duration(value)?
+        } else if datetime::is_compound_interval(value) {
+            datetime::tokenize_compound_interval(value, &options)?
+        } else {
+            datetime::tokenize_interval(value, &options)?
+        };
+        Ok(toks)
+    }
+
+    /// Tokenize the positional interval at the start of `value`, for parsers
+    /// that embed intervals in a larger grammar
+    ///
+    /// This returns the tokens and the byte offset where the interval ends,
+    /// which is where the surrounding grammar picks up, so `'1:30 AND x'`
+    /// gives the tokens of `1:30` and 4. The interval stops at the first
+    /// character that can't be part of it, and spaces, colons and signs at
+    /// its end are not part of it.
+    pub fn tokenize_interval_prefix(
+        value: &str,
+    ) -> Result<(Vec<IntervalToken>, usize), ParserError> {
+        Ok(datetime::tokenize_interval_prefix(
+            value,
+            &DateTimeParseOptions::default(),
+        )?)
+    }
+
+    /// Split a positional interval value into its tokens, going past the
+    /// problems that don't stop the rest of it from being read
+    ///
+    /// Unlike [`Parser::debug_tokenize_interval`], which stops at the first
+    /// problem, this returns every one of them, so that an editor can
+    /// underline them all at once. An invalid character is skipped with the
+    /// rest of its field, and an empty field, as in `'1::2'`, is left out of
+    /// the tokens. Each error gives its offset in `value`.
+    pub fn tokenize_interval_collect(value: &str) -> (Vec<IntervalToken>, Vec<ParserError>) {
+        let (toks, errors) =
+            datetime::tokenize_interval_collect(value, &DateTimeParseOptions::default());
+        (toks, errors.into_iter().map(ParserError::from).collect())
     }
 
     pub fn parse_timestamp_string(
@@ -907,17 +1402,65 @@ impl Parser {
             ));
         }
 
+        if let Some(special) = DateTimeSpecial::from_keyword(value) {
+            return parser_err!(
+                "Timestamp '{}' is the special value {}, which needs resolving against the \
+                 current time, see Parser::parse_timestamp_or_special",
+                value,
+                special
+            );
+        }
+
         let (ts_string, tz_string) = datetime::split_timestamp_string(value);
 
         let mut pdt = Self::parse_interval_string(ts_string, &DateTimeField::Year)?;
-        if !parse_timezone || tz_string.is_empty() {
-            return Ok(pdt);
-        }
+        let tz_string = match tz_string {
+            Some(tz_string) if parse_timezone => tz_string,
+            _ => return Ok(pdt),
+        };
 
         pdt.timezone_offset_second = Some(datetime::parse_timezone_offset_second(tz_string)?);
         Ok(pdt)
     }
 
+    /// Parse a timestamp string like [`Parser::parse_timestamp_string`], or
+    /// recognize one of the keywords `now`, `today`, `tomorrow` and
+    /// `yesterday`, in any case
+    ///
+    /// Those keywords are left for the caller to resolve against the current
+    /// time. They are never an interval, so the interval parsers reject them.
+    pub fn parse_timestamp_or_special(
+        value: &str,
+        parse_timezone: bool,
+    ) -> Result<DateTimeOrSpecial, ParserError> {
+        match DateTimeSpecial::from_keyword(value) {
+            Some(special) => Ok(DateTimeOrSpecial::Special(special)),
+            None => Ok(DateTimeOrSpecial::DateTime(Self::parse_timestamp_string(
+                value,
+                parse_timezone,
+            )?)),
+        }
+    }
+
+    /// Parse a timezone offset on its own, like the `'+05:30'` in
+    /// `AT TIME ZONE '+05:30'`, into seconds east of UTC
+    ///
+    /// The offset is written as `{+|-}HH:MM`, `{+|-}HHMM` or `{+|-}HH`, or
+    /// is `Z` for UTC. Of the named timezones only `UTC`, `GMT`, `UT` and
+    /// `Zulu` are supported, in any case.
+    pub fn parse_timezone_offset(value: &str) -> Result<i64, ParserError> {
+        Ok(datetime::parse_timezone_offset_second(value)?)
+    }
+
+    /// Parse a timezone offset written as a signed number of minutes east of
+    /// UTC, like `'-480'` for Pacific Standard Time, into seconds
+    ///
+    /// The offset must be at most 14 hours either way. See also
+    /// [`ParsedDateTime::with_timezone_minutes`].
+    pub fn parse_timezone_minutes(value: &str) -> Result<i64, ParserError> {
+        Ok(datetime::parse_timezone_minutes(value)?)
+    }
+
     /// Parses the parens following the `[ NOT ] IN` operator
     pub fn parse_in(&mut self, expr: Expr, negated: bool) -> Result<Expr, ParserError> {
         self.expect_token(&Token::LParen)?;
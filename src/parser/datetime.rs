@@ -1,76 +1,846 @@
-use crate::ast::ParsedDateTime;
+use crate::ast::{
+    DateTimeSpecial, IntervalStyle, ParsedDateTime, DATE_TIME_FIELD_KEYWORDS,
+    MAX_TIMEZONE_OFFSET_MINUTES,
+};
+use crate::dialect::IntervalGrammar;
 use crate::parser::{DateTimeField, ParserError};
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::Range;
 
-pub(crate) fn tokenize_interval(value: &str) -> Result<Vec<IntervalToken>, ParserError> {
-    let mut toks = vec![];
-    let mut num_buf = String::with_capacity(4);
-    fn parse_num(n: &str, idx: usize) -> Result<IntervalToken, ParserError> {
-        Ok(IntervalToken::Num(n.parse().map_err(|e| {
-            ParserError::ParserError(format!(
-                "Unable to parse value as a number at index {}: {}",
-                idx, e
-            ))
-        })?))
+mod lint;
+pub(crate) use self::lint::lint_interval;
+pub use self::lint::{IntervalIssue, IssueSeverity};
+
+macro_rules! datetime_err {
+    ($kind:ident, $($arg:tt)*) => {
+        Err(DateTimeError::new(
+            ErrorKind::$kind,
+            ParserError::ParserError(format!($($arg)*)),
+        ))
     };
-    let mut last_field_is_frac = false;
-    for (i, chr) in value.chars().enumerate() {
-        match chr {
-            '-' => {
-                // dashes at the beginning mean make it negative
-                if !num_buf.is_empty() {
-                    toks.push(parse_num(&num_buf, i)?);
-                    num_buf.clear();
-                }
-                toks.push(IntervalToken::Dash);
+}
+
+macro_rules! tokenizer_err {
+    ($kind:ident, $($arg:tt)*) => {
+        Err(DateTimeError::new(
+            ErrorKind::$kind,
+            ParserError::TokenizerError(format!($($arg)*)),
+        ))
+    };
+}
+
+/// The kind of problem a [`DateTimeError`] is about
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// A character that can't appear where it does, like the `!` in `'1!'`
+    UnexpectedChar,
+    /// The fields aren't laid out the way the value's format expects
+    InvalidLayout,
+    /// A field is outside of the range it must be in, like a month of `0`
+    OutOfRange,
+    /// A number, or the value as a whole, is too large to be represented
+    Overflow,
+    /// A value or a field without any digits
+    EmptyField,
+    /// The same unit is given more than once
+    DuplicateField,
+    /// A word that isn't one of the units
+    UnknownUnit,
+    /// Something that is valid SQL but that isn't supported, like a named
+    /// timezone
+    Unsupported,
+    /// The value is longer than the limits in the [`DateTimeParseOptions`]
+    TooLong,
+    /// The value ends with a delimiter, like `'1-'`, so it might be valid
+    /// with more input
+    ///
+    /// Every other kind is wrong however the value goes on.
+    Incomplete,
+}
+
+/// Which step of parsing a value a [`DateTimeError`] comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorPhase {
+    /// Reading the characters of the value into numbers, separators and
+    /// unit words
+    ///
+    /// These are values that can't be read at all, like `'1!'`, `'1 fortnight'`
+    /// or a number too large for a `u64`.
+    Tokenize,
+    /// Putting the tokens together into the fields of the value, and checking
+    /// those fields
+    ///
+    /// These are values that read fine but don't make sense, like a month of
+    /// `13` in `'1-13' YEAR TO MONTH` or fields in the wrong order.
+    Build,
+}
+
+/// How a fraction is rounded when digits are dropped from it, see
+/// [`DateTimeParseOptions::fraction_rounding`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FractionRounding {
+    /// Drop the digits, rounding towards zero
+    Truncate,
+    /// Round to the nearest, with halves rounded away from zero
+    Round,
+    /// Round towards positive infinity
+    Ceil,
+    /// Round towards negative infinity
+    Floor,
+}
+
+impl FractionRounding {
+    /// Whether a fraction with `dropped` left over is rounded away from zero,
+    /// where `half` is how much `dropped` is at the halfway point
+    fn rounds_up(self, dropped: u64, half: u64, is_positive: bool) -> bool {
+        dropped > 0
+            && match self {
+                FractionRounding::Truncate => false,
+                FractionRounding::Round => dropped >= half,
+                FractionRounding::Ceil => is_positive,
+                FractionRounding::Floor => !is_positive,
             }
-            ' ' => {
-                toks.push(parse_num(&num_buf, i)?);
-                num_buf.clear();
-                toks.push(IntervalToken::Space);
+    }
+}
+
+/// A [`ParserError`] from parsing a date, time or interval value, along with
+/// what kind of problem it is
+///
+/// This converts into the `ParserError`, and displays the same way.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DateTimeError {
+    pub kind: ErrorKind,
+    /// Whether the value couldn't be read, or could be but made no sense
+    pub phase: ErrorPhase,
+    pub error: ParserError,
+    /// The tokens that would have been valid where the value went wrong
+    ///
+    /// This is for suggesting completions in an editor. It is only filled
+    /// in when a positional value doesn't match its layout, or is
+    /// [`ErrorKind::Incomplete`], and is empty otherwise. Numbers are
+    /// `Num(0)` and fractions `Nanos(0, 0)`.
+    pub expected_tokens: Vec<IntervalToken>,
+}
+
+impl DateTimeError {
+    /// An error in the [`ErrorPhase::Tokenize`] phase if it is a
+    /// [`ParserError::TokenizerError`], and otherwise in [`ErrorPhase::Build`]
+    pub(crate) fn new(kind: ErrorKind, error: ParserError) -> DateTimeError {
+        let phase = match error {
+            ParserError::TokenizerError(_) => ErrorPhase::Tokenize,
+            ParserError::ParserError(_) => ErrorPhase::Build,
+        };
+        DateTimeError {
+            kind,
+            phase,
+            error,
+            expected_tokens: vec![],
+        }
+    }
+
+    /// This error, as one found while the value was being tokenized
+    ///
+    /// The tokenizers also report problems with numbers that they read, and
+    /// those are [`ParserError::ParserError`]s.
+    pub(crate) fn tokenizing(self) -> DateTimeError {
+        DateTimeError {
+            phase: ErrorPhase::Tokenize,
+            ..self
+        }
+    }
+
+    pub(crate) fn with_expected_tokens(self, expected_tokens: Vec<IntervalToken>) -> DateTimeError {
+        DateTimeError {
+            expected_tokens,
+            ..self
+        }
+    }
+}
+
+impl From<DateTimeError> for ParserError {
+    fn from(e: DateTimeError) -> ParserError {
+        e.error
+    }
+}
+
+impl fmt::Display for DateTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DateTimeError {}
+
+/// Options controlling how [`Parser::parse_interval_string_with_options`]
+/// interprets a value
+///
+/// The defaults match [`Parser::parse_interval_string`]. Options that
+/// contradict each other are rejected before a value is parsed, see
+/// [`DateTimeParseOptions::validate`].
+///
+/// [`Parser::parse_interval_string`]: crate::parser::Parser::parse_interval_string
+/// [`Parser::parse_interval_string_with_options`]: crate::parser::Parser::parse_interval_string_with_options
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateTimeParseOptions {
+    /// Require a `YEAR` or `MONTH` value to only contain year-month fields
+    ///
+    /// By default `'9-5 4:3' MONTH` is read as 9 months 5 days 4 hours and
+    /// 3 minutes. When this is set a `MONTH` value may only be a number of
+    /// months, and a `YEAR` value may only be `<years>[-<months>]`.
+    pub strict_year_month: bool,
+    /// The last field of a qualifier like `DAY TO MINUTE`
+    ///
+    /// A positional value may not go on past this field, so `'1 2:03:04' DAY TO MINUTE`
+    /// is rejected, while `'1 2:03' DAY TO MINUTE` and `'1 2' DAY TO MINUTE`
+    /// are accepted. By default a value may run through the seconds, and
+    /// [`IntervalValue::fields_match_precision`] can report the extra fields
+    /// afterwards. It is ignored for a `QUARTER` value.
+    ///
+    /// [`IntervalValue::fields_match_precision`]: crate::ast::IntervalValue::fields_match_precision
+    pub last_field: Option<DateTimeField>,
+    /// Limit the digits of the leading field, like `DAY(p)`
+    ///
+    /// Must be at least `1`. With a precision of `2`, `'99' DAY` is accepted
+    /// but `'999' DAY` is rejected. Leading zeros are not counted.
+    pub leading_precision: Option<u64>,
+    /// Round the fractional seconds to this many digits, like `SECOND(p)`
+    ///
+    /// Must be between `0` and `9`. Halves are rounded up, which can carry
+    /// into the seconds: `'1.9999'` with a precision of `3` is `2.000`.
+    pub fractional_seconds_precision: Option<u64>,
+    /// Accept a trailing precision marker inside the value, e.g. `'1.2345 (3)'`
+    ///
+    /// The marker is stripped before parsing and the fraction is rounded as
+    /// if it had been passed as the `fractional_seconds_precision`, which it
+    /// takes precedence over. By default a marker is an invalid character.
+    pub embedded_precision: bool,
+    /// Accept a comma as the decimal point, e.g. `'1,5'` for one and a half
+    ///
+    /// By default a comma is an invalid character, except between the parts
+    /// of a compound value like `'1 day, 2 hours'`, where it is ignored.
+    pub decimal_comma: bool,
+    /// Strip a separator from between the digits of a number, as in `'1_000 days'`
+    ///
+    /// The separator must be `_` or `,`, and is only removed where it has a
+    /// digit on both sides, so the comma in `'1 day, 2 hours'` still
+    /// separates the parts of the value. Where it is removed it has to
+    /// separate groups of three digits, as in `'1,000,000'`, so `'1,5'` is
+    /// an error rather than 15. `,` can't be used together with
+    /// [`DateTimeParseOptions::decimal_comma`]. By default a separator is an
+    /// invalid character.
+    pub group_separator: Option<char>,
+    /// Reject a fraction with more digits than nanoseconds can keep
+    ///
+    /// A fraction is kept to the nanosecond, 9 digits. By default the digits
+    /// past the ninth are dropped, so `'1.123456789012'` is read as
+    /// `'1.123456789'`. When this is set such a value is an
+    /// [`ErrorKind::OutOfRange`] error instead, for callers that would rather
+    /// not lose the precision they were given.
+    pub strict_precision: bool,
+    /// How fractions are rounded when they lose digits
+    ///
+    /// This applies to the digits past the ninth, and to the digits past a
+    /// [`DateTimeParseOptions::fractional_seconds_precision`]. A rounding
+    /// that carries goes on into the whole number, so with
+    /// [`FractionRounding::Round`] `'1.9999999999'` is `2.000000000`. By
+    /// default the digits past the ninth are truncated, and
+    /// `fractional_seconds_precision` rounds halves up.
+    pub fraction_rounding: Option<FractionRounding>,
+    /// Require a `YEAR` value to have months, as for a `YEAR TO MONTH` qualifier
+    ///
+    /// By default `'2023'` is read as 2023 years with the months left unset,
+    /// even though a `YEAR TO MONTH` value should be written `'2023-0'`. When
+    /// this is set the dash and the months must be present.
+    pub strict_year_to_month: bool,
+    /// Read an `HOUR` value of exactly 4 or 6 digits as `HHMM` or `HHMMSS`
+    ///
+    /// By default `'1203'` is 1203 hours. When this is set it is 12 hours
+    /// and 3 minutes, and `'120305'` is 12 hours 3 minutes and 5 seconds.
+    /// Values with any other number of digits are read as usual.
+    pub compact_time: bool,
+    /// Carry seconds and minutes of 60 or more into the larger time fields
+    ///
+    /// By default `'90061' SECOND` is kept as 90061 seconds. When this is set
+    /// it is 25 hours 1 minute and 1 second, like PostgreSQL stores it, see
+    /// [`ParsedDateTime::normalize`].
+    pub normalize: bool,
+    /// Strip whitespace and a pair of quotes from around the value
+    ///
+    /// This is for values pasted from elsewhere, like `" '1 day' "`. The
+    /// surrounding ASCII whitespace is removed, and then a single pair of
+    /// matching `'` or `"` quotes. A quote without a match is left alone, and
+    /// so is still an invalid character. By default the value is used as is.
+    pub strip_quotes: bool,
+    /// Hold the time of a `h:m:s` value to a clock's ranges
+    ///
+    /// By default `'25:00:00'` is 25 hours, as a duration. When this is set
+    /// a value written like a time of day, with a colon, must have hours
+    /// from 0 to 23 and minutes and seconds from 0 to 59. A value without a
+    /// colon, like `'25' HOUR` or `'1 day 25 hours'`, is still a duration.
+    pub clock_fields: bool,
+    /// Allow a leap second under [`DateTimeParseOptions::clock_fields`]
+    ///
+    /// A timestamp may read `'23:59:60'` for the second added at the end of
+    /// a day. When this is set, a seconds value of exactly 60 passes the
+    /// clock check; 61 and up are still out of range. It is an error to set
+    /// this without `clock_fields`.
+    pub leap_second: bool,
+    /// Allow the end of a day, `'24:00:00'`, under [`DateTimeParseOptions::clock_fields`]
+    ///
+    /// ISO 8601 writes midnight at the end of a day as `24:00:00`. When this
+    /// is set an hour of 24 passes the clock check if the minutes, seconds
+    /// and fraction are all zero, so `'24:00:00'` is accepted and
+    /// `'24:00:01'` is still out of range. With
+    /// [`DateTimeParseOptions::normalize`] it becomes `00:00:00` of the next
+    /// day. It is an error to set this without `clock_fields`.
+    pub end_of_day: bool,
+    /// Keep the qualifier on the value, see [`ParsedDateTime::leading_field`]
+    ///
+    /// When this is set the leading field, and the
+    /// [`DateTimeParseOptions::last_field`] if there is one, are stored on
+    /// the parsed value, so that it can be written out with the qualifier it
+    /// was read with. By default they are left unset, and values parsed with
+    /// different qualifiers compare equal.
+    pub record_qualifier: bool,
+    /// Read an empty or whitespace-only value as a zero interval
+    ///
+    /// By default `''` and `'   '` are rejected with an
+    /// [`ErrorKind::EmptyField`] error. When this is set they are
+    /// [`ParsedDateTime::zero`] instead, for data where a blank means no
+    /// time at all.
+    pub empty_is_zero: bool,
+    /// The longest interval that is accepted, either way from zero
+    ///
+    /// This is a policy for services that don't want to take, say, more than
+    /// `'10 years'` from their users. The lengths are compared with
+    /// [`ParsedDateTime::approx_total_seconds`], so a year is 365 days and a
+    /// month 30, and a value longer than the bound is an
+    /// [`ErrorKind::OutOfRange`] error. By default there is no bound past
+    /// what the fields can hold.
+    pub max_magnitude: Option<ParsedDateTime>,
+    /// Read compact durations with a unit after each number, like `'5h30m'`
+    ///
+    /// This is the style of Go's `time.ParseDuration`, which is common in
+    /// configuration. The units are `d`, `h`, `m`, `s`, `ms`, `us` (or `µs`)
+    /// and `ns`, and `m` is always minutes, never months. Each number may
+    /// have a fraction, and a sign at the start applies to the whole value,
+    /// so `'-1.5h'` is minus an hour and 30 minutes. The parts are added up,
+    /// so `'90s'` is kept as 90 seconds and `'500ms'` is half a second. By
+    /// default such values are rejected.
+    pub unit_suffixes: bool,
+    /// The longest value, in bytes, that will be parsed
+    ///
+    /// Longer values are rejected before they are looked at, so that
+    /// untrusted input can't make parsing take unbounded time. Defaults to
+    /// [`DateTimeParseOptions::DEFAULT_MAX_LENGTH`].
+    pub max_length: usize,
+    /// The most digits in a row that a value may have
+    ///
+    /// A number never needs more than 20 digits, and a fraction more than 9,
+    /// but leading zeros are allowed. Defaults to
+    /// [`DateTimeParseOptions::DEFAULT_MAX_DIGITS`].
+    pub max_digits: usize,
+}
+
+impl DateTimeParseOptions {
+    pub const DEFAULT_MAX_LENGTH: usize = 4096;
+    pub const DEFAULT_MAX_DIGITS: usize = 256;
+
+    /// Check that the options make sense, on their own and together
+    ///
+    /// Every value is parsed with the options checked first, so options that
+    /// would otherwise be ignored or contradict each other, like a
+    /// [`DateTimeParseOptions::leap_second`] without
+    /// [`DateTimeParseOptions::clock_fields`], are an
+    /// [`ErrorKind::Unsupported`] error rather than a surprise later.
+    pub fn validate(&self) -> Result<(), DateTimeError> {
+        if let Some(separator) = self.group_separator {
+            if separator != '_' && separator != ',' {
+                return datetime_err!(
+                    Unsupported,
+                    "the group separator must be '_' or ',', got {:?}",
+                    separator
+                );
             }
-            ':' => {
-                toks.push(parse_num(&num_buf, i)?);
-                num_buf.clear();
-                toks.push(IntervalToken::Colon);
+            if separator == ',' && self.decimal_comma {
+                return datetime_err!(
+                    Unsupported,
+                    "',' can't be both the group separator and the decimal point"
+                );
             }
-            '.' => {
-                toks.push(parse_num(&num_buf, i)?);
-                num_buf.clear();
-                toks.push(IntervalToken::Dot);
+        }
+        if let Some(precision) = self.fractional_seconds_precision {
+            if precision > 9 {
+                return datetime_err!(
+                    OutOfRange,
+                    "fractional seconds precision must be between 0 and 9, got {}",
+                    precision
+                );
+            }
+        }
+        for (is_set, option) in &[
+            (self.leap_second, "leap_second"),
+            (self.end_of_day, "end_of_day"),
+        ] {
+            if *is_set && !self.clock_fields {
+                return datetime_err!(
+                    Unsupported,
+                    "the {} option only applies to clock fields, but clock_fields is not set",
+                    option
+                );
+            }
+        }
+        // the digits past the ninth are rejected, so there is nothing left
+        // for the rounding to apply to
+        let rounds_precision =
+            self.fractional_seconds_precision.is_some() || self.embedded_precision;
+        if self.fraction_rounding.is_some() && self.strict_precision && !rounds_precision {
+            return datetime_err!(
+                Unsupported,
+                "the fraction_rounding option does nothing with strict_precision, unless a \
+                 fractional seconds precision is given"
+            );
+        }
+        Ok(())
+    }
+}
+
+impl Default for DateTimeParseOptions {
+    fn default() -> DateTimeParseOptions {
+        DateTimeParseOptions {
+            strict_year_month: false,
+            last_field: None,
+            leading_precision: None,
+            fractional_seconds_precision: None,
+            embedded_precision: false,
+            decimal_comma: false,
+            group_separator: None,
+            strict_precision: false,
+            fraction_rounding: None,
+            strict_year_to_month: false,
+            compact_time: false,
+            normalize: false,
+            strip_quotes: false,
+            clock_fields: false,
+            leap_second: false,
+            end_of_day: false,
+            record_qualifier: false,
+            empty_is_zero: false,
+            max_magnitude: None,
+            unit_suffixes: false,
+            max_length: DateTimeParseOptions::DEFAULT_MAX_LENGTH,
+            max_digits: DateTimeParseOptions::DEFAULT_MAX_DIGITS,
+        }
+    }
+}
+
+pub(crate) fn tokenize_interval(
+    value: &str,
+    options: &DateTimeParseOptions,
+) -> Result<Vec<IntervalToken>, DateTimeError> {
+    interval_tokens(value, options).collect()
+}
+
+/// Tokenize the positional interval at the start of `value`, returning its
+/// tokens and how many bytes of `value` it takes up
+///
+/// The interval ends before the first character that a positional value
+/// can't have, before a sign that follows a space and before a second
+/// decimal point, so `'1:30 - x'` is `1:30` and takes 4 bytes. Spaces,
+/// colons and signs at the end are left for the caller, because a field
+/// would have to follow them. It is an error if `value` doesn't start with
+/// an interval, or if the interval it starts with can't be tokenized.
+pub(crate) fn tokenize_interval_prefix(
+    value: &str,
+    options: &DateTimeParseOptions,
+) -> Result<(Vec<IntervalToken>, usize), DateTimeError> {
+    let mut end = value.len();
+    let mut seen_dot = false;
+    let mut after_space = false;
+    for (i, chr) in value.char_indices() {
+        let is_dot = chr == '.' || (chr == ',' && options.decimal_comma);
+        let fits = match chr {
+            '-' | '+' => !after_space,
+            ' ' | 'T' | 't' | ':' => true,
+            _ if is_dot => !seen_dot,
+            chr => chr.is_ascii_digit(),
+        };
+        if !fits {
+            end = i;
+            break;
+        }
+        seen_dot |= is_dot;
+        after_space = chr == ' ';
+    }
+    let prefix = value[..end].trim_end_matches(&[' ', 'T', 't', ':', '-', '+'][..]);
+    if !prefix.contains(|c: char| c.is_ascii_digit()) {
+        return tokenizer_err!(
+            InvalidLayout,
+            "Invalid interval '{}': it doesn't start with an interval",
+            value
+        );
+    }
+    Ok((tokenize_interval(prefix, options)?, prefix.len()))
+}
+
+/// Tokenize a positional value like [`tokenize_interval`], but keep going
+/// past the problems that don't stop the rest of it from being read
+///
+/// This is for editors, which want to underline everything that is wrong at
+/// once. An invalid character is skipped along with the rest of its field,
+/// after the number before it, and a field with no number, as in `'1::2'`,
+/// is left out. Numbers that don't fit are left out too. Each problem is
+/// recorded with its offset, in the order they appear. When there are no
+/// errors the tokens are those of `tokenize_interval`.
+pub(crate) fn tokenize_interval_collect(
+    value: &str,
+    options: &DateTimeParseOptions,
+) -> (Vec<IntervalToken>, Vec<DateTimeError>) {
+    let mut toks = vec![];
+    let mut errors = vec![];
+    let mut num_start: Option<usize> = None;
+    let mut last_field_is_frac = false;
+    // the field that an invalid character was in is only reported once
+    let mut in_bad_field = false;
+    let take_num = |num_start: &mut Option<usize>,
+                    end: usize,
+                    idx: usize,
+                    toks: &mut Vec<IntervalToken>,
+                    errors: &mut Vec<DateTimeError>| {
+        match parse_number(&value[num_start.take().unwrap_or(end)..end], idx) {
+            Ok(num) => toks.push(IntervalToken::Num(num)),
+            Err(e) => errors.push(e),
+        }
+    };
+    for (i, (byte_idx, chr)) in value.char_indices().enumerate() {
+        let delimiter = match chr {
+            '-' | '+' => {
+                if num_start.is_some() {
+                    take_num(&mut num_start, byte_idx, i, &mut toks, &mut errors);
+                }
+                in_bad_field = false;
+                toks.push(if chr == '-' {
+                    IntervalToken::Dash
+                } else {
+                    IntervalToken::Plus
+                });
+                continue;
+            }
+            ' ' | 'T' | 't' => IntervalToken::Space,
+            ':' => IntervalToken::Colon,
+            chr if chr == '.' || (chr == ',' && options.decimal_comma) => {
+                if last_field_is_frac {
+                    errors.push(DateTimeError::new(
+                        ErrorKind::UnexpectedChar,
+                        ParserError::ParserError(format!(
+                            "Invalid interval '{}': unexpected second decimal point at offset {}, \
+                             only one field may have a fraction",
+                            value, i
+                        )),
+                    ));
+                }
                 last_field_is_frac = true;
+                if num_start.is_none()
+                    && value[byte_idx + 1..].starts_with(|c: char| c.is_ascii_digit())
+                {
+                    toks.push(IntervalToken::Num(0));
+                    toks.push(IntervalToken::Dot);
+                    continue;
+                }
+                IntervalToken::Dot
+            }
+            chr if chr.is_ascii_digit() => {
+                if !in_bad_field {
+                    num_start.get_or_insert(byte_idx);
+                }
+                continue;
             }
-            chr if chr.is_digit(10) => num_buf.push(chr),
             chr => {
-                return Err(ParserError::TokenizerError(format!(
-                    "Invalid character at offset {} in {}: {:?}",
-                    i, value, chr
-                )))
+                if num_start.is_some() {
+                    take_num(&mut num_start, byte_idx, i, &mut toks, &mut errors);
+                }
+                in_bad_field = true;
+                errors.push(DateTimeError::new(
+                    ErrorKind::UnexpectedChar,
+                    ParserError::TokenizerError(format!(
+                        "Invalid character at offset {} in {}: {:?}",
+                        i, value, chr
+                    )),
+                ));
+                continue;
             }
+        };
+        if !in_bad_field || num_start.is_some() {
+            take_num(&mut num_start, byte_idx, i, &mut toks, &mut errors);
         }
+        in_bad_field = false;
+        toks.push(delimiter);
     }
-    if !num_buf.is_empty() {
-        if !last_field_is_frac {
-            toks.push(parse_num(&num_buf, 0)?);
+    if let Some(start) = num_start {
+        if last_field_is_frac {
+            let digits = &value[start..];
+            match fraction_to_nanos(digits) {
+                Ok(nanos) => toks.push(IntervalToken::Nanos(nanos, digits.len() as u8)),
+                Err(e) => errors.push(e),
+            }
         } else {
-            let raw: u32 = num_buf.parse().map_err(|e| {
-                ParserError::ParserError(format!(
-                    "couldn't parse fraction of second {}: {}",
-                    num_buf, e
-                ))
-            })?;
-            // this is guaranteed to be ascii, so len is fine
-            let chars = num_buf.len() as u32;
-            let multiplicand = 1_000_000_000 / 10_u32.pow(chars);
+            take_num(&mut num_start, value.len(), 0, &mut toks, &mut errors);
+        }
+    } else if matches!(
+        toks.last(),
+        Some(IntervalToken::Dash) | Some(IntervalToken::Space) | Some(IntervalToken::Colon)
+    ) {
+        errors.push(
+            DateTimeError::new(
+                ErrorKind::Incomplete,
+                ParserError::TokenizerError(format!(
+                    "Invalid interval '{}': it ends with {:?}, but a field should follow it",
+                    value,
+                    value.chars().last().unwrap_or_default()
+                )),
+            )
+            .with_expected_tokens(vec![IntervalToken::Num(0)]),
+        );
+    }
+    let errors = errors.into_iter().map(DateTimeError::tokenizing).collect();
+    (toks, errors)
+}
 
-            toks.push(IntervalToken::Nanos(raw * multiplicand));
+/// Tokenize a positional value lazily, one token at a time
+///
+/// This is what [`tokenize_interval`] collects, for the callers that consume
+/// the tokens as they go. The iterator stops after the first error.
+pub(crate) fn interval_tokens<'a>(
+    value: &'a str,
+    options: &DateTimeParseOptions,
+) -> IntervalTokens<'a> {
+    IntervalTokens {
+        value,
+        decimal_comma: options.decimal_comma,
+        chars: value.char_indices().enumerate(),
+        num: Some(0),
+        num_start: 0,
+        num_end: 0,
+        last_field_is_frac: false,
+        pending: None,
+        emitted: 0,
+        last: None,
+        done: false,
+    }
+}
+
+/// The iterator returned by [`interval_tokens`]
+pub(crate) struct IntervalTokens<'a> {
+    value: &'a str,
+    decimal_comma: bool,
+    chars: core::iter::Enumerate<core::str::CharIndices<'a>>,
+    // The number currently being read is accumulated digit by digit, and is
+    // only re-read from `value[num_start..num_end]` when it is a fraction or
+    // when it is empty or overflows, so that the errors come from `str::parse`
+    num: Option<u64>,
+    num_start: usize,
+    num_end: usize,
+    last_field_is_frac: bool,
+    /// The second of two tokens that a single character produced
+    pending: Option<IntervalToken>,
+    /// How many tokens have been produced, and the last of them
+    emitted: usize,
+    last: Option<IntervalToken>,
+    done: bool,
+}
+
+impl<'a> IntervalTokens<'a> {
+    fn take_num(&mut self, idx: usize) -> Result<IntervalToken, DateTimeError> {
+        let tok = match self.num {
+            Some(n) if self.num_start < self.num_end => IntervalToken::Num(n),
+            _ => IntervalToken::Num(parse_number(
+                &self.value[self.num_start..self.num_end],
+                idx,
+            )?),
+        };
+        self.num = Some(0);
+        Ok(tok)
+    }
+
+    /// The number before a `delimiter` if there is one, then the delimiter
+    fn after_num(
+        &mut self,
+        idx: usize,
+        delimiter: IntervalToken,
+    ) -> Result<IntervalToken, DateTimeError> {
+        if self.num_start < self.num_end {
+            self.pending = Some(delimiter);
+            self.take_num(idx)
+        } else {
+            Ok(delimiter)
         }
     }
-    Ok(toks)
+
+    /// Read up to the next token
+    fn step(&mut self) -> Result<Option<IntervalToken>, DateTimeError> {
+        let value = self.value;
+        while let Some((i, (byte_idx, chr))) = self.chars.next() {
+            let tok = match chr {
+                // A sign after a space would be a sign for just the fields after
+                // it, which a positional value can't have. Unit words can,
+                // `'1 day -2 hours'` is read by `tokenize_compound_interval`.
+                '-' | '+'
+                    if self.num_start == self.num_end
+                        && self.last == Some(IntervalToken::Space) =>
+                {
+                    return datetime_err!(
+                        UnexpectedChar,
+                        "Invalid interval '{}': the {:?} at offset {} follows a space, but only the \
+                         whole value may have a sign, before its leading field",
+                        value,
+                        chr,
+                        i
+                    );
+                }
+                // dashes at the beginning mean make it negative
+                '-' => self.after_num(i, IntervalToken::Dash)?,
+                '+' => self.after_num(i, IntervalToken::Plus)?,
+                // ISO 8601 style `T` separators between the date and the time
+                // are treated like spaces
+                ' ' | 'T' | 't' => {
+                    if self.num_start == self.num_end
+                        && self.emitted == 1
+                        && matches!(
+                            self.last,
+                            Some(IntervalToken::Dash) | Some(IntervalToken::Plus)
+                        )
+                    {
+                        return datetime_err!(
+                            UnexpectedChar,
+                            "Invalid interval '{}': the sign must immediately precede the leading \
+                             field",
+                            value
+                        );
+                    }
+                    self.pending = Some(IntervalToken::Space);
+                    self.take_num(i)?
+                }
+                ':' => {
+                    self.pending = Some(IntervalToken::Colon);
+                    self.take_num(i)?
+                }
+                chr if chr == '.' || (chr == ',' && self.decimal_comma) => {
+                    if self.last_field_is_frac {
+                        return datetime_err!(
+                            UnexpectedChar,
+                            "Invalid interval '{}': unexpected second decimal point at offset {}, \
+                             only one field may have a fraction",
+                            value,
+                            i
+                        );
+                    }
+                    self.last_field_is_frac = true;
+                    self.pending = Some(IntervalToken::Dot);
+                    // a fraction without a whole part, like `'.5'`, is read as
+                    // `'0.5'`, but a dot with no digits on either side is not
+                    if self.num_start == self.num_end
+                        && value[byte_idx + 1..].starts_with(|c: char| c.is_ascii_digit())
+                    {
+                        IntervalToken::Num(0)
+                    } else {
+                        self.take_num(i)?
+                    }
+                }
+                chr if chr.is_ascii_digit() => {
+                    if self.num_start == self.num_end {
+                        self.num_start = byte_idx;
+                    }
+                    self.num_end = byte_idx + 1;
+                    self.num = self
+                        .num
+                        .and_then(|n| n.checked_mul(10))
+                        .and_then(|n| n.checked_add(u64::from(chr as u8 - b'0')));
+                    continue;
+                }
+                chr => {
+                    return tokenizer_err!(
+                        UnexpectedChar,
+                        "Invalid character at offset {} in {}: {:?}",
+                        i,
+                        value,
+                        chr
+                    )
+                }
+            };
+            self.num_start = byte_idx + 1;
+            self.num_end = self.num_start;
+            return Ok(Some(tok));
+        }
+        self.done = true;
+        if self.num_start < self.num_end {
+            if !self.last_field_is_frac {
+                return self.take_num(0).map(Some);
+            }
+            let digits = &value[self.num_start..self.num_end];
+            return Ok(Some(IntervalToken::Nanos(
+                fraction_to_nanos(digits)?,
+                digits.len() as u8,
+            )));
+        }
+        // A value cut short while it is being typed, which more input could fix.
+        // A trailing dot is fine, `'1.'` is one second.
+        if matches!(
+            self.last,
+            Some(IntervalToken::Dash) | Some(IntervalToken::Space) | Some(IntervalToken::Colon)
+        ) {
+            return tokenizer_err!(
+                Incomplete,
+                "Invalid interval '{}': it ends with {:?}, but a field should follow it",
+                value,
+                value.chars().last().unwrap_or_default()
+            )
+            .map_err(|e: DateTimeError| e.with_expected_tokens(vec![IntervalToken::Num(0)]));
+        }
+        Ok(None)
+    }
+}
+
+impl<'a> Iterator for IntervalTokens<'a> {
+    type Item = Result<IntervalToken, DateTimeError>;
+
+    fn next(&mut self) -> Option<Result<IntervalToken, DateTimeError>> {
+        let tok = match self.pending.take() {
+            Some(tok) => tok,
+            None if self.done => return None,
+            None => match self.step() {
+                Ok(Some(tok)) => tok,
+                Ok(None) => return None,
+                Err(e) => {
+                    self.done = true;
+                    self.pending = None;
+                    return Some(Err(e.tokenizing()));
+                }
+            },
+        };
+        self.emitted += 1;
+        self.last = Some(tok.clone());
+        Some(Ok(tok))
+    }
 }
 
-fn tokenize_timezone(value: &str) -> Result<Vec<IntervalToken>, ParserError> {
+fn tokenize_timezone(value: &str) -> Result<Vec<IntervalToken>, DateTimeError> {
+    // Whitespace after the offset, as in `'+05:30 '`, is not part of it, but
+    // whitespace inside of it still is, and is rejected
+    let value = value.trim_end();
     let mut toks: Vec<IntervalToken> = vec![];
-    let mut num_buf = String::with_capacity(4);
+    // Where the digits of the number being read start. Everything before a
+    // letter or the end of the value is ASCII, so the offsets are also the
+    // character offsets that errors report.
+    let mut num_start: Option<usize> = None;
     // If the timezone string has a colon, we need to parse all numbers naively.
     // Otherwise we need to parse long sequences of digits as [..hhhhmm]
     let split_nums: bool = !value.contains(':');
@@ -82,7 +852,7 @@ fn tokenize_timezone(value: &str) -> Result<Vec<IntervalToken>, ParserError> {
         n: &str,
         split_nums: bool,
         idx: usize,
-    ) -> Result<(), ParserError> {
+    ) -> Result<(), DateTimeError> {
         if n.is_empty() {
             return Ok(());
         }
@@ -95,66 +865,77 @@ fn tokenize_timezone(value: &str) -> Result<Vec<IntervalToken>, ParserError> {
         };
 
         toks.push(IntervalToken::Num(first.parse().map_err(|e| {
-                ParserError::ParserError(format!(
+                DateTimeError::new(ErrorKind::Overflow, ParserError::ParserError(format!(
                     "Error tokenizing timezone string: unable to parse value {} as a number at index {}: {}",
                     first, idx, e
-                ))
+                )))
             })?));
 
         if let Some(second) = second {
             toks.push(IntervalToken::Num(second.parse().map_err(|e| {
-                ParserError::ParserError(format!(
+                DateTimeError::new(ErrorKind::Overflow, ParserError::ParserError(format!(
                     "Error tokenizing timezone string: unable to parse value {} as a number at index {}: {}",
                     second, idx, e
-                ))
+                )))
             })?));
         }
 
         Ok(())
     };
-    for (i, chr) in value.chars().enumerate() {
+    let take_num = |num_start: &mut Option<usize>, end: usize| -> &str {
+        match num_start.take() {
+            Some(start) => &value[start..end],
+            None => "",
+        }
+    };
+    for (i, chr) in value.char_indices() {
         match chr {
             '-' => {
-                parse_num(&mut toks, &num_buf, split_nums, i)?;
-                num_buf.clear();
+                parse_num(&mut toks, take_num(&mut num_start, i), split_nums, i)?;
                 toks.push(IntervalToken::Dash);
             }
             ' ' => {
-                parse_num(&mut toks, &num_buf, split_nums, i)?;
-                num_buf.clear();
+                parse_num(&mut toks, take_num(&mut num_start, i), split_nums, i)?;
                 toks.push(IntervalToken::Space);
             }
             ':' => {
-                parse_num(&mut toks, &num_buf, split_nums, i)?;
-                num_buf.clear();
+                parse_num(&mut toks, take_num(&mut num_start, i), split_nums, i)?;
                 toks.push(IntervalToken::Colon);
             }
             '+' => {
-                parse_num(&mut toks, &num_buf, split_nums, i)?;
-                num_buf.clear();
+                parse_num(&mut toks, take_num(&mut num_start, i), split_nums, i)?;
                 toks.push(IntervalToken::Plus);
             }
             chr if (chr == 'z' || chr == 'Z') && (i == value.len() - 1) => {
-                parse_num(&mut toks, &num_buf, split_nums, i)?;
-                num_buf.clear();
+                parse_num(&mut toks, take_num(&mut num_start, i), split_nums, i)?;
                 toks.push(IntervalToken::Zulu);
             }
-            chr if chr.is_digit(10) => num_buf.push(chr),
+            chr if chr.is_ascii_digit() => {
+                num_start.get_or_insert(i);
+            }
             chr if chr.is_ascii_alphabetic() => {
-                parse_num(&mut toks, &num_buf, split_nums, i)?;
+                parse_num(&mut toks, take_num(&mut num_start, i), split_nums, i)?;
                 let substring = &value[i..];
                 toks.push(IntervalToken::TzName(substring.to_string()));
                 return Ok(toks);
             }
             chr => {
-                return Err(ParserError::TokenizerError(format!(
+                return tokenizer_err!(
+                    UnexpectedChar,
                     "Error tokenizing timezone string ({}): invalid character {:?} at offset {}",
-                    value, chr, i
-                )))
+                    value,
+                    chr,
+                    i
+                )
             }
         }
     }
-    parse_num(&mut toks, &num_buf, split_nums, 0)?;
+    parse_num(
+        &mut toks,
+        take_num(&mut num_start, value.len()),
+        split_nums,
+        0,
+    )?;
     Ok(toks)
 }
 
@@ -164,11 +945,11 @@ fn tokenize_timezone(value: &str) -> Result<Vec<IntervalToken>, ParserError> {
 /// should interpret that as `9 months 5 days 4 hours 3 minutes`. This function
 /// doesn't take any perspective on what things should be, it just teslls you
 /// what the user might have meant.
-fn potential_interval_tokens(from: &DateTimeField) -> Vec<IntervalToken> {
+fn potential_interval_tokens(from: &DateTimeField) -> &'static [IntervalToken] {
     use DateTimeField::*;
     use IntervalToken::*;
 
-    let all_toks = [
+    static ALL_TOKS: [IntervalToken; 13] = [
         Num(0), // year
         Dash,
         Num(0), // month
@@ -181,37 +962,89 @@ fn potential_interval_tokens(from: &DateTimeField) -> Vec<IntervalToken> {
         Colon,
         Num(0), // second
         Dot,
-        Nanos(0), // Nanos
+        Nanos(0, 0), // Nanos
     ];
+    // A number of quarters or weeks, which may have a fraction
+    static SINGLE_NUMBER_TOKS: [IntervalToken; 3] = [Num(0), Dot, Nanos(0, 0)];
     let offset = match from {
         Year => 0,
+        Quarter | Week => return &SINGLE_NUMBER_TOKS,
         Month => 2,
         Day => 4,
         Hour => 6,
         Minute => 8,
         Second => 10,
     };
-    all_toks[offset..].to_vec()
+    &ALL_TOKS[offset..]
+}
+
+/// Cut the `expected` tokens of a `leading_field` value down to those up to
+/// and including the `last_field`
+fn truncate_to_last_field(
+    expected: &mut &[IntervalToken],
+    leading_field: &DateTimeField,
+    last_field: &DateTimeField,
+    value: &str,
+) -> Result<(), DateTimeError> {
+    use DateTimeField::*;
+
+    if last_field < leading_field {
+        return datetime_err!(
+            InvalidLayout,
+            "Invalid interval '{}': the last field, {}, is larger than the leading field, {}",
+            value,
+            last_field,
+            leading_field
+        );
+    }
+    let start = match leading_field {
+        Year => 0,
+        Quarter | Month => 2,
+        Week | Day => 4,
+        Hour => 6,
+        Minute => 8,
+        Second => 10,
+    };
+    let end = match last_field {
+        Year => 1,
+        Quarter | Month => 3,
+        Week | Day => 5,
+        Hour => 7,
+        Minute => 9,
+        Second => 13,
+    };
+    *expected = &expected[..end - start];
+    Ok(())
 }
 
-fn build_timezone_offset_second(tokens: &[IntervalToken], value: &str) -> Result<i64, ParserError> {
+fn build_timezone_offset_second(
+    tokens: &[IntervalToken],
+    value: &str,
+) -> Result<i64, DateTimeError> {
     use IntervalToken::*;
-    let all_formats = [
-        vec![Plus, Num(0), Colon, Num(0)],
-        vec![Dash, Num(0), Colon, Num(0)],
-        vec![Plus, Num(0), Num(0)],
-        vec![Dash, Num(0), Num(0)],
-        vec![Plus, Num(0)],
-        vec![Dash, Num(0)],
-        vec![TzName("".to_string())],
-        vec![Zulu],
+    const ALL_FORMATS: &[&[IntervalToken]] = &[
+        &[Plus, Num(0), Colon, Num(0)],
+        &[Dash, Num(0), Colon, Num(0)],
+        &[Plus, Num(0), Num(0)],
+        &[Dash, Num(0), Num(0)],
+        &[Plus, Num(0)],
+        &[Dash, Num(0)],
+        &[TzName(String::new())],
+        &[Zulu],
     ];
+    if let [Plus, Num(_), Colon] | [Dash, Num(_), Colon] = tokens {
+        return datetime_err!(
+            EmptyField,
+            "Error parsing timezone string ({}): missing minutes after the ':'",
+            value
+        );
+    }
 
     let mut is_positive = true;
     let mut hour_offset: Option<i64> = None;
     let mut minute_offset: Option<i64> = None;
 
-    for format in all_formats.iter() {
+    for &format in ALL_FORMATS {
         let actual = tokens.iter();
 
         if actual.len() != format.len() {
@@ -232,35 +1065,44 @@ fn build_timezone_offset_second(tokens: &[IntervalToken], value: &str) -> Result
                         } else {
                             // We can return an error here because in all the formats with numbers
                             // we require the first number to be an hour and we require it to be <= 24
-                            return Err(ParserError::ParserError(format!(
+                            return datetime_err!(
+                                OutOfRange,
                                 "Error parsing timezone string ({}): timezone hour invalid {}",
                                 value, val
-                            )));
+                            );
                         }
                         (Some(_), None) => if val <= 60 {
                             minute_offset = Some(val as i64);
                         } else {
-                            return Err(ParserError::ParserError(format!(
+                            return datetime_err!(
+                                OutOfRange,
                                 "Error parsing timezone string ({}): timezone minute invalid {}",
                                 value, val
-                            )));
+                            );
                         },
                         // We've already seen an hour and a minute so we should never see another number
-                        (Some(_), Some(_)) => return Err(ParserError::ParserError(format!(
+                        (Some(_), Some(_)) => return datetime_err!(
+                            InvalidLayout,
                             "Error parsing timezone string ({}): invalid value {} at token index {}", value,
                             val, i
-                        ))),
+                        ),
                         (None, Some(_)) => unreachable!("parsed a minute before an hour!"),
                     }
                 }
                 (Zulu, Zulu) => return Ok(0 as i64),
                 (TzName(val), TzName(_)) => {
-                    // For now, we don't support named timezones
-                    return Err(ParserError::ParserError(format!(
+                    if let Some(offset) = timezone_alias_offset(val) {
+                        return Ok(offset);
+                    }
+                    // For now, we don't support other named timezones
+                    return datetime_err!(
+                        Unsupported,
                         "Error parsing timezone string ({}): named timezones are not supported. \
                          Failed to parse {} at token index {}",
-                        value, val, i
-                    )));
+                        value,
+                        val,
+                        i
+                    );
                 }
                 (_, _) => {
                     // Theres a mismatch between this format and the actual token stream
@@ -288,11 +1130,61 @@ fn build_timezone_offset_second(tokens: &[IntervalToken], value: &str) -> Result
         }
     }
 
-    return Err(ParserError::ParserError(format!("It didnt work")));
+    datetime_err!(
+        InvalidLayout,
+        "Error parsing timezone string ({}): expected an offset like +HH:MM, +HHMM or +HH, or Z",
+        value
+    )
 }
 
+/// Timezone names that are a fixed offset, in seconds east of UTC
+///
+/// Other names, like the IANA `Europe/Paris`, depend on the date and are left
+/// to the caller.
+const TIMEZONE_ALIASES: &[(&str, i64)] =
+    &[("UTC", 0), ("GMT", 0), ("UT", 0), ("Z", 0), ("ZULU", 0)];
+
+/// The offset of a timezone name from [`TIMEZONE_ALIASES`], ignoring case
+fn timezone_alias_offset(name: &str) -> Option<i64> {
+    let name = name.trim();
+    TIMEZONE_ALIASES
+        .iter()
+        .find(|(alias, _)| alias.eq_ignore_ascii_case(name))
+        .map(|(_, offset)| *offset)
+}
+
+/// A piece of a date, time or interval string, see
+/// [`Parser::debug_tokenize_interval`]
+///
+/// The tokens are public so that tools like formatters and linters can be
+/// built on them outside of this crate:
+///
+/// ```
+/// # use sqlparser::parser::{IntervalToken, Parser};
+/// let toks = Parser::debug_tokenize_interval("1 2:03").unwrap();
+/// assert_eq!(
+///     toks,
+///     vec![
+///         IntervalToken::Num(1),
+///         IntervalToken::Space,
+///         IntervalToken::Num(2),
+///         IntervalToken::Colon,
+///         IntervalToken::Num(3),
+///     ]
+/// );
+/// let numbers: u64 = toks
+///     .iter()
+///     .map(|tok| match tok {
+///         IntervalToken::Num(n) => *n,
+///         _ => 0,
+///     })
+///     .sum();
+/// assert_eq!(numbers, 6);
+/// ```
+///
+/// [`Parser::debug_tokenize_interval`]: crate::parser::Parser::debug_tokenize_interval
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub(crate) enum IntervalToken {
+pub enum IntervalToken {
     Dash,
     Space,
     Colon,
@@ -300,19 +1192,115 @@ pub(crate) enum IntervalToken {
     Plus,
     Zulu,
     Num(u64),
-    Nanos(u32),
-    // String representation of a named timezone e.g. 'EST'
+    /// A fraction in billionths, and how many digits it was written with
+    Nanos(u32, u8),
+    /// String representation of a named timezone e.g. 'EST'
     TzName(String),
+    /// The unit that the preceding number is in, e.g. the 'D' in 'P1D'
+    Unit(DateTimeField),
+}
+
+impl IntervalToken {
+    /// How the token is written, for error messages
+    ///
+    /// The punctuation is the character itself, so that a message can say
+    /// `expected '-'` rather than `expected Dash`. Tokens with a value, like
+    /// numbers, are a placeholder for what they stand for.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            IntervalToken::Dash => "-",
+            IntervalToken::Space => " ",
+            IntervalToken::Colon => ":",
+            IntervalToken::Dot => ".",
+            IntervalToken::Plus => "+",
+            IntervalToken::Zulu => "Z",
+            IntervalToken::Num(_) => "<number>",
+            IntervalToken::Nanos(_, _) => "<fraction>",
+            IntervalToken::TzName(_) => "<timezone>",
+            IntervalToken::Unit(_) => "<unit>",
+        }
+    }
+}
+
+/// Each token is rendered the way it could have been written, except for a
+/// [`IntervalToken::Space`], which is `_` so that it stands out
+impl fmt::Display for IntervalToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IntervalToken::Dash => f.write_str("-"),
+            IntervalToken::Space => f.write_str("_"),
+            IntervalToken::Colon => f.write_str(":"),
+            IntervalToken::Dot => f.write_str("."),
+            IntervalToken::Plus => f.write_str("+"),
+            IntervalToken::Zulu => f.write_str("Z"),
+            IntervalToken::Num(num) => write!(f, "{}", num),
+            IntervalToken::Nanos(nanos, digits) if (1..=9).contains(digits) => write!(
+                f,
+                "{:0width$}",
+                nanos / 10_u32.pow(9 - u32::from(*digits)),
+                width = usize::from(*digits)
+            ),
+            IntervalToken::Nanos(nanos, _) => {
+                let fraction = format!("{:09}", nanos);
+                match fraction.trim_end_matches('0') {
+                    "" => f.write_str("0"),
+                    fraction => f.write_str(fraction),
+                }
+            }
+            IntervalToken::TzName(name) => f.write_str(name),
+            IntervalToken::Unit(field) => write!(f, "{}", field),
+        }
+    }
 }
 
+/// The most tokens that a valid positional value has: a sign and the 13
+/// tokens of a `YEAR` value
+const MAX_POSITIONAL_TOKENS: usize = 14;
+
+/// Build a positional value out of its `tokens`, as they are produced by
+/// [`interval_tokens`]
+///
+/// The tokens are gathered on the stack, so that only values with more
+/// tokens than a valid value can have allocate.
 pub(crate) fn build_parsed_datetime(
+    tokens: impl IntoIterator<Item = Result<IntervalToken, DateTimeError>>,
+    leading_field: &DateTimeField,
+    value: &str,
+    options: &DateTimeParseOptions,
+) -> Result<ParsedDateTime, DateTimeError> {
+    const UNUSED: IntervalToken = IntervalToken::Dash;
+    let mut buf = [UNUSED; MAX_POSITIONAL_TOKENS];
+    let mut len = 0;
+    let mut spilled = Vec::new();
+    for tok in tokens {
+        let tok = tok?;
+        if len < MAX_POSITIONAL_TOKENS {
+            buf[len] = tok;
+            len += 1;
+        } else {
+            if spilled.is_empty() {
+                spilled.extend(buf.iter().cloned());
+            }
+            spilled.push(tok);
+        }
+    }
+    let tokens = if spilled.is_empty() {
+        &buf[..len]
+    } else {
+        &spilled[..]
+    };
+    build_parsed_datetime_from_tokens(tokens, leading_field, value, options)
+}
+
+fn build_parsed_datetime_from_tokens(
     tokens: &[IntervalToken],
     leading_field: &DateTimeField,
     value: &str,
-) -> Result<ParsedDateTime, ParserError> {
+    options: &DateTimeParseOptions,
+) -> Result<ParsedDateTime, DateTimeError> {
     use IntervalToken::*;
 
-    let expected = potential_interval_tokens(&leading_field);
+    let mut expected = potential_interval_tokens(leading_field);
     let mut actual = tokens.iter().peekable();
 
     let is_positive = match actual.peek() {
@@ -320,15 +1308,206 @@ pub(crate) fn build_parsed_datetime(
             actual.next();
             false
         }
+        Some(val) if val == &&IntervalToken::Plus => {
+            actual.next();
+            true
+        }
         _ => true,
     };
-    let mut current_field = leading_field.clone();
-    let mut pdt = ParsedDateTime {
-        is_positive,
-        ..Default::default()
+    if let Some(Dash) | Some(Plus) = actual.peek() {
+        return datetime_err!(
+            UnexpectedChar,
+            "Invalid interval '{}': only a single sign is allowed, before the leading field",
+            value
+        );
+    }
+    if options.compact_time && leading_field == &DateTimeField::Hour {
+        if let Some(pdt) = compact_time(value, is_positive)? {
+            return Ok(pdt);
+        }
+    }
+    if options.strict_year_month {
+        match leading_field {
+            DateTimeField::Year => expected = &expected[..3],
+            DateTimeField::Month => expected = &expected[..1],
+            _ => {}
+        }
+        if actual.len() > expected.len() {
+            return datetime_err!(
+                InvalidLayout,
+                "Invalid interval '{}': a {} interval may not contain day or time fields",
+                value,
+                leading_field
+            );
+        }
+    }
+    if let Some(last_field) = options.last_field.as_ref() {
+        if leading_field != &DateTimeField::Quarter && leading_field != &DateTimeField::Week {
+            truncate_to_last_field(&mut expected, leading_field, last_field, value)?;
+            if actual.len() > expected.len() {
+                return datetime_err!(
+                    InvalidLayout,
+                    "Invalid interval '{}': a {} TO {} interval may not have fields after the {}",
+                    value,
+                    leading_field,
+                    last_field,
+                    last_field
+                );
+            }
+        }
+    }
+    let mut time_separator = None;
+    for tok in actual.clone() {
+        match tok {
+            Colon | Dot => time_separator = time_separator.or(Some(tok)),
+            // `'12:00+05:30'` is a time with a timezone, which only
+            // timestamps that are parsed with one can have
+            Plus if time_separator.is_some() => {
+                return datetime_err!(
+                    Unsupported,
+                    "Invalid interval '{}': a timezone offset is not permitted here, but the '+' \
+                     after the time starts one",
+                    value
+                );
+            }
+            Dash => {
+                if let Some(separator) = time_separator {
+                    return datetime_err!(
+                        InvalidLayout,
+                        "Invalid interval '{}': a '-' follows a '{}', but the date parts must come \
+                         before the time parts",
+                        value,
+                        separator
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+    // Only the last field may have a fraction, which is the seconds when
+    // there is more than one field. A lone number is left to the checks for
+    // the fields that may have fractions.
+    if let Some(dot) = actual.clone().position(|tok| tok == &Dot) {
+        let more_fields = actual
+            .clone()
+            .skip(dot + 1)
+            .any(|tok| !matches!(tok, Nanos(..)));
+        if expected.get(dot) != Some(&Dot) && (dot > 1 || more_fields) {
+            return datetime_err!(
+                InvalidLayout,
+                "Invalid interval '{}': a fractional part is only allowed on seconds, the last \
+                 field of a {} interval",
+                value,
+                leading_field
+            );
+        }
+    }
+    let count_colons = |toks: &[IntervalToken]| {
+        toks.iter()
+            .filter(|tok| **tok == IntervalToken::Colon)
+            .count()
     };
+    if options.strict_year_to_month
+        && leading_field == &DateTimeField::Year
+        && !actual.clone().any(|tok| tok == &Dash)
+    {
+        return datetime_err!(
+            InvalidLayout,
+            "Invalid interval '{}': a YEAR TO MONTH value must be written as <years>-<months>",
+            value
+        );
+    }
+    let allowed_colons = count_colons(expected);
+    let provided_colons = count_colons(tokens);
+    if provided_colons > allowed_colons {
+        return datetime_err!(
+            InvalidLayout,
+            "Invalid interval '{}': it has {} colon-separated time fields but {} intervals \
+             allow at most {}{}",
+            value,
+            provided_colons + 1,
+            leading_field,
+            allowed_colons + 1,
+            clock_time_hint(value, leading_field)
+        );
+    }
+    let mut current_field = leading_field.clone();
+    let mut pdt = ParsedDateTime::with_sign(is_positive);
+    if let (Some(field_seconds), [Num(whole), Dot, Nanos(fraction, _)]) = (
+        fraction_field_seconds(leading_field),
+        &tokens[tokens.len() - actual.len()..],
+    ) {
+        match leading_field {
+            DateTimeField::Day => pdt.day = Some(*whole),
+            DateTimeField::Hour => pdt.hour = Some(*whole),
+            _ => pdt.minute = Some(*whole),
+        }
+        spread_fraction(&mut pdt, leading_field, *fraction, field_seconds);
+        return Ok(pdt);
+    }
+    if leading_field == &DateTimeField::Quarter {
+        let (quarters, fraction) = match &tokens[tokens.len() - actual.len()..] {
+            [Num(quarters)] => (*quarters, 0),
+            [Num(quarters), Dot, Nanos(fraction, _)] => (*quarters, *fraction),
+            _ => {
+                return datetime_err!(
+                    InvalidLayout,
+                    "Invalid interval '{}': a QUARTER value must be a single number",
+                    value
+                )
+            }
+        };
+        // Like postgres, a fraction of a month is turned into 30-day days
+        let months = u64::from(fraction) * 3;
+        let month_fraction = months % 1_000_000_000;
+        let days = month_fraction * 30;
+        let day_fraction = (days % 1_000_000_000) as u32;
+        pdt.month = quarters
+            .checked_mul(3)
+            .and_then(|m| m.checked_add(months / 1_000_000_000));
+        if pdt.month.is_none() {
+            return datetime_err!(Overflow, "Invalid interval '{}': too many quarters", value);
+        }
+        if month_fraction != 0 {
+            pdt.day = Some(days / 1_000_000_000);
+        }
+        if day_fraction != 0 {
+            spread_fraction(&mut pdt, &DateTimeField::Day, day_fraction, 24 * 60 * 60);
+        }
+        return Ok(pdt);
+    }
+    if leading_field == &DateTimeField::Week {
+        let (weeks, fraction) = match &tokens[tokens.len() - actual.len()..] {
+            [Num(weeks)] => (*weeks, 0),
+            [Num(weeks), Dot, Nanos(fraction, _)] => (*weeks, *fraction),
+            _ => {
+                return datetime_err!(
+                    InvalidLayout,
+                    "Invalid interval '{}': a WEEK value must be a single number",
+                    value
+                )
+            }
+        };
+        // A fraction of a week is whole days and then a fraction of a day
+        let days = u64::from(fraction) * 7;
+        let day_fraction = (days % 1_000_000_000) as u32;
+        pdt.day = weeks
+            .checked_mul(7)
+            .and_then(|d| d.checked_add(days / 1_000_000_000));
+        if pdt.day.is_none() {
+            return datetime_err!(Overflow, "Invalid interval '{}': too many weeks", value);
+        }
+        if day_fraction != 0 {
+            spread_fraction(&mut pdt, &DateTimeField::Day, day_fraction, 24 * 60 * 60);
+        }
+        return Ok(pdt);
+    }
     let mut seconds_seen = 0;
-    for (i, (atok, etok)) in actual.zip(&expected).enumerate() {
+    let sign_tokens = tokens.len() - actual.len();
+    let trailing = tokens
+        .get(sign_tokens + expected.len()..)
+        .unwrap_or_default();
+    for (i, (atok, etok)) in actual.zip(expected).enumerate() {
         match (atok, etok) {
             (Dash, Dash) | (Space, Space) | (Colon, Colon) | (Dot, Dot) => {
                 /* matching punctuation */
@@ -337,15 +1516,19 @@ pub(crate) fn build_parsed_datetime(
                 let val = *val;
                 match current_field {
                     DateTimeField::Year => pdt.year = Some(val),
+                    DateTimeField::Quarter => unreachable!("quarters are handled above"),
+                    DateTimeField::Week => unreachable!("weeks are handled above"),
                     DateTimeField::Month => {
                         if val < 1 {
-                            return parser_err!("Invalid Month {} in {}", val, value);
+                            return Err(field_range_error(&current_field, val, value, ">= 1"));
                         }
                         pdt.month = Some(val)
                     }
                     DateTimeField::Day => {
-                        if val < 1 {
-                            return parser_err!("Invalid Day {} in {}", val, value);
+                        // A leading day is a count of days, as in Oracle's
+                        // `+00 01:00:00`, rather than a day of the month
+                        if val < 1 && leading_field != &DateTimeField::Day {
+                            return Err(field_range_error(&current_field, val, value, ">= 1"));
                         }
                         pdt.day = Some(val)
                     }
@@ -356,288 +1539,5497 @@ pub(crate) fn build_parsed_datetime(
                         pdt.second = Some(val);
                     }
                     DateTimeField::Second => {
-                        return parser_err!("Too many numbers to parse as a second at {}", val)
+                        return datetime_err!(
+                            InvalidLayout,
+                            "Too many numbers to parse as a second at {}",
+                            val
+                        )
                     }
                 }
                 if current_field != DateTimeField::Second {
-                    current_field = current_field
-                        .into_iter()
-                        .next()
-                        .expect("Exhausted day iterator");
+                    current_field = match current_field.next_smaller_field() {
+                        Some(field) => field,
+                        None => {
+                            return datetime_err!(
+                                InvalidLayout,
+                                "Invalid interval '{}': too many fields after {}",
+                                value,
+                                current_field
+                            )
+                        }
+                    };
                 }
             }
-            (Nanos(val), Nanos(_)) if seconds_seen == 1 => pdt.nano = Some(*val),
-            (provided, expected) => {
-                return parser_err!(
-                    "Invalid interval part at offset {}: '{}' provided {:?} but expected {:?}",
+            (Nanos(val, digits), Nanos(_, _)) if seconds_seen == 1 => {
+                pdt.nano = Some(*val);
+                pdt.nano_digits = Some(*digits);
+            }
+            (provided, expected_tok) => {
+                return datetime_err!(
+                    InvalidLayout,
+                    "Invalid interval part at offset {}: '{}' provided '{}' but expected '{}'; \
+                     {} intervals are laid out as {}\n{}{}",
                     i,
                     value,
-                    provided,
-                    expected,
+                    provided.symbol(),
+                    expected_tok.symbol(),
+                    leading_field,
+                    layout_msg(expected),
+                    token_span(value, sign_tokens + i)
+                        .map(|span| context_snippet(value, span))
+                        .unwrap_or_default(),
+                    clock_time_hint(value, leading_field),
                 )
+                .map_err(|e: DateTimeError| e.with_expected_tokens(vec![expected_tok.clone()]))
             }
         }
     }
+    // the zip stops at the end of the layout, so anything after it is unread
+    if !trailing.is_empty() {
+        return datetime_err!(
+            InvalidLayout,
+            "Invalid interval '{}': '{}' is left over after the end of the {} layout, {}",
+            value,
+            trailing
+                .iter()
+                .map(IntervalToken::symbol)
+                .collect::<String>(),
+            leading_field,
+            layout_msg(expected)
+        );
+    }
 
     Ok(pdt)
 }
 
-/// Takes a 'date timezone' 'date time timezone' string and splits
-/// it into 'date {time}' and 'timezone' components
-pub(crate) fn split_timestamp_string(value: &str) -> (&str, &str) {
-    // First we need to see if the string contains " +" or " -" because
-    // timestamps can come in a format YYYY-MM-DD {+|-}<tz> (where the
-    // timezone string can have colons)
-    let cut = value.find(" +").or_else(|| value.find(" -"));
+/// Build a value that is a single integer, like `'30'` or `'-5'`, without
+/// tokenizing it
+///
+/// Returns `None` for every other value, and for the integers that
+/// [`build_parsed_datetime`] treats specially or rejects, which are left to
+/// it. Otherwise the result is the same as that of `build_parsed_datetime`.
+pub(crate) fn build_integer_interval(
+    value: &str,
+    leading_field: &DateTimeField,
+    options: &DateTimeParseOptions,
+) -> Option<ParsedDateTime> {
+    // the last field is checked against the leading field by the general path
+    if options.last_field.is_some() {
+        return None;
+    }
+    let (is_positive, digits) = match value.as_bytes().first()? {
+        b'-' => (false, &value[1..]),
+        b'+' => (true, &value[1..]),
+        _ => (true, value),
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let num: u64 = digits.parse().ok()?;
+    let mut pdt = ParsedDateTime::with_sign(is_positive);
+    match leading_field {
+        DateTimeField::Year if !options.strict_year_to_month => pdt.year = Some(num),
+        DateTimeField::Month if num >= 1 => pdt.month = Some(num),
+        DateTimeField::Day => pdt.day = Some(num),
+        DateTimeField::Hour if !options.compact_time => pdt.hour = Some(num),
+        DateTimeField::Minute => pdt.minute = Some(num),
+        DateTimeField::Second => pdt.second = Some(num),
+        _ => return None,
+    }
+    Some(pdt)
+}
 
-    if let Some(cut) = cut {
-        let (first, second) = value.split_at(cut);
-        return (first.trim(), second.trim());
+/// Split a delimiter-free `HHMM` or `HHMMSS` value, ignoring its sign
+///
+/// The minutes and seconds must be below 60, since nothing separates them
+/// from the field in front of them.
+fn compact_time(value: &str, is_positive: bool) -> Result<Option<ParsedDateTime>, DateTimeError> {
+    let digits = value.trim();
+    let digits = digits.strip_prefix(&['-', '+'][..]).unwrap_or(digits);
+    if !(digits.len() == 4 || digits.len() == 6) || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Ok(None);
     }
+    let pair = |i: usize| {
+        digits
+            .get(i..i + 2)
+            .and_then(|pair| pair.parse::<u64>().ok())
+    };
+    for (field, units) in &[
+        (DateTimeField::Minute, pair(2)),
+        (DateTimeField::Second, pair(4)),
+    ] {
+        if let Some(units) = units.filter(|units| *units > 59) {
+            return Err(field_range_error(field, units, value, "between 0 and 59"));
+        }
+    }
+    Ok(Some(ParsedDateTime {
+        hour: pair(0),
+        minute: pair(2),
+        second: pair(4),
+        ..ParsedDateTime::with_sign(is_positive)
+    }))
+}
 
-    // If we have a hh:mm:dd component, we need to go past that to see if we can find a tz
-    let colon = value.find(':');
+/// The error for a `field` whose `val` in `value` is not `valid_range`
+fn field_range_error(
+    field: &DateTimeField,
+    val: u64,
+    value: &str,
+    valid_range: &str,
+) -> DateTimeError {
+    let name = format!("{:?}", field);
+    DateTimeError::new(
+        ErrorKind::OutOfRange,
+        ParserError::ParserError(format!(
+            "Invalid {} {} in '{}': {} must be {}",
+            name,
+            val,
+            value,
+            name.to_lowercase(),
+            valid_range
+        )),
+    )
+}
+
+/// Distribute a fraction of `field`, which is `field_seconds` long, into the
+/// finer fields of `pdt`
+///
+/// The `fraction` is in billionths, like an [`IntervalToken::Nanos`].
+/// The seconds in one `field`, for the fields whose fraction is spread over
+/// the finer ones: days, hours and minutes
+fn fraction_field_seconds(field: &DateTimeField) -> Option<u64> {
+    match field {
+        DateTimeField::Day => Some(24 * 60 * 60),
+        DateTimeField::Hour => Some(60 * 60),
+        DateTimeField::Minute => Some(60),
+        _ => None,
+    }
+}
 
-    if let Some(colon) = colon {
-        let substring = value.get(colon..);
-        if let Some(substring) = substring {
-            let tz = substring
-                .find(|c: char| (c == '-') || (c == '+') || (c == ' ') || c.is_ascii_alphabetic());
+fn spread_fraction(
+    pdt: &mut ParsedDateTime,
+    field: &DateTimeField,
+    fraction: u32,
+    field_seconds: u64,
+) {
+    let mut nanos = u128::from(fraction) * u128::from(field_seconds);
+    for finer in field.clone() {
+        let (slot, seconds) = match finer {
+            DateTimeField::Hour => (&mut pdt.hour, 60 * 60),
+            DateTimeField::Minute => (&mut pdt.minute, 60),
+            DateTimeField::Second => (&mut pdt.second, 1),
+            _ => continue,
+        };
+        let unit = seconds * 1_000_000_000;
+        *slot = Some((nanos / unit) as u64);
+        nanos %= unit;
+    }
+    if nanos != 0 {
+        pdt.nano = Some(nanos as u32);
+    }
+}
 
-            if let Some(tz) = tz {
-                let (first, second) = value.split_at(colon + tz);
-                return (first.trim(), second.trim());
+/// The byte range of the token at `index` in a positional `value`, which has
+/// a token for every run of digits and for every other character
+fn token_span(value: &str, index: usize) -> Option<Range<usize>> {
+    let mut chars = value.char_indices().peekable();
+    let mut current = 0;
+    while let Some((start, chr)) = chars.next() {
+        let mut end = start + chr.len_utf8();
+        if chr.is_ascii_digit() {
+            while let Some((i, c)) = chars.peek() {
+                if !c.is_ascii_digit() {
+                    break;
+                }
+                end = i + 1;
+                chars.next();
             }
         }
-
-        return (value.trim(), "");
-    } else {
-        // We don't have a time, so the only formats available are
-        // YYY-mm-dd<tz> or YYYY-MM-dd <tz>
-        // Numeric offset timezones need to be separated from the ymd by a space
-        let cut = value.find(|c: char| (c == ' ') || c.is_ascii_alphabetic());
-
-        if let Some(cut) = cut {
-            let (first, second) = value.split_at(cut);
-            return (first.trim(), second.trim());
+        if current == index {
+            return Some(start..end);
         }
-
-        return (value.trim(), "");
+        current += 1;
     }
+    None
 }
 
-pub(crate) fn parse_timezone_offset_second(value: &str) -> Result<i64, ParserError> {
-    let toks = tokenize_timezone(value)?;
-    Ok(build_timezone_offset_second(&toks, value)?)
+/// A few characters of `value` on either side of `span`, and a line of
+/// carets under the `span`
+fn context_snippet(value: &str, span: Range<usize>) -> String {
+    const CONTEXT: usize = 10;
+    let mut start = span.start.saturating_sub(CONTEXT);
+    while !value.is_char_boundary(start) {
+        start -= 1;
+    }
+    let mut end = (span.end + CONTEXT).min(value.len());
+    while !value.is_char_boundary(end) {
+        end += 1;
+    }
+    let prefix = if start > 0 { "..." } else { "" };
+    let suffix = if end < value.len() { "..." } else { "" };
+    let padding = prefix.len() + value[start..span.start].chars().count();
+    let width = value[span].chars().count().max(1);
+    format!(
+        "{}{}{}\n{}{}",
+        prefix,
+        &value[start..end],
+        suffix,
+        " ".repeat(padding),
+        "^".repeat(width)
+    )
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use crate::parser::*;
-
-    #[test]
-    fn test_potential_interval_tokens() {
-        use DateTimeField::*;
-        use IntervalToken::*;
-        assert_eq!(
-            potential_interval_tokens(&Year),
-            vec![
-                Num(0),
-                Dash,
-                Num(0),
-                Dash,
-                Num(0),
-                Space,
-                Num(0),
-                Colon,
-                Num(0),
-                Colon,
-                Num(0),
-                Dot,
-                Nanos(0),
-            ]
-        );
+/// Render a token template like `Num Dash Num` for error messages
+/// A hint for a value that looks like a clock time, as in `'1:2:3' MONTH`,
+/// under a date `leading_field`, which is likely meant to be a time one
+///
+/// The value looks like a time if it has colons and no dashes or spaces.
+/// This is empty when there is nothing to suggest.
+fn clock_time_hint(value: &str, leading_field: &DateTimeField) -> String {
+    use DateTimeField::*;
 
-        assert_eq!(
-            potential_interval_tokens(&Day),
-            vec![
-                Num(0),
-                Space,
-                Num(0),
-                Colon,
-                Num(0),
-                Colon,
-                Num(0),
-                Dot,
-                Nanos(0),
-            ]
-        );
+    let unsigned = value.strip_prefix(&['-', '+'][..]).unwrap_or(value);
+    let looks_like_time = unsigned.contains(':')
+        && unsigned
+            .chars()
+            .all(|chr| chr.is_ascii_digit() || chr == ':' || chr == '.');
+    if !looks_like_time || !matches!(leading_field, Year | Quarter | Month | Week | Day) {
+        return String::new();
     }
+    let suggestion = match unsigned.matches(':').count() {
+        1 => "HOUR TO MINUTE or MINUTE TO SECOND",
+        _ => "HOUR TO SECOND",
+    };
+    format!(
+        "\nhint: '{}' looks like a time, but {} is a date field; a time needs a time leading \
+         field, like {}",
+        value, leading_field, suggestion
+    )
+}
 
-    #[test]
-    fn test_split_timestamp_string() {
-        let test_cases = [
-            (
-                "1969-06-01 10:10:10.410 UTC",
-                "1969-06-01 10:10:10.410",
-                "UTC",
-            ),
-            (
-                "1969-06-01 10:10:10.410+4:00",
-                "1969-06-01 10:10:10.410",
-                "+4:00",
-            ),
-            (
-                "1969-06-01 10:10:10.410-4:00",
-                "1969-06-01 10:10:10.410",
-                "-4:00",
-            ),
-            ("1969-06-01 10:10:10.410", "1969-06-01 10:10:10.410", ""),
-            ("1969-06-01 10:10:10.410+4", "1969-06-01 10:10:10.410", "+4"),
-            ("1969-06-01 10:10:10.410-4", "1969-06-01 10:10:10.410", "-4"),
-            ("1969-06-01 10:10:10+4:00", "1969-06-01 10:10:10", "+4:00"),
-            ("1969-06-01 10:10:10-4:00", "1969-06-01 10:10:10", "-4:00"),
-            ("1969-06-01 10:10:10 UTC", "1969-06-01 10:10:10", "UTC"),
-            ("1969-06-01 10:10:10", "1969-06-01 10:10:10", ""),
-            ("1969-06-01 10:10+4:00", "1969-06-01 10:10", "+4:00"),
-            ("1969-06-01 10:10-4:00", "1969-06-01 10:10", "-4:00"),
-            ("1969-06-01 10:10 UTC", "1969-06-01 10:10", "UTC"),
-            ("1969-06-01 10:10", "1969-06-01 10:10", ""),
-            ("1969-06-01 UTC", "1969-06-01", "UTC"),
-            ("1969-06-01 +4:00", "1969-06-01", "+4:00"),
-            ("1969-06-01 -4:00", "1969-06-01", "-4:00"),
-            ("1969-06-01 +4", "1969-06-01", "+4"),
-            ("1969-06-01 -4", "1969-06-01", "-4"),
-            ("1969-06-01", "1969-06-01", ""),
-            ("1969-06-01 10:10:10.410Z", "1969-06-01 10:10:10.410", "Z"),
-            ("1969-06-01 10:10:10.410z", "1969-06-01 10:10:10.410", "z"),
-            ("1969-06-01Z", "1969-06-01", "Z"),
-            ("1969-06-01z", "1969-06-01", "z"),
-            ("1969-06-01 10:10:10.410   ", "1969-06-01 10:10:10.410", ""),
-            (
-                "1969-06-01     10:10:10.410   ",
-                "1969-06-01     10:10:10.410",
-                "",
-            ),
-            ("   1969-06-01 10:10:10.412", "1969-06-01 10:10:10.412", ""),
-            (
-                "   1969-06-01 10:10:10.413   ",
-                "1969-06-01 10:10:10.413",
-                "",
-            ),
-            (
-                "1969-06-01 10:10:10.410 +4:00",
-                "1969-06-01 10:10:10.410",
-                "+4:00",
-            ),
-            (
-                "1969-06-01 10:10:10.410+4 :00",
-                "1969-06-01 10:10:10.410",
-                "+4 :00",
-            ),
-            (
-                "1969-06-01 10:10:10.410      +4:00",
-                "1969-06-01 10:10:10.410",
-                "+4:00",
-            ),
-            (
-                "1969-06-01 10:10:10.410+4:00     ",
-                "1969-06-01 10:10:10.410",
-                "+4:00",
-            ),
-            (
-                "1969-06-01 10:10:10.410  Z  ",
-                "1969-06-01 10:10:10.410",
-                "Z",
-            ),
-            ("1969-06-01    +4  ", "1969-06-01", "+4"),
-            ("1969-06-01   Z   ", "1969-06-01", "Z"),
-        ];
+fn layout_msg(tokens: &[IntervalToken]) -> String {
+    tokens
+        .iter()
+        .map(|tok| match tok {
+            IntervalToken::Num(_) => "Num".to_string(),
+            IntervalToken::Nanos(_, _) => "Nanos".to_string(),
+            other => format!("{:?}", other),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
 
-        for test in test_cases.iter() {
-            let (ts, tz) = split_timestamp_string(test.0);
+/// Whether `value` looks like an ISO 8601 duration, e.g. `P1Y2M3DT4H5M6S`
+pub(crate) fn is_iso8601_duration(value: &str) -> bool {
+    value.starts_with('P') || value.starts_with("-P")
+}
 
-            assert_eq!(ts, test.1);
-            assert_eq!(tz, test.2);
+/// Tokenize an ISO 8601 duration in the "format with designators"
+///
+/// The designators are turned into [`IntervalToken::Unit`]s, so `M` becomes
+/// a month before the `T` and a minute after it. The `P` and `T` markers
+/// themselves don't produce any tokens.
+pub(crate) fn tokenize_iso8601_duration(value: &str) -> Result<Vec<IntervalToken>, DateTimeError> {
+    iso8601_duration_tokens(value).map_err(DateTimeError::tokenizing)
+}
+
+fn iso8601_duration_tokens(value: &str) -> Result<Vec<IntervalToken>, DateTimeError> {
+    let mut toks = vec![];
+    let mut chars = value.char_indices().peekable();
+    if let Some((_, '-')) = chars.peek() {
+        chars.next();
+        toks.push(IntervalToken::Dash);
+    }
+    match chars.next() {
+        Some((_, 'P')) => {}
+        _ => {
+            return tokenizer_err!(
+                InvalidLayout,
+                "ISO 8601 duration '{}' must start with 'P'",
+                value
+            )
         }
     }
 
-    #[test]
-    fn test_parse_timezone_offset_second() {
-        let test_cases = [
-            ("+0:00", 0),
-            ("-0:00", 0),
-            ("+0:000000", 0),
-            ("+000000:00", 0),
-            ("+000000:000000", 0),
-            ("+0", 0),
-            ("+00", 0),
-            ("+000", 0),
-            ("+0000", 0),
-            ("+00000000", 0),
-            ("+0000001:000000", 3600),
-            ("+0000000:000001", 60),
-            ("+0000001:000001", 3660),
-            ("+4:00", 14400),
-            ("-4:00", -14400),
-            ("+2:30", 9000),
-            ("-5:15", -18900),
-            ("+0:20", 1200),
-            ("-0:20", -1200),
-            ("+5", 18000),
-            ("-5", -18000),
-            ("+05", 18000),
-            ("-05", -18000),
-            ("+500", 18000),
-            ("-500", -18000),
-            ("+530", 19800),
-            ("-530", -19800),
-            ("+050", 3000),
-            ("-050", -3000),
-            ("+15", 54000),
-            ("-15", -54000),
-            ("+1515", 54900),
-            ("+015", 900),
-            ("-015", -900),
-            ("+0015", 900),
-            ("-0015", -900),
-            ("+00015", 900),
-            ("-00015", -900),
-            ("+005", 300),
-            ("-005", -300),
-            ("+0000005", 300),
-            ("+00000100", 3600),
-            ("Z", 0),
-            ("z", 0),
-        ];
-
-        for test in test_cases.iter() {
-            match parse_timezone_offset_second(test.0) {
-                Ok(tz_offset) => {
-                    let expected: i64 = test.1 as i64;
-
-                    println!("{} {}", expected, tz_offset);
-                    assert_eq!(tz_offset, expected);
+    let mut num_buf = String::with_capacity(4);
+    let mut frac_buf: Option<String> = None;
+    let mut in_time = false;
+    let mut time_components = 0;
+    for (i, chr) in chars {
+        match chr {
+            chr if chr.is_ascii_digit() => match &mut frac_buf {
+                Some(frac) => frac.push(chr),
+                None => num_buf.push(chr),
+            },
+            '.' | ',' if frac_buf.is_none() && !num_buf.is_empty() => {
+                frac_buf = Some(String::with_capacity(9));
+            }
+            'T' if !in_time && num_buf.is_empty() => in_time = true,
+            'Y' | 'M' | 'D' | 'H' | 'S' => {
+                use DateTimeField::*;
+                let field = match (chr, in_time) {
+                    ('Y', false) => Year,
+                    ('M', false) => Month,
+                    ('D', false) => Day,
+                    ('H', true) => Hour,
+                    ('M', true) => Minute,
+                    ('S', true) => Second,
+                    (_, _) => {
+                        return tokenizer_err!(
+                            UnexpectedChar,
+                            "Invalid designator {:?} at offset {} in ISO 8601 duration '{}'",
+                            chr,
+                            i,
+                            value
+                        )
+                    }
+                };
+                if num_buf.is_empty() {
+                    return tokenizer_err!(
+                        EmptyField,
+                        "Missing number before designator {:?} at offset {} in ISO 8601 duration '{}'",
+                        chr, i, value
+                    );
+                }
+                toks.push(IntervalToken::Num(parse_number(&num_buf, i)?));
+                num_buf.clear();
+                if let Some(frac) = frac_buf.take() {
+                    toks.push(IntervalToken::Dot);
+                    toks.push(IntervalToken::Nanos(
+                        fraction_to_nanos(&frac)?,
+                        frac.len() as u8,
+                    ));
+                }
+                toks.push(IntervalToken::Unit(field));
+                if in_time {
+                    time_components += 1;
                 }
-                Err(e) => panic!(
-                    "Test failed when expected to pass test case: {} error: {}",
-                    test.0, e
-                ),
+            }
+            chr => {
+                return tokenizer_err!(
+                    UnexpectedChar,
+                    "Invalid character at offset {} in {}: {:?}",
+                    i,
+                    value,
+                    chr
+                )
             }
         }
+    }
+    if !num_buf.is_empty() {
+        return tokenizer_err!(
+            InvalidLayout,
+            "Missing designator after the final number in ISO 8601 duration '{}'",
+            value
+        );
+    }
+    if in_time && time_components == 0 {
+        return tokenizer_err!(
+            EmptyField,
+            "ISO 8601 duration '{}' has a 'T' but no time components",
+            value
+        );
+    }
+    Ok(toks)
+}
 
-        let failure_test_cases = [
-            "+25:00", "+120:00", "+0:61", "+0:500", " 12:30", "+-12:30", "+2525", "+2561",
-            "+255900", "+25", "+5::30", "+5:30:", "+5:30:16", "+5:", "++5:00", "--5:00", "UTC",
-            " UTC", "a", "zzz", "ZZZ", "ZZ Top", " +", " -", " ", "1", "12", "1234",
+/// Whether `value` is made of unit words, like `1 day 2 hours`
+///
+/// A lone `T` is not a unit word, it separates the date from the time.
+pub(crate) fn is_compound_interval(value: &str) -> bool {
+    value
+        .chars()
+        .any(|chr| chr.is_ascii_alphabetic() && chr != 'T' && chr != 't')
+}
+
+/// Whether `value` is a compact duration like `'5h30m'`, see
+/// [`DateTimeParseOptions::unit_suffixes`]
+pub(crate) fn is_unit_suffix_interval(value: &str) -> bool {
+    let unsigned = value.strip_prefix(&['-', '+'][..]).unwrap_or(value);
+    unsigned.starts_with(|chr: char| chr.is_ascii_digit() || chr == '.')
+        && unsigned.contains(char::is_alphabetic)
+        && unsigned
+            .chars()
+            .all(|chr| chr.is_ascii_digit() || chr == '.' || chr.is_alphabetic())
+}
+
+/// Parse a compact duration like `'5h30m'`, see
+/// [`DateTimeParseOptions::unit_suffixes`]
+pub(crate) fn parse_unit_suffix_interval(value: &str) -> Result<ParsedDateTime, DateTimeError> {
+    let (is_positive, mut rest) = match value.strip_prefix('-') {
+        Some(rest) => (false, rest),
+        None => (true, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let is_number = |chr: char| chr.is_ascii_digit() || chr == '.';
+    let mut pdt = ParsedDateTime::positive();
+    while !rest.is_empty() {
+        let offset = value.len() - rest.len();
+        let number_end = rest.find(|chr| !is_number(chr)).unwrap_or(rest.len());
+        let unit_end = rest[number_end..]
+            .find(is_number)
+            .map_or(rest.len(), |end| number_end + end);
+        let (number, unit) = (&rest[..number_end], &rest[number_end..unit_end]);
+        rest = &rest[unit_end..];
+        if unit.is_empty() {
+            return tokenizer_err!(
+                Incomplete,
+                "Invalid interval '{}': the number at offset {} needs a unit after it, like h, m \
+                 or s",
+                value,
+                offset
+            );
+        }
+        let (whole, fraction) = match number.split_once('.') {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (number, None),
+        };
+        let whole = match whole {
+            "" if fraction.is_some() => 0,
+            whole => parse_number(whole, offset).map_err(DateTimeError::tokenizing)?,
+        };
+        let fraction = fraction
+            .map(fraction_to_nanos)
+            .transpose()
+            .map_err(DateTimeError::tokenizing)?
+            .unwrap_or(0);
+        let mut part = ParsedDateTime::positive();
+        let (field, field_slot, unit_nanos) = match unit {
+            "d" => (DateTimeField::Day, Some(&mut part.day), 0),
+            "h" => (DateTimeField::Hour, Some(&mut part.hour), 0),
+            "m" => (DateTimeField::Minute, Some(&mut part.minute), 0),
+            "s" => (DateTimeField::Second, None, 1_000_000_000),
+            "ms" => (DateTimeField::Second, None, 1_000_000),
+            "us" | "µs" => (DateTimeField::Second, None, 1_000),
+            "ns" => (DateTimeField::Second, None, 1),
+            unit => {
+                return tokenizer_err!(
+                    UnknownUnit,
+                    "Invalid interval '{}': unknown unit '{}' at offset {}, expected d, h, m, s, \
+                     ms, us or ns",
+                    value,
+                    unit,
+                    offset + number.len()
+                )
+            }
+        };
+        match field_slot {
+            Some(slot) => {
+                *slot = Some(whole);
+                if fraction != 0 {
+                    let field_seconds = fraction_field_seconds(&field).unwrap_or(1);
+                    spread_fraction(&mut part, &field, fraction, field_seconds);
+                }
+            }
+            None => {
+                let nanos = u128::from(whole) * unit_nanos
+                    + u128::from(fraction) * unit_nanos / 1_000_000_000;
+                let second = match u64::try_from(nanos / 1_000_000_000) {
+                    Ok(second) => second,
+                    Err(_) => {
+                        return datetime_err!(
+                            Overflow,
+                            "Invalid interval '{}': too many seconds",
+                            value
+                        )
+                    }
+                };
+                part.second = Some(second);
+                part.nano = Some((nanos % 1_000_000_000) as u32).filter(|nano| *nano != 0);
+            }
+        }
+        pdt = (pdt + part).map_err(|_| {
+            DateTimeError::new(
+                ErrorKind::Overflow,
+                ParserError::ParserError(format!(
+                    "Invalid interval '{}': the parts add up to more than an interval can hold",
+                    value
+                )),
+            )
+        })?;
+    }
+    pdt.is_positive = is_positive;
+    Ok(pdt)
+}
+
+/// See [`Parser::infer_interval_leading_field`]
+///
+/// [`Parser::infer_interval_leading_field`]: crate::parser::Parser::infer_interval_leading_field
+pub(crate) fn infer_leading_field(value: &str) -> DateTimeField {
+    let value = value.trim();
+    if is_iso8601_duration(value) || is_compound_interval(value) {
+        return DateTimeField::Second;
+    }
+    let unsigned = value.strip_prefix(&['-', '+'][..]).unwrap_or(value);
+    let first_end = unsigned
+        .find(|chr: char| !chr.is_ascii_digit())
+        .unwrap_or(unsigned.len());
+    let rest = &unsigned[first_end..];
+    if first_end > 0 && rest.starts_with('-') {
+        DateTimeField::Year
+    } else if first_end > 0 && rest.starts_with(' ') && rest.contains(':') {
+        DateTimeField::Day
+    } else if value.contains(':') {
+        DateTimeField::Hour
+    } else {
+        DateTimeField::Second
+    }
+}
+
+/// Tokenize a PostgreSQL style `<number> <unit> [<number> <unit> ...]` value
+///
+/// Each unit word becomes an [`IntervalToken::Unit`], preceded by a
+/// `Num(1)` if it was not preceded by a number. The special values like
+/// `now` that PostgreSQL accepts for timestamps are rejected. The word `and`
+/// may join two parts, as in `'1 hour and 30 minutes'`, and is skipped like a
+/// comma.
+pub(crate) fn tokenize_compound_interval(
+    value: &str,
+    options: &DateTimeParseOptions,
+) -> Result<Vec<IntervalToken>, DateTimeError> {
+    compound_interval_tokens(value, options).map_err(DateTimeError::tokenizing)
+}
+
+fn compound_interval_tokens(
+    value: &str,
+    options: &DateTimeParseOptions,
+) -> Result<Vec<IntervalToken>, DateTimeError> {
+    let is_decimal_point = |chr: char| chr == '.' || (chr == ',' && options.decimal_comma);
+    let mut toks = vec![];
+    // the offset of the last `and`, and how many tokens came before it
+    let mut last_and = None;
+    let mut chars = value.char_indices().peekable();
+    while let Some((i, chr)) = chars.next() {
+        match chr {
+            ' ' => {}
+            ',' if !options.decimal_comma => {}
+            '-' => toks.push(IntervalToken::Dash),
+            '+' => toks.push(IntervalToken::Plus),
+            chr if chr.is_ascii_digit() || is_decimal_point(chr) => {
+                let mut end = i + 1;
+                while let Some((j, c)) = chars.peek() {
+                    if !c.is_ascii_digit() && !is_decimal_point(*c) {
+                        break;
+                    }
+                    end = j + 1;
+                    chars.next();
+                }
+                let number = &value[i..end];
+                if options.decimal_comma
+                    && number.ends_with(',')
+                    && matches!(chars.peek(), Some((_, c)) if *c == ' ' || c.is_ascii_alphabetic())
+                {
+                    return tokenizer_err!(
+                        UnexpectedChar,
+                        "Invalid interval '{}': the ',' at offset {} can't separate the parts, \
+                         the comma is the decimal separator in this mode",
+                        value,
+                        end - 1
+                    );
+                }
+                let (whole, fraction) = match number.find(is_decimal_point) {
+                    Some(dot) => (&number[..dot], Some(&number[dot + 1..])),
+                    None => (number, None),
+                };
+                toks.push(IntervalToken::Num(if whole.is_empty() {
+                    0
+                } else {
+                    parse_number(whole, i)?
+                }));
+                if let Some(fraction) = fraction {
+                    toks.push(IntervalToken::Dot);
+                    toks.push(IntervalToken::Nanos(
+                        fraction_to_nanos(fraction)?,
+                        fraction.len() as u8,
+                    ));
+                }
+            }
+            chr if chr.is_ascii_alphabetic() => {
+                let mut end = i + 1;
+                while let Some((j, c)) = chars.peek() {
+                    if !c.is_ascii_alphabetic() {
+                        break;
+                    }
+                    end = j + 1;
+                    chars.next();
+                }
+                let word = value[i..end].to_ascii_lowercase();
+                if word == "and" {
+                    if last_and.map(|(_, len)| len) == Some(toks.len())
+                        || !matches!(toks.last(), Some(IntervalToken::Unit(_)))
+                    {
+                        return and_err(value, i);
+                    }
+                    last_and = Some((i, toks.len()));
+                    continue;
+                }
+                let field = match unit_from_word(&word) {
+                    Some(field) => field,
+                    None => match DateTimeSpecial::from_keyword(&word) {
+                        Some(special) => {
+                            return datetime_err!(
+                                Unsupported,
+                                "Invalid interval '{}': the special value '{}' is not supported",
+                                value,
+                                special
+                            )
+                        }
+                        None => {
+                            return datetime_err!(
+                                UnknownUnit,
+                                "Invalid interval '{}': unknown unit '{}' at offset {}, {}",
+                                value,
+                                word,
+                                i,
+                                unit_suggestion(&word)
+                            )
+                        }
+                    },
+                };
+                match toks.last() {
+                    Some(IntervalToken::Num(_)) | Some(IntervalToken::Nanos(_, _)) => {}
+                    _ => toks.push(IntervalToken::Num(1)),
+                }
+                toks.push(IntervalToken::Unit(field));
+            }
+            chr => {
+                return tokenizer_err!(
+                    UnexpectedChar,
+                    "Invalid character at offset {} in {}: {:?}",
+                    i,
+                    value,
+                    chr
+                )
+            }
+        }
+    }
+    match last_and {
+        Some((i, len)) if len == toks.len() => and_err(value, i),
+        _ => Ok(toks),
+    }
+}
+
+fn and_err<T>(value: &str, offset: usize) -> Result<T, DateTimeError> {
+    datetime_err!(
+        InvalidLayout,
+        "Invalid interval '{}': the 'and' at offset {} must join two parts",
+        value,
+        offset
+    )
+}
+
+/// The field named by a lowercase unit word like `day`, `hours` or `mins`
+///
+/// Quarters and weeks are units too, which [`build_parsed_datetime_from_units`]
+/// adds to the months and days, since there are no fields for them.
+fn unit_from_word(word: &str) -> Option<DateTimeField> {
+    DateTimeField::from_keyword(word)
+}
+
+/// What to tell someone who wrote the unknown unit `word`
+///
+/// The closest spelling is suggested if it is only a typo or two away,
+/// otherwise the accepted units are listed.
+fn unit_suggestion(word: &str) -> String {
+    let closest = DATE_TIME_FIELD_KEYWORDS
+        .iter()
+        .map(|(spelling, _)| (edit_distance(word, spelling), *spelling))
+        .min();
+    match closest {
+        Some((distance, spelling)) if distance <= 2 && distance < word.len() => {
+            format!("did you mean '{}'?", spelling)
+        }
+        _ => "expected one of year, quarter, month, week, day, hour, minute or second, or \
+              their plurals or abbreviations"
+            .to_string(),
+    }
+}
+
+/// The Levenshtein distance between two ASCII words
+fn edit_distance(a: &str, b: &str) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.bytes().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.bytes().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Parse the `digits` of a number in an interval, which start at `idx`
+fn parse_number(digits: &str, idx: usize) -> Result<u64, DateTimeError> {
+    digits
+        .parse()
+        .map_err(|e: core::num::ParseIntError| match e.kind() {
+            core::num::IntErrorKind::PosOverflow => DateTimeError::new(
+                ErrorKind::Overflow,
+                ParserError::ParserError(format!(
+                    "interval field out of range: {} at index {} is more than {}",
+                    digits,
+                    idx,
+                    u64::MAX
+                )),
+            ),
+            _ => DateTimeError::new(
+                ErrorKind::EmptyField,
+                ParserError::ParserError(format!(
+                    "Unable to parse value as a number at index {}: {}",
+                    idx, e
+                )),
+            ),
+        })
+}
+
+/// Check `value` against the `max_length` and `max_digits` of the `options`
+pub(crate) fn check_input_limits(
+    value: &str,
+    options: &DateTimeParseOptions,
+) -> Result<(), DateTimeError> {
+    if value.len() > options.max_length {
+        return datetime_err!(
+            TooLong,
+            "Invalid interval: the value is {} bytes long, but at most {} are allowed",
+            value.len(),
+            options.max_length
+        );
+    }
+    let mut digits = 0;
+    for (i, byte) in value.bytes().enumerate() {
+        if !byte.is_ascii_digit() {
+            digits = 0;
+            continue;
+        }
+        digits += 1;
+        if digits > options.max_digits {
+            return datetime_err!(
+                TooLong,
+                "Invalid interval: the number at offset {} has more than {} digits",
+                i + 1 - digits,
+                options.max_digits
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Check that `pdt` fits in the [`Interval`] it can be turned into
+///
+/// The years and months together must fit in an `i64` number of months, and
+/// the days, hours, minutes and seconds together in a `u64` number of
+/// seconds.
+///
+/// [`Interval`]: crate::ast::Interval
+pub(crate) fn check_interval_range(pdt: &ParsedDateTime, value: &str) -> Result<(), DateTimeError> {
+    let months = pdt
+        .year
+        .unwrap_or(0)
+        .checked_mul(12)
+        .and_then(|months| months.checked_add(pdt.month.unwrap_or(0)))
+        .filter(|months| *months <= i64::MAX as u64);
+    if months.is_none() {
+        return datetime_err!(
+            Overflow,
+            "Invalid interval '{}': interval field out of range, the years and months add up \
+             to more than {} months",
+            value,
+            i64::MAX
+        );
+    }
+    let fields = [
+        (pdt.day, 24 * 60 * 60),
+        (pdt.hour, 60 * 60),
+        (pdt.minute, 60),
+        (pdt.second, 1),
+    ];
+    let seconds = fields.iter().try_fold(0_u64, |total, (units, seconds)| {
+        units
+            .unwrap_or(0)
+            .checked_mul(*seconds)
+            .and_then(|units| total.checked_add(units))
+    });
+    if seconds.is_none() {
+        return datetime_err!(
+            Overflow,
+            "Invalid interval '{}': interval field out of range, the days, hours, minutes and \
+             seconds add up to more than {} seconds",
+            value,
+            u64::MAX
+        );
+    }
+    Ok(())
+}
+
+/// Strip the whitespace and a matched pair of quotes from around `value`,
+/// for [`DateTimeParseOptions::strip_quotes`]
+pub(crate) fn strip_quotes(value: &str) -> &str {
+    let value = value.trim_matches(|chr: char| chr.is_ascii_whitespace());
+    for quote in &['\'', '"'] {
+        if let Some(inner) = value
+            .strip_prefix(*quote)
+            .and_then(|rest| rest.strip_suffix(*quote))
+        {
+            return inner;
+        }
+    }
+    value
+}
+
+/// Drop the digits of each fraction in `value` past the ninth, or reject
+/// them under [`DateTimeParseOptions::strict_precision`]
+///
+/// The fraction is rounded by [`DateTimeParseOptions::fraction_rounding`],
+/// carrying into the digits before the point if it has to. This is `None`
+/// when no fraction is that long, so that the value can be used as it is.
+pub(crate) fn truncate_fraction_digits(
+    value: &str,
+    options: &DateTimeParseOptions,
+) -> Result<Option<String>, DateTimeError> {
+    const MAX_FRACTION_DIGITS: usize = 9;
+    let bytes = value.as_bytes();
+    // each part of a compound value has its own sign
+    let own_sign = is_compound_interval(value) && !is_iso8601_duration(value);
+    let mut truncated: Option<String> = None;
+    // how much of `value` has been copied into `truncated`
+    let mut copied = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let is_dot = bytes[i] == b'.' || (bytes[i] == b',' && options.decimal_comma);
+        i += 1;
+        if !is_dot {
+            continue;
+        }
+        let start = i;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        if i - start <= MAX_FRACTION_DIGITS {
+            continue;
+        }
+        if options.strict_precision {
+            return datetime_err!(
+                OutOfRange,
+                "Invalid interval '{}': the fraction {} has {} digits, but at most {} are kept, \
+                 to the nanosecond",
+                value,
+                &value[start..i],
+                i - start,
+                MAX_FRACTION_DIGITS
+            )
+            .map_err(DateTimeError::tokenizing);
+        }
+        let mut kept = value[copied..start + MAX_FRACTION_DIGITS].to_string();
+        let dropped = &value[start + MAX_FRACTION_DIGITS..i];
+        let rounding = options
+            .fraction_rounding
+            .unwrap_or(FractionRounding::Truncate);
+        let is_positive = !sign_before(value, start - 1, own_sign).contains('-');
+        // only the first dropped digit, and whether any are not zero, matter
+        let first = u64::from(dropped.as_bytes()[0] - b'0');
+        let rest = u64::from(dropped.bytes().skip(1).any(|b| b != b'0'));
+        if rounding.rounds_up(first * 2 + rest, 10, is_positive) {
+            increment_digits(&mut kept, start - 1 - copied);
+        }
+        truncated
+            .get_or_insert_with(|| String::with_capacity(value.len()))
+            .push_str(&kept);
+        copied = i;
+    }
+    Ok(truncated.map(|mut out| {
+        out.push_str(&value[copied..]);
+        out
+    }))
+}
+
+/// The sign that applies to the number ending at `end` in `value`, or `""`
+///
+/// That is the closest `+` or `-` before it that is at the start of the
+/// value or after a space or a letter, so the dashes of `'1-2'` are not
+/// signs, and the sign of `'-1 2:03:04.5'` covers the seconds. With
+/// `own_sign`, as for the parts of `'-1 day 2.5 seconds'`, it is only a sign
+/// right in front of the number, or apart from it by spaces.
+fn sign_before(value: &str, end: usize, own_sign: bool) -> &str {
+    let bytes = value.as_bytes();
+    if own_sign {
+        let mut i = end;
+        while i > 0 && bytes[i - 1].is_ascii_digit() {
+            i -= 1;
+        }
+        while i > 0 && bytes[i - 1] == b' ' {
+            i -= 1;
+        }
+        return match i.checked_sub(1) {
+            Some(sign) if bytes[sign] == b'-' || bytes[sign] == b'+' => &value[sign..i],
+            _ => "",
+        };
+    }
+    (0..end)
+        .rev()
+        .find(|&i| {
+            (bytes[i] == b'-' || bytes[i] == b'+')
+                && (i == 0 || bytes[i - 1] == b' ' || bytes[i - 1].is_ascii_alphabetic())
+        })
+        .map_or("", |i| &value[i..=i])
+}
+
+/// Add one to the last digit of `digits`, carrying past the decimal point at
+/// `point` and into the digits before it, and adding a `1` in front if
+/// they are all `9`s
+fn increment_digits(digits: &mut String, point: usize) {
+    let mut bytes = core::mem::take(digits).into_bytes();
+    let mut i = bytes.len();
+    loop {
+        if i == point + 1 {
+            i = point;
+        }
+        if i == 0 || !bytes[i - 1].is_ascii_digit() {
+            bytes.insert(i, b'1');
+            break;
+        }
+        i -= 1;
+        if bytes[i] == b'9' {
+            bytes[i] = b'0';
+        } else {
+            bytes[i] += 1;
+            break;
+        }
+    }
+    *digits = String::from_utf8(bytes).expect("only ASCII digits were changed");
+}
+
+/// Remove the `separator` from between digits in `value`, for
+/// [`DateTimeParseOptions::group_separator`]
+pub(crate) fn strip_group_separators(
+    value: &str,
+    separator: char,
+) -> Result<String, DateTimeError> {
+    let mut stripped = String::with_capacity(value.len());
+    // the digits since the start of the number or its last separator, and
+    // whether the number has had a separator yet
+    let mut group = 0;
+    let mut grouped = false;
+    for (i, chr) in value.char_indices() {
+        if chr.is_ascii_digit() {
+            group += 1;
+            stripped.push(chr);
+            continue;
+        }
+        if chr == separator && group > 0 {
+            let next = value[i + 1..]
+                .bytes()
+                .take_while(|b| b.is_ascii_digit())
+                .count();
+            if next > 0 {
+                if next != 3 || group > 3 || (grouped && group != 3) {
+                    return datetime_err!(
+                        UnexpectedChar,
+                        "Invalid interval '{}': the group separator {:?} at offset {} must be \
+                         between groups of three digits",
+                        value,
+                        separator,
+                        i
+                    );
+                }
+                group = 0;
+                grouped = true;
+                continue;
+            }
+        }
+        group = 0;
+        grouped = false;
+        stripped.push(chr);
+    }
+    Ok(stripped)
+}
+
+/// Reject a value whose leading field has more digits than the `precision`
+/// of a qualifier like `DAY(p)` allows
+pub(crate) fn check_leading_precision(
+    pdt: &ParsedDateTime,
+    leading_field: &DateTimeField,
+    precision: u64,
+    value: &str,
+) -> Result<(), DateTimeError> {
+    if precision == 0 {
+        return datetime_err!(
+            OutOfRange,
+            "Invalid interval '{}': a leading precision of 0 is not allowed, it must be at least 1",
+            value
+        );
+    }
+    let units = match leading_field {
+        DateTimeField::Year => pdt.year,
+        DateTimeField::Quarter => pdt.month.map(|months| months / 3),
+        DateTimeField::Month => pdt.month,
+        DateTimeField::Week => pdt.day.map(|days| days / 7),
+        DateTimeField::Day => pdt.day,
+        DateTimeField::Hour => pdt.hour,
+        DateTimeField::Minute => pdt.minute,
+        DateTimeField::Second => pdt.second,
+    };
+    let digits = match units {
+        Some(units) => units.to_string().len() as u64,
+        None => return Ok(()),
+    };
+    if digits > precision {
+        return datetime_err!(
+            OutOfRange,
+            "Invalid interval '{}': the {} has {} digits, but the leading precision allows at \
+             most {}",
+            value,
+            leading_field,
+            digits,
+            precision
+        );
+    }
+    Ok(())
+}
+
+/// Reject the hours, minutes or seconds of a value that are past what a
+/// clock shows, for [`DateTimeParseOptions::clock_fields`]
+pub(crate) fn check_clock_fields(
+    pdt: &ParsedDateTime,
+    value: &str,
+    options: &DateTimeParseOptions,
+) -> Result<(), DateTimeError> {
+    let max_second = if options.leap_second { 60 } else { 59 };
+    let end_of_day = options.end_of_day
+        && pdt.hour == Some(24)
+        && pdt.minute.unwrap_or(0) == 0
+        && pdt.second.unwrap_or(0) == 0
+        && pdt.nano.unwrap_or(0) == 0;
+    let max_hour = if end_of_day { 24 } else { 23 };
+    let fields = [
+        (DateTimeField::Hour, pdt.hour, max_hour),
+        (DateTimeField::Minute, pdt.minute, 59),
+        (DateTimeField::Second, pdt.second, max_second),
+    ];
+    for (field, units, max) in &fields {
+        if let Some(units) = units.filter(|units| units > max) {
+            return datetime_err!(
+                OutOfRange,
+                "Invalid interval '{}': {} {} is out of range for a time of day, it must be at \
+                 most {}",
+                value,
+                field,
+                units,
+                max
+            );
+        }
+    }
+    Ok(())
+}
+
+/// The things about a parsed interval that are allowed but look like
+/// mistakes, see [`Parser::parse_interval_with_warnings`]
+///
+/// [`Parser::parse_interval_with_warnings`]: crate::parser::Parser::parse_interval_with_warnings
+pub(crate) fn interval_warnings(pdt: &ParsedDateTime, value: &str) -> Vec<String> {
+    let mut warnings = vec![];
+    let normalized = pdt.normalize();
+    if &normalized != pdt {
+        warnings.push(format!(
+            "Interval '{}' is not normalized: it has 60 or more minutes or seconds, and is the \
+             same as '{}'",
+            value,
+            normalized.to_style(IntervalStyle::Postgres)
+        ));
+    }
+    let fields = [
+        (DateTimeField::Year, pdt.year),
+        (DateTimeField::Month, pdt.month),
+        (DateTimeField::Day, pdt.day),
+        (DateTimeField::Hour, pdt.hour),
+        (DateTimeField::Minute, pdt.minute),
+        (DateTimeField::Second, pdt.second),
+    ];
+    if pdt.is_zero() {
+        warnings.push(format!("Interval '{}' is zero", value));
+    } else if let Some((field, Some(0))) = fields.iter().find(|(_, units)| units.is_some()) {
+        warnings.push(format!(
+            "Interval '{}' starts with a {} of 0, which adds nothing",
+            value, field
+        ));
+    }
+    warnings
+}
+
+/// Reject a value that is longer than the `bound`, for
+/// [`DateTimeParseOptions::max_magnitude`]
+pub(crate) fn check_max_magnitude(
+    pdt: &ParsedDateTime,
+    bound: &ParsedDateTime,
+    value: &str,
+) -> Result<(), DateTimeError> {
+    // `f64::abs` is not in `core` on older compilers
+    let magnitude = |pdt: &ParsedDateTime| {
+        let seconds = pdt.approx_total_seconds();
+        if seconds < 0.0 {
+            -seconds
+        } else {
+            seconds
+        }
+    };
+    if magnitude(pdt) > magnitude(bound) {
+        return datetime_err!(
+            OutOfRange,
+            "Invalid interval '{}': it is longer than the most that is allowed, {}",
+            value,
+            bound.to_style(IntervalStyle::Postgres)
+        );
+    }
+    Ok(())
+}
+
+/// Reject the forms of value that the `grammar` doesn't have
+pub(crate) fn check_interval_grammar(
+    value: &str,
+    grammar: IntervalGrammar,
+) -> Result<(), DateTimeError> {
+    match grammar {
+        IntervalGrammar::Permissive | IntervalGrammar::Postgres => Ok(()),
+        IntervalGrammar::MySql if is_iso8601_duration(value.trim_start()) => datetime_err!(
+            Unsupported,
+            "Invalid interval '{}': MySQL intervals can't be ISO 8601 durations",
+            value
+        ),
+        IntervalGrammar::MySql if is_compound_interval(value) => datetime_err!(
+            Unsupported,
+            "Invalid interval '{}': MySQL intervals can't have unit words, the unit goes after \
+             the value, as in INTERVAL '1' DAY",
+            value
+        ),
+        IntervalGrammar::MySql => Ok(()),
+    }
+}
+
+/// Reject a unit word or ISO 8601 designator that is outside of the range
+/// of the qualifier, see [`crate::parser::Parser::parse_interval_string`]
+pub(crate) fn check_units_in_qualifier(
+    tokens: &[IntervalToken],
+    leading_field: &DateTimeField,
+    options: &DateTimeParseOptions,
+    value: &str,
+) -> Result<(), DateTimeError> {
+    use DateTimeField::*;
+    let year_month = |field: &DateTimeField| *field <= Month;
+    let last = match &options.last_field {
+        Some(last) => last.clone(),
+        None if year_month(leading_field) => Month,
+        None => Second,
+    };
+    for token in tokens {
+        if let IntervalToken::Unit(unit) = token {
+            // quarters and weeks are counted in months and days
+            let stored = match unit {
+                Quarter => &Month,
+                Week => &Day,
+                unit => unit,
+            };
+            if stored < leading_field || *stored > last {
+                let qualifier = if *leading_field == last {
+                    leading_field.to_string()
+                } else {
+                    format!("{} TO {}", leading_field, last)
+                };
+                return datetime_err!(
+                    InvalidLayout,
+                    "Invalid interval '{}': the unit in the value, {}, is not within {}, \
+                     the range of the qualifier",
+                    value,
+                    unit,
+                    qualifier
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Store the qualifier on `pdt`, for [`DateTimeParseOptions::record_qualifier`]
+pub(crate) fn record_qualifier(
+    pdt: &mut ParsedDateTime,
+    leading_field: &DateTimeField,
+    options: &DateTimeParseOptions,
+) {
+    if options.record_qualifier {
+        pdt.leading_field = Some(leading_field.clone());
+        pdt.trailing_field = options.last_field.clone();
+    }
+}
+
+/// Convert the digits after a decimal point into nanoseconds
+///
+/// There must be between 1 and 9 digits, the most that nanoseconds keep.
+fn fraction_to_nanos(digits: &str) -> Result<u32, DateTimeError> {
+    if digits.is_empty() || digits.len() > 9 {
+        return datetime_err!(
+            OutOfRange,
+            "fraction of second must have between 1 and 9 digits, got '{}'",
+            digits
+        );
+    }
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return datetime_err!(
+            UnexpectedChar,
+            "couldn't parse fraction of second {}: it may only contain digits",
+            digits
+        );
+    }
+    let raw: u32 = digits.parse().map_err(|e| {
+        DateTimeError::new(
+            ErrorKind::UnexpectedChar,
+            ParserError::ParserError(format!(
+                "couldn't parse fraction of second {}: {}",
+                digits, e
+            )),
+        )
+    })?;
+    match raw.checked_mul(10_u32.pow(9 - digits.len() as u32)) {
+        Some(nanos) => Ok(nanos),
+        None => datetime_err!(
+            Overflow,
+            "fraction of second {} is too large to be nanoseconds",
+            digits
+        ),
+    }
+}
+
+/// Split a trailing `(<precision>)` off of `value`
+///
+/// Returns the value without the marker and the precision, if there was one.
+pub(crate) fn split_precision_marker(value: &str) -> Result<(&str, Option<u64>), DateTimeError> {
+    let trimmed = value.trim_end();
+    if !trimmed.ends_with(')') {
+        return Ok((value, None));
+    }
+    let open = match trimmed.rfind('(') {
+        Some(open) => open,
+        None => {
+            return datetime_err!(
+                UnexpectedChar,
+                "Unmatched ')' in interval precision marker: '{}'",
+                value
+            )
+        }
+    };
+    let digits = &trimmed[open + 1..trimmed.len() - 1];
+    let precision = digits.trim().parse().map_err(|e| {
+        DateTimeError::new(
+            ErrorKind::UnexpectedChar,
+            ParserError::ParserError(format!(
+                "Invalid interval precision marker '({})' in '{}': {}",
+                digits, value, e
+            )),
+        )
+    })?;
+    Ok((trimmed[..open].trim_end(), Some(precision)))
+}
+
+/// Whether a clock time is before or after noon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Meridiem {
+    Am,
+    Pm,
+}
+
+/// Split a trailing `AM` or `PM`, in any case, off of a positional `value`
+///
+/// The suffix may or may not be separated from the time by a space, as in
+/// `4:05 PM` and `4:05pm`. Returns the value without it and the meridiem, if
+/// there was one.
+pub(crate) fn split_meridiem(value: &str) -> (&str, Option<Meridiem>) {
+    let trimmed = value.trim_end();
+    let (rest, suffix) = match trimmed.len().checked_sub(2) {
+        Some(split) if trimmed.is_char_boundary(split) => trimmed.split_at(split),
+        _ => return (value, None),
+    };
+    let meridiem = if suffix.eq_ignore_ascii_case("am") {
+        Meridiem::Am
+    } else if suffix.eq_ignore_ascii_case("pm") {
+        Meridiem::Pm
+    } else {
+        return (value, None);
+    };
+    let rest = rest.trim_end();
+    if !rest.ends_with(|c: char| c.is_ascii_digit()) || is_compound_interval(rest) {
+        return (value, None);
+    }
+    (rest, Some(meridiem))
+}
+
+/// Turn the 12-hour clock hour of `pdt` into a 24-hour one
+pub(crate) fn apply_meridiem(
+    pdt: &mut ParsedDateTime,
+    meridiem: Meridiem,
+    value: &str,
+) -> Result<(), DateTimeError> {
+    let hour = match pdt.hour {
+        Some(hour) if (1..=12).contains(&hour) => hour,
+        Some(hour) => {
+            return datetime_err!(
+                OutOfRange,
+                "Invalid interval '{}': the hour must be between 1 and 12 with AM or PM, got {}",
+                value,
+                hour
+            )
+        }
+        None => {
+            return datetime_err!(
+                InvalidLayout,
+                "Invalid interval '{}': AM or PM needs an hour",
+                value
+            )
+        }
+    };
+    pdt.hour = Some(match (meridiem, hour) {
+        (Meridiem::Am, 12) => 0,
+        (Meridiem::Am, hour) => hour,
+        (Meridiem::Pm, 12) => 12,
+        (Meridiem::Pm, hour) => hour + 12,
+    });
+    Ok(())
+}
+
+/// Round `pdt`'s nanoseconds to `precision` decimal digits, as
+/// [`DateTimeParseOptions::fraction_rounding`] says to
+pub(crate) fn round_fractional_seconds(
+    pdt: &mut ParsedDateTime,
+    precision: u64,
+    options: &DateTimeParseOptions,
+) -> Result<(), DateTimeError> {
+    if precision > 9 {
+        return datetime_err!(
+            OutOfRange,
+            "fractional seconds precision must be between 0 and 9, got {}",
+            precision
+        );
+    }
+    let nano = match pdt.nano {
+        Some(nano) => nano,
+        None => return Ok(()),
+    };
+    let unit = 10_u32.pow(9 - precision as u32);
+    let rounding = options.fraction_rounding.unwrap_or(FractionRounding::Round);
+    let dropped = nano % unit;
+    let rounded =
+        match rounding.rounds_up(u64::from(dropped), u64::from(unit / 2), pdt.signs().time) {
+            true => nano - dropped + unit,
+            false => nano - dropped,
+        };
+    if rounded >= 1_000_000_000 {
+        pdt.second = match pdt.second.unwrap_or(0).checked_add(1) {
+            Some(second) => Some(second),
+            None => return datetime_err!(Overflow, "interval seconds out of range after rounding"),
+        };
+        pdt.nano = Some(rounded - 1_000_000_000);
+    } else {
+        pdt.nano = Some(rounded);
+    }
+    pdt.nano_digits = pdt.nano_digits.map(|digits| digits.min(precision as u8));
+    Ok(())
+}
+
+/// Build a `ParsedDateTime` out of a stream of `<number> <unit>` tokens
+///
+/// Every unit may only appear once, and a fraction is only allowed on days,
+/// hours, minutes and seconds. The fraction of a day, an hour or a minute is
+/// spread over the smaller units and added to them, so `'1.5 minutes'` is a
+/// minute and 30 seconds, and `'1.25 hours 10 minutes'` is an hour and 25
+/// minutes. A sign only applies to the part it is on, like in PostgreSQL, so
+/// `'-1 day 2 hours'` is a day back and two hours forward. The signed parts
+/// are added up like [`ParsedDateTime`]s are, so `'1 hour -30 minutes'` is
+/// 30 minutes, and the months, the days and the time keep their own signs,
+/// so `'2 days -3 hours'` is two days forward and three hours back, see
+/// [`ParsedDateTime::part_signs`].
+pub(crate) fn build_parsed_datetime_from_units(
+    tokens: &[IntervalToken],
+    value: &str,
+) -> Result<ParsedDateTime, DateTimeError> {
+    use IntervalToken::*;
+
+    if tokens
+        .windows(2)
+        .any(|pair| matches!(pair, [Dash | Plus, Dash | Plus]))
+    {
+        return datetime_err!(
+            UnexpectedChar,
+            "Invalid interval '{}': only a single sign is allowed, before each part",
+            value
+        );
+    }
+    let mut actual = tokens.iter().peekable();
+    let mut pdt = ParsedDateTime::positive();
+    let mut seen_any = false;
+    // the fields whose part had a negative sign
+    let mut negative = vec![];
+    // the fractions of days, hours and minutes, spread over the smaller units
+    let mut fractions = vec![];
+    // the quarters and weeks, as months and days
+    let mut folded = vec![];
+    let mut folded_units = vec![];
+    while actual.peek().is_some() {
+        let is_negative = match actual.peek() {
+            Some(Dash) => {
+                actual.next();
+                true
+            }
+            Some(Plus) => {
+                actual.next();
+                false
+            }
+            _ => false,
+        };
+        let tok = match actual.next() {
+            Some(tok) => tok,
+            None => {
+                return datetime_err!(
+                    InvalidLayout,
+                    "Invalid interval part in '{}': expected a number after the sign",
+                    value
+                )
+            }
+        };
+        let num = match tok {
+            Num(num) => *num,
+            other => {
+                return datetime_err!(
+                    InvalidLayout,
+                    "Invalid interval part in '{}': expected a number but found {}",
+                    value,
+                    found_token(Some(other))
+                )
+            }
+        };
+        let nanos = match actual.peek() {
+            Some(Dot) => {
+                actual.next();
+                match actual.next() {
+                    Some(Nanos(nanos, digits)) => Some((*nanos, *digits)),
+                    other => {
+                        return datetime_err!(
+                            InvalidLayout,
+                            "Invalid interval part in '{}': expected a fraction but found {}",
+                            value,
+                            found_token(other)
+                        )
+                    }
+                }
+            }
+            _ => None,
+        };
+        let field = match actual.next() {
+            Some(Unit(field)) => field,
+            other => {
+                return datetime_err!(
+                    InvalidLayout,
+                    "Invalid interval part in '{}': expected a unit after {} but found {}",
+                    value,
+                    num,
+                    found_token(other)
+                )
+            }
+        };
+        let field_seconds = fraction_field_seconds(field);
+        if nanos.is_some() && field != &DateTimeField::Second && field_seconds.is_none() {
+            return datetime_err!(
+                InvalidLayout,
+                "Invalid interval '{}': only DAY, HOUR, MINUTE and SECOND may have a \
+                 fractional part, not {}",
+                value,
+                field
+            );
+        }
+        if let Some((factor, too_many)) = match field {
+            DateTimeField::Quarter => Some((3, "quarters")),
+            DateTimeField::Week => Some((7, "weeks")),
+            _ => None,
+        } {
+            if folded_units.contains(field) {
+                return datetime_err!(
+                    DuplicateField,
+                    "Invalid interval '{}': {} specified more than once",
+                    value,
+                    field
+                );
+            }
+            folded_units.push(field.clone());
+            let units = match num.checked_mul(factor) {
+                Some(units) => units,
+                None => {
+                    return datetime_err!(
+                        Overflow,
+                        "Invalid interval '{}': too many {}",
+                        value,
+                        too_many
+                    )
+                }
+            };
+            let mut part = ParsedDateTime::with_sign(!is_negative);
+            match field {
+                DateTimeField::Quarter => part.month = Some(units),
+                _ => part.day = Some(units),
+            }
+            folded.push(part);
+            seen_any = true;
+            continue;
+        }
+        let slot = match field {
+            DateTimeField::Year => &mut pdt.year,
+            DateTimeField::Month => &mut pdt.month,
+            DateTimeField::Quarter | DateTimeField::Week => {
+                unreachable!("quarters and weeks are folded above")
+            }
+            DateTimeField::Day => &mut pdt.day,
+            DateTimeField::Hour => &mut pdt.hour,
+            DateTimeField::Minute => &mut pdt.minute,
+            DateTimeField::Second => &mut pdt.second,
+        };
+        if slot.is_some() {
+            return datetime_err!(
+                DuplicateField,
+                "Invalid interval '{}': {} specified more than once",
+                value,
+                field
+            );
+        }
+        *slot = Some(num);
+        match (nanos, field_seconds) {
+            (Some((nanos, _)), Some(field_seconds)) => {
+                let mut spread = ParsedDateTime::with_sign(!is_negative);
+                spread_fraction(&mut spread, field, nanos, field_seconds);
+                fractions.push(spread);
+            }
+            (Some((nanos, digits)), None) => {
+                pdt.nano = Some(nanos);
+                pdt.nano_digits = Some(digits);
+            }
+            (None, _) => {}
+        }
+        if is_negative {
+            negative.push(field.clone());
+        }
+        seen_any = true;
+    }
+    if !seen_any {
+        return datetime_err!(
+            EmptyField,
+            "Invalid interval '{}': no components were provided",
+            value
+        );
+    }
+    let fields = pdt.fields_set().len();
+    let mut pdt = if negative.is_empty() || negative.len() == fields {
+        pdt.is_positive = negative.is_empty();
+        pdt
+    } else {
+        combine_signed_parts(pdt, &negative, value)?
+    };
+    for part in folded.into_iter().chain(fractions) {
+        pdt = (pdt + part).map_err(|_| parts_out_of_range(value))?;
+    }
+    Ok(pdt)
+}
+
+/// The token found where another was expected, in words
+fn found_token(token: Option<&IntervalToken>) -> String {
+    match token {
+        Some(IntervalToken::Num(num)) => format!("the number {}", num),
+        Some(token @ IntervalToken::Nanos(_, _)) => format!("the fraction .{}", token),
+        Some(IntervalToken::Unit(field)) => format!("the unit {}", field),
+        Some(IntervalToken::TzName(name)) => format!("the timezone {}", name),
+        Some(token) => format!("'{}'", token.symbol()),
+        None => "the end of the value".to_string(),
+    }
+}
+
+fn parts_out_of_range(value: &str) -> DateTimeError {
+    DateTimeError::new(
+        ErrorKind::Overflow,
+        ParserError::ParserError(format!(
+            "Invalid interval '{}': once its parts are added up, a field is out of range",
+            value
+        )),
+    )
+}
+
+/// Add up the magnitudes in `pdt`, with the `negative` fields subtracted
+fn combine_signed_parts(
+    pdt: ParsedDateTime,
+    negative: &[DateTimeField],
+    value: &str,
+) -> Result<ParsedDateTime, DateTimeError> {
+    let is_negative = |field| negative.contains(&field);
+    let part = |negative: bool| {
+        let keep = |field, units: Option<u64>| units.filter(|_| is_negative(field) == negative);
+        let has_seconds = is_negative(DateTimeField::Second) == negative;
+        ParsedDateTime {
+            is_positive: !negative,
+            year: keep(DateTimeField::Year, pdt.year),
+            month: keep(DateTimeField::Month, pdt.month),
+            day: keep(DateTimeField::Day, pdt.day),
+            hour: keep(DateTimeField::Hour, pdt.hour),
+            minute: keep(DateTimeField::Minute, pdt.minute),
+            second: keep(DateTimeField::Second, pdt.second),
+            nano: pdt.nano.filter(|_| has_seconds),
+            nano_digits: pdt.nano_digits.filter(|_| has_seconds),
+            ..ParsedDateTime::positive()
+        }
+    };
+    (part(false) + part(true)).map_err(|_| parts_out_of_range(value))
+}
+
+/// Takes a 'date timezone' 'date time timezone' string and splits
+/// it into 'date {time}' and 'timezone' components
+///
+/// The timezone starts at a `+` or `-` that follows whitespace, anywhere in
+/// the value, or otherwise at the first sign, space or letter after the
+/// time. The timezone is `None` if there isn't one.
+///
+/// This walks the value once, noting each place the timezone could start,
+/// and then picks between them.
+pub(crate) fn split_timestamp_string(value: &str) -> (&str, Option<&str>) {
+    fn split_at(value: &str, cut: usize) -> (&str, Option<&str>) {
+        let (first, second) = value.split_at(cut);
+        let second = second.trim();
+        (first.trim(), Some(second).filter(|tz| !tz.is_empty()))
+    }
+
+    // Timestamps can come in a format YYYY-MM-DD {+|-}<tz>, where the
+    // timezone string can have colons, so a sign after a space wins, and one
+    // with a `+` over one with a `-`
+    let mut space_minus = None;
+    // If we have a hh:mm:dd component, we need to go past that to find a tz
+    let mut colon = None;
+    let mut after_colon = None;
+    // We don't have a time, so the only formats available are
+    // YYY-mm-dd<tz> or YYYY-MM-dd <tz>, and numeric offsets need the space
+    let mut space_or_letter = None;
+    let bytes = value.as_bytes();
+    for (i, &byte) in bytes.iter().enumerate() {
+        let after_space = i > 0 && bytes[i - 1] == b' ';
+        match byte {
+            b'+' if after_space => return split_at(value, i - 1),
+            b'-' if after_space && space_minus.is_none() => space_minus = Some(i - 1),
+            _ => {}
+        }
+        if colon.is_none() {
+            if byte == b':' {
+                colon = Some(i);
+            } else if space_or_letter.is_none() && (byte == b' ' || byte.is_ascii_alphabetic()) {
+                space_or_letter = Some(i);
+            }
+        } else if after_colon.is_none()
+            && matches!(byte, b'-' | b'+' | b' ' | b'a'..=b'z' | b'A'..=b'Z')
+        {
+            after_colon = Some(i);
+        }
+    }
+
+    let cut = match colon {
+        _ if space_minus.is_some() => space_minus,
+        Some(_) => after_colon,
+        None => space_or_letter,
+    };
+    match cut {
+        Some(cut) => split_at(value, cut),
+        None => (value.trim(), None),
+    }
+}
+
+pub(crate) fn parse_timezone_offset_second(value: &str) -> Result<i64, DateTimeError> {
+    let toks = tokenize_timezone(value).map_err(DateTimeError::tokenizing)?;
+    Ok(build_timezone_offset_second(&toks, value)?)
+}
+
+/// Parse a timezone offset written as a signed number of minutes, like
+/// `'-480'`, into seconds east of UTC
+pub(crate) fn parse_timezone_minutes(value: &str) -> Result<i64, DateTimeError> {
+    let (sign, digits) = match value.strip_prefix('-') {
+        Some(digits) => (-1, digits),
+        None => (1, value.strip_prefix('+').unwrap_or(value)),
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return datetime_err!(
+            InvalidLayout,
+            "Error parsing timezone string ({}): expected a signed number of minutes, like -480",
+            value
+        );
+    }
+    match digits.parse::<i64>() {
+        Ok(minutes) if minutes <= i64::from(MAX_TIMEZONE_OFFSET_MINUTES) => Ok(sign * minutes * 60),
+        _ => datetime_err!(
+            OutOfRange,
+            "Error parsing timezone string ({}): the offset must be at most {} minutes from UTC",
+            value,
+            MAX_TIMEZONE_OFFSET_MINUTES
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parser::*;
+    use proptest::prelude::*;
+    use proptest::{collection, sample};
+
+    /// The `String`-buffering tokenizer that `tokenize_interval` replaced
+    fn tokenize_interval_with_buffer(value: &str) -> Result<Vec<IntervalToken>, DateTimeError> {
+        let mut toks = vec![];
+        let mut num_buf = String::with_capacity(4);
+        fn parse_num(n: &str, idx: usize) -> Result<IntervalToken, DateTimeError> {
+            Ok(IntervalToken::Num(parse_number(n, idx)?))
+        }
+        let mut last_field_is_frac = false;
+        for (i, chr) in value.chars().enumerate() {
+            match chr {
+                '-' => {
+                    // dashes at the beginning mean make it negative
+                    if !num_buf.is_empty() {
+                        toks.push(parse_num(&num_buf, i)?);
+                        num_buf.clear();
+                    }
+                    toks.push(IntervalToken::Dash);
+                }
+                ' ' => {
+                    toks.push(parse_num(&num_buf, i)?);
+                    num_buf.clear();
+                    toks.push(IntervalToken::Space);
+                }
+                ':' => {
+                    toks.push(parse_num(&num_buf, i)?);
+                    num_buf.clear();
+                    toks.push(IntervalToken::Colon);
+                }
+                '.' => {
+                    if num_buf.is_empty() && matches!(value.chars().nth(i + 1), Some('0'..='9')) {
+                        num_buf.push('0');
+                    }
+                    toks.push(parse_num(&num_buf, i)?);
+                    num_buf.clear();
+                    toks.push(IntervalToken::Dot);
+                    last_field_is_frac = true;
+                }
+                chr if chr.is_ascii_digit() => num_buf.push(chr),
+                chr => {
+                    return tokenizer_err!(
+                        UnexpectedChar,
+                        "Invalid character at offset {} in {}: {:?}",
+                        i,
+                        value,
+                        chr
+                    )
+                }
+            }
+        }
+        if !num_buf.is_empty() {
+            if !last_field_is_frac {
+                toks.push(parse_num(&num_buf, 0)?);
+            } else {
+                // this is guaranteed to be ascii, so len is fine
+                toks.push(IntervalToken::Nanos(
+                    fraction_to_nanos(&num_buf)?,
+                    num_buf.len() as u8,
+                ));
+            }
+        }
+        Ok(toks)
+    }
+
+    #[test]
+    fn test_tokenize_interval_matches_buffered() {
+        for value in &[
+            "",
+            "1",
+            "-1",
+            "1-2",
+            "-1-2 3:4:5.678",
+            "1 2:3:4.000000001",
+            "12:30",
+            "1.5",
+            "-.5",
+            ".5",
+            ".",
+            "1 .",
+            "..5",
+            "-",
+            "1::2",
+            "1 ",
+            " 1",
+            "007",
+            "18446744073709551615",
+            "18446744073709551616",
+            "99999999999999999999:1",
+            "1-2x",
+            "1 2:3:4.5 (3)",
+            "1 d\u{e9}j\u{e0}",
+        ] {
+            let buffered = tokenize_interval_with_buffer(value).map_err(DateTimeError::tokenizing);
+            match tokenize_interval(value, &DateTimeParseOptions::default()) {
+                // the buffered tokenizer doesn't look for incomplete values
+                Err(e) if e.kind == ErrorKind::Incomplete => assert!(
+                    matches!(
+                        buffered.as_deref(),
+                        Ok([
+                            ..,
+                            IntervalToken::Dash | IntervalToken::Space | IntervalToken::Colon
+                        ])
+                    ),
+                    "tokenizing {:?}",
+                    value
+                ),
+                toks => assert_eq!(toks, buffered, "tokenizing {:?}", value),
+            }
+        }
+    }
+
+    #[test]
+    fn test_potential_interval_tokens() {
+        use DateTimeField::*;
+        use IntervalToken::*;
+        assert_eq!(
+            potential_interval_tokens(&Year),
+            vec![
+                Num(0),
+                Dash,
+                Num(0),
+                Dash,
+                Num(0),
+                Space,
+                Num(0),
+                Colon,
+                Num(0),
+                Colon,
+                Num(0),
+                Dot,
+                Nanos(0, 0),
+            ]
+        );
+
+        assert_eq!(
+            potential_interval_tokens(&Day),
+            vec![
+                Num(0),
+                Space,
+                Num(0),
+                Colon,
+                Num(0),
+                Colon,
+                Num(0),
+                Dot,
+                Nanos(0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_out_of_order_error_shows_layout() {
+        assert_eq!(
+            Parser::parse_interval_string("1:2", &DateTimeField::Year).unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval part at offset 1: '1:2' provided ':' but expected '-'; \
+                 YEAR intervals are laid out as \
+                 Num Dash Num Dash Num Space Num Colon Num Colon Num Dot Nanos\n\
+                 1:2\n \
+                 ^\n\
+                 hint: '1:2' looks like a time, but YEAR is a date field; a time needs a time \
+                 leading field, like HOUR TO MINUTE or MINUTE TO SECOND"
+                    .into()
+            )
+        );
+    }
+
+    #[test]
+    fn test_expected_tokens() {
+        use IntervalToken::*;
+        let options = DateTimeParseOptions::default();
+        let expected = |value, field| {
+            Parser::parse_interval_string_with_kind(value, &field, &options)
+                .unwrap_err()
+                .expected_tokens
+        };
+        // a value cut off after a delimiter needs a number
+        assert_eq!(expected("1-2 3:", DateTimeField::Year), vec![Num(0)]);
+        assert_eq!(expected("1 ", DateTimeField::Day), vec![Num(0)]);
+        // otherwise it's what the layout has at that point
+        assert_eq!(expected("1-2:3", DateTimeField::Year), vec![Dash]);
+        assert_eq!(expected("1 1-1", DateTimeField::Day), vec![Colon]);
+        assert_eq!(expected("1:2:3:4", DateTimeField::Hour), vec![]);
+        assert_eq!(expected("1 fortnight", DateTimeField::Second), vec![]);
+    }
+
+    #[test]
+    fn test_token_symbols() {
+        use IntervalToken::*;
+        let symbols: Vec<_> = [Dash, Space, Colon, Dot, Plus, Zulu, Num(1), Nanos(5, 1)]
+            .iter()
+            .map(IntervalToken::symbol)
+            .collect();
+        assert_eq!(
+            symbols,
+            vec!["-", " ", ":", ".", "+", "Z", "<number>", "<fraction>"]
+        );
+        assert_eq!(
+            Parser::parse_interval_string("1-2:3", &DateTimeField::Year).unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval part at offset 3: '1-2:3' provided ':' but expected '-'; \
+                 YEAR intervals are laid out as \
+                 Num Dash Num Dash Num Space Num Colon Num Colon Num Dot Nanos\n\
+                 1-2:3\n   \
+                 ^"
+                .into()
+            )
+        );
+    }
+
+    #[test]
+    fn test_interval_tokens_lazily() {
+        use IntervalToken::*;
+        let options = DateTimeParseOptions::default();
+        let mut toks = interval_tokens("1-2x3", &options);
+        assert_eq!(toks.next(), Some(Ok(Num(1))));
+        assert_eq!(toks.next(), Some(Ok(Dash)));
+        assert_eq!(
+            toks.next().map(|tok| tok.unwrap_err().kind),
+            Some(ErrorKind::UnexpectedChar)
+        );
+        // nothing follows an error
+        assert_eq!(toks.next(), None);
+
+        // values with more tokens than fit on the stack are still built
+        let long = "1-2-3 4:5:6.7 8 9";
+        assert_eq!(
+            build_parsed_datetime(
+                interval_tokens(long, &options),
+                &DateTimeField::Year,
+                long,
+                &options
+            ),
+            build_parsed_datetime_from_tokens(
+                &tokenize_interval(long, &options).unwrap(),
+                &DateTimeField::Year,
+                long,
+                &options
+            )
+        );
+    }
+
+    #[test]
+    fn test_tokenize_interval_prefix() {
+        use IntervalToken::*;
+        let options = DateTimeParseOptions::default();
+        for (value, toks, consumed) in &[
+            ("1:30 AND x", vec![Num(1), Colon, Num(30)], 4),
+            ("1:30 - x", vec![Num(1), Colon, Num(30)], 4),
+            (
+                "1-2 3:04)",
+                vec![Num(1), Dash, Num(2), Space, Num(3), Colon, Num(4)],
+                8,
+            ),
+            ("-5 days", vec![Dash, Num(5)], 2),
+            ("1.5.6", vec![Num(1), Dot, Nanos(500_000_000, 1)], 3),
+            ("10 to 20", vec![Num(10)], 2),
+            ("1: ", vec![Num(1)], 1),
+            ("3:04", vec![Num(3), Colon, Num(4)], 4),
+        ] {
+            assert_eq!(
+                tokenize_interval_prefix(value, &options).unwrap(),
+                (toks.clone(), *consumed),
+                "tokenizing {:?}",
+                value
+            );
+            assert_eq!(
+                &tokenize_interval(&value[..*consumed], &options).unwrap(),
+                toks
+            );
+        }
+        assert_eq!(
+            Parser::tokenize_interval_prefix("1 day").unwrap(),
+            (vec![Num(1)], 1)
+        );
+        for value in &["", "x", " 1", "- x"] {
+            let err = tokenize_interval_prefix(value, &options).unwrap_err();
+            assert_eq!(err.phase, ErrorPhase::Tokenize, "{:?}", value);
+        }
+        // the prefix is found, but it is too long to be a number
+        assert!(tokenize_interval_prefix("99999999999999999999 x", &options).is_err());
+    }
+
+    #[test]
+    fn test_tokenize_interval_collect() {
+        use IntervalToken::*;
+        let options = DateTimeParseOptions::default();
+        for value in &["1-2 3:4:5.678", "-1 2:03", ".5", "007"] {
+            assert_eq!(
+                tokenize_interval_collect(value, &options),
+                (tokenize_interval(value, &options).unwrap(), vec![]),
+                "tokenizing {:?}",
+                value
+            );
+        }
+
+        let (toks, errors) = tokenize_interval_collect("1-2x 3y:4", &options);
+        assert_eq!(
+            toks,
+            vec![Num(1), Dash, Num(2), Space, Num(3), Colon, Num(4)]
+        );
+        assert_eq!(
+            errors,
+            vec![
+                DateTimeError::new(
+                    ErrorKind::UnexpectedChar,
+                    ParserError::TokenizerError(
+                        "Invalid character at offset 3 in 1-2x 3y:4: 'x'".into()
+                    )
+                ),
+                DateTimeError::new(
+                    ErrorKind::UnexpectedChar,
+                    ParserError::TokenizerError(
+                        "Invalid character at offset 6 in 1-2x 3y:4: 'y'".into()
+                    )
+                ),
+            ]
+        );
+        // the fail-fast tokenizer only sees the first
+        assert_eq!(
+            tokenize_interval("1-2x 3y:4", &options).unwrap_err(),
+            errors[0]
+        );
+
+        // the digits after an invalid character are part of the same bad field
+        let (toks, errors) = Parser::tokenize_interval_collect("1x5 2:3");
+        assert_eq!(toks, vec![Num(1), Space, Num(2), Colon, Num(3)]);
+        assert_eq!(errors.len(), 1);
+
+        let (toks, errors) = tokenize_interval_collect("1::2:", &options);
+        assert_eq!(toks, vec![Num(1), Colon, Colon, Num(2), Colon]);
+        assert_eq!(
+            errors.iter().map(|e| e.kind).collect::<Vec<_>>(),
+            vec![ErrorKind::EmptyField, ErrorKind::Incomplete]
+        );
+    }
+
+    #[test]
+    fn test_trailing_tokens() {
+        use IntervalToken::*;
+        let options = DateTimeParseOptions::default();
+        let build = |toks: &[IntervalToken], field| {
+            build_parsed_datetime(toks.iter().cloned().map(Ok), &field, "", &options)
+        };
+        assert_eq!(
+            build(&[Num(1), Dot, Nanos(500_000_000, 1)], DateTimeField::Second).unwrap(),
+            ParsedDateTime {
+                second: Some(1),
+                nano: Some(500_000_000),
+                nano_digits: Some(1),
+                ..Default::default()
+            }
+        );
+        let err = build(
+            &[Num(1), Dot, Nanos(500_000_000, 1), Space, Num(2)],
+            DateTimeField::Second,
+        )
+        .unwrap_err();
+        assert_eq!(err.kind, ErrorKind::InvalidLayout);
+        assert_eq!(
+            err.error,
+            ParserError::ParserError(
+                "Invalid interval '': ' <number>' is left over after the end of the SECOND \
+                 layout, Num Dot Nanos"
+                    .into()
+            )
+        );
+        let err = build(
+            &[
+                Dash,
+                Num(1),
+                Colon,
+                Num(2),
+                Colon,
+                Num(3),
+                Dot,
+                Nanos(4, 1),
+                Dot,
+            ],
+            DateTimeField::Hour,
+        )
+        .unwrap_err();
+        assert_eq!(err.kind, ErrorKind::InvalidLayout);
+    }
+
+    #[test]
+    fn test_leading_zeros() {
+        use DateTimeField::*;
+        let parse = |value, field| Parser::parse_interval_string(value, &field).unwrap();
+        for (padded, plain, field) in &[
+            ("01-02", "1-2", Year),
+            ("-01-02", "-1-2", Year),
+            ("04:05:06", "4:5:6", Hour),
+            ("00:00:09", "0:0:9", Hour),
+            ("007 days", "7 days", Day),
+            ("P007D", "P7D", Day),
+            ("0001 02:03", "1 2:3", Day),
+        ] {
+            assert_eq!(
+                parse(padded, field.clone()),
+                parse(plain, field.clone()),
+                "parsing {}",
+                padded
+            );
+        }
+        assert_eq!(
+            parse("00:00:09", Hour),
+            ParsedDateTime {
+                hour: Some(0),
+                minute: Some(0),
+                second: Some(9),
+                ..Default::default()
+            }
+        );
+        // zeros before the point are not fraction digits
+        let pdt = parse("0009.50", Second);
+        assert_eq!(
+            (pdt.second, pdt.nano, pdt.nano_digits),
+            (Some(9), Some(500_000_000), Some(2))
+        );
+    }
+
+    #[test]
+    fn test_fraction_to_nanos() {
+        for (digits, nanos) in &[
+            ("5", 500_000_000),
+            ("05", 50_000_000),
+            ("123", 123_000_000),
+            ("1234", 123_400_000),
+            ("00001", 10_000),
+            ("999999", 999_999_000),
+            ("0000001", 100),
+            ("12345678", 123_456_780),
+            ("999999999", 999_999_999),
+            ("000000000", 0),
+        ] {
+            assert_eq!(
+                fraction_to_nanos(digits),
+                Ok(*nanos),
+                "converting {}",
+                digits
+            );
+        }
+        for digits in &["", "1234567890", "99999999999999999999"] {
+            assert_eq!(
+                fraction_to_nanos(digits).unwrap_err().kind,
+                ErrorKind::OutOfRange,
+                "converting {:?}",
+                digits
+            );
+        }
+        for digits in &["+1", "1a", "1 "] {
+            assert_eq!(
+                fraction_to_nanos(digits).unwrap_err().kind,
+                ErrorKind::UnexpectedChar,
+                "converting {:?}",
+                digits
+            );
+        }
+    }
+
+    #[test]
+    fn test_fraction_without_whole_part() {
+        let parse = |value, field| Parser::parse_interval_string(value, &field);
+        let half = ParsedDateTime {
+            second: Some(0),
+            nano: Some(500_000_000),
+            nano_digits: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(parse(".5", DateTimeField::Second).unwrap(), half);
+        assert_eq!(parse("0.5", DateTimeField::Second).unwrap(), half);
+        assert_eq!(
+            parse("-.5", DateTimeField::Second).unwrap(),
+            parse("-0.5", DateTimeField::Second).unwrap()
+        );
+        assert_eq!(
+            parse("1:.5", DateTimeField::Minute).unwrap(),
+            parse("1:0.5", DateTimeField::Minute).unwrap()
+        );
+        // a dot that isn't followed by digits is still an error
+        for value in &[".", "-.", "1 .", "..5"] {
+            assert!(
+                parse(value, DateTimeField::Second).is_err(),
+                "parsing {:?}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_incomplete_values() {
+        let tokenize = |value| tokenize_interval(value, &DateTimeParseOptions::default());
+        assert_eq!(
+            tokenize("1-").unwrap_err(),
+            DateTimeError::new(
+                ErrorKind::Incomplete,
+                ParserError::TokenizerError(
+                    "Invalid interval '1-': it ends with '-', but a field should follow it".into()
+                )
+            )
+            .with_expected_tokens(vec![IntervalToken::Num(0)])
+        );
+        for value in &["-", "1-2 ", "1 2:", "1-2 3:4:"] {
+            assert_eq!(
+                tokenize(value).unwrap_err().kind,
+                ErrorKind::Incomplete,
+                "tokenizing {:?}",
+                value
+            );
+        }
+        for value in &["1-x", "1:!"] {
+            assert_eq!(
+                tokenize(value).unwrap_err().kind,
+                ErrorKind::UnexpectedChar,
+                "tokenizing {:?}",
+                value
+            );
+        }
+        assert!(tokenize("1.").is_ok());
+        let options = DateTimeParseOptions::default();
+        let err = Parser::parse_interval_string_with_kind("1-", &DateTimeField::Year, &options);
+        assert_eq!(err.unwrap_err().kind, ErrorKind::Incomplete);
+    }
+
+    #[test]
+    fn test_empty_is_zero_option() {
+        let lenient = DateTimeParseOptions {
+            empty_is_zero: true,
+            ..Default::default()
+        };
+        let strict = DateTimeParseOptions::default();
+        for value in &["", "   ", "\t "] {
+            assert_eq!(
+                Parser::parse_interval_string_with_kind(value, &DateTimeField::Second, &strict)
+                    .unwrap_err(),
+                DateTimeError::new(
+                    ErrorKind::EmptyField,
+                    ParserError::ParserError("Interval date string is empty!".into())
+                ),
+                "parsing {:?}",
+                value
+            );
+            assert_eq!(
+                Parser::parse_interval_string_with_options(value, &DateTimeField::Day, &lenient)
+                    .unwrap(),
+                ParsedDateTime::zero(),
+                "parsing {:?}",
+                value
+            );
+        }
+        for options in &[&strict, &lenient] {
+            assert_eq!(
+                Parser::parse_interval_string_with_options("1", &DateTimeField::Day, options)
+                    .unwrap(),
+                ParsedDateTime {
+                    day: Some(1),
+                    ..Default::default()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_no_numeric_value() {
+        for value in &[".", "-.", ".+", "-", ": ."] {
+            assert_eq!(
+                Parser::parse_interval_string(value, &DateTimeField::Second).unwrap_err(),
+                ParserError::ParserError(format!("Invalid interval '{}': no numeric value", value))
+            );
+        }
+        // a trailing dot after a number is still accepted
+        assert!(Parser::parse_interval_string("1.", &DateTimeField::Second).is_ok());
+    }
+
+    #[test]
+    fn test_colon_separated_fields() {
+        let pdt = Parser::parse_interval_string("12:30", &DateTimeField::Hour).unwrap();
+        assert_eq!(
+            (pdt.hour, pdt.minute, pdt.second),
+            (Some(12), Some(30), None)
+        );
+        let pdt = Parser::parse_interval_string("12:30", &DateTimeField::Minute).unwrap();
+        assert_eq!(
+            (pdt.hour, pdt.minute, pdt.second),
+            (None, Some(12), Some(30))
+        );
+
+        assert_eq!(
+            Parser::parse_interval_string("1:2:3:4", &DateTimeField::Hour).unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '1:2:3:4': it has 4 colon-separated time fields but HOUR \
+                 intervals allow at most 3"
+                    .into()
+            )
+        );
+        assert_eq!(
+            Parser::parse_interval_string("1:2:3", &DateTimeField::Minute).unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '1:2:3': it has 3 colon-separated time fields but MINUTE \
+                 intervals allow at most 2"
+                    .into()
+            )
+        );
+        assert!(Parser::parse_interval_string("1 2:3:4:5", &DateTimeField::Day).is_err());
+        assert!(Parser::parse_interval_string("1:2", &DateTimeField::Second).is_err());
+    }
+
+    #[test]
+    fn test_strict_year_month() {
+        let strict = DateTimeParseOptions {
+            strict_year_month: true,
+            ..Default::default()
+        };
+
+        let loose = Parser::parse_interval_string("9-5 4:3", &DateTimeField::Month).unwrap();
+        assert_eq!(
+            loose,
+            ParsedDateTime {
+                month: Some(9),
+                day: Some(5),
+                hour: Some(4),
+                minute: Some(3),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            Parser::parse_interval_string_with_options("9-5 4:3", &DateTimeField::Month, &strict)
+                .unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '9-5 4:3': a MONTH interval may not contain day or time fields"
+                    .into()
+            )
+        );
+        assert!(
+            Parser::parse_interval_string_with_options("1-2 3", &DateTimeField::Year, &strict)
+                .is_err()
+        );
+
+        let pdt = Parser::parse_interval_string_with_options("-9", &DateTimeField::Month, &strict)
+            .unwrap();
+        assert_eq!(
+            pdt,
+            ParsedDateTime {
+                is_positive: false,
+                month: Some(9),
+                ..Default::default()
+            }
+        );
+        let pdt = Parser::parse_interval_string_with_options("1-2", &DateTimeField::Year, &strict)
+            .unwrap();
+        assert_eq!((pdt.year, pdt.month), (Some(1), Some(2)));
+        // day-time intervals are not affected
+        let pdt = Parser::parse_interval_string_with_options("1 2:3", &DateTimeField::Day, &strict)
+            .unwrap();
+        assert_eq!((pdt.day, pdt.hour, pdt.minute), (Some(1), Some(2), Some(3)));
+    }
+
+    #[test]
+    fn test_strip_quotes_option() {
+        let options = DateTimeParseOptions {
+            strip_quotes: true,
+            ..Default::default()
+        };
+        let parse = |value, options: &DateTimeParseOptions| {
+            Parser::parse_unqualified_interval_string_with_options(value, options)
+        };
+        let day = ParsedDateTime {
+            day: Some(1),
+            ..Default::default()
+        };
+        for value in &[
+            "'1 day'",
+            " 1 day ",
+            " '1 day' ",
+            "\"1 day\"",
+            "\t\"1 day\"\n",
+        ] {
+            assert_eq!(
+                parse(value, &options),
+                Ok(day.clone()),
+                "parsing {:?}",
+                value
+            );
+        }
+        assert_eq!(
+            parse("' 1:30'", &options),
+            Parser::parse_unqualified_interval_string(" 1:30")
+        );
+        for value in &["'1 day", "1 day'", "'1 day\"", "''1 day''"] {
+            assert!(parse(value, &options).is_err(), "parsing {:?}", value);
+        }
+        assert!(parse("'1 day'", &DateTimeParseOptions::default()).is_err());
+    }
+
+    #[test]
+    fn test_last_field_option() {
+        use DateTimeField::*;
+        let parse = |value, last_field| {
+            let options = DateTimeParseOptions {
+                last_field: Some(last_field),
+                ..Default::default()
+            };
+            Parser::parse_interval_string_with_options(value, &Day, &options)
+        };
+        for (last_field, fits, too_long) in &[
+            (Hour, "1 2", "1 2:03"),
+            (Minute, "1 2:03", "1 2:03:04"),
+            (Second, "1 2:03:04.5", "1 2:03:04.5 6"),
+        ] {
+            assert_eq!(
+                parse(fits, last_field.clone()),
+                Parser::parse_interval_string(fits, &Day),
+                "parsing {} as DAY TO {}",
+                fits,
+                last_field
+            );
+            assert!(
+                parse(too_long, last_field.clone()).is_err(),
+                "parsing {} as DAY TO {}",
+                too_long,
+                last_field
+            );
+        }
+        assert_eq!(
+            parse("1 2:03:04", Minute).unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '1 2:03:04': a DAY TO MINUTE interval may not have fields after \
+                 the MINUTE"
+                    .into()
+            )
+        );
+        // a value may stop short of the last field
+        assert!(parse("1", Minute).is_ok());
+        assert!(parse("1 2", Hour.clone()).is_ok());
+        assert_eq!(
+            parse("1", Year).unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '1': the last field, YEAR, is larger than the leading field, DAY"
+                    .into()
+            )
+        );
+    }
+
+    #[test]
+    fn test_leading_precision() {
+        let parse = |value, field, precision| {
+            let options = DateTimeParseOptions {
+                leading_precision: Some(precision),
+                ..Default::default()
+            };
+            Parser::parse_interval_string_with_options(value, &field, &options)
+        };
+        assert_eq!(
+            parse("99", DateTimeField::Day, 2).unwrap(),
+            Parser::parse_interval_string("99", &DateTimeField::Day).unwrap()
+        );
+        assert_eq!(
+            parse("999", DateTimeField::Day, 2).unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '999': the DAY has 3 digits, but the leading precision allows \
+                 at most 2"
+                    .into()
+            )
+        );
+        assert!(parse("999", DateTimeField::Day, 3).is_ok());
+        assert!(parse("-0099 12:00", DateTimeField::Day, 2).is_ok());
+        assert!(parse("100:30", DateTimeField::Hour, 2).is_err());
+        assert!(parse("12 days 100 hours", DateTimeField::Day, 2).is_ok());
+        assert_eq!(
+            parse("1", DateTimeField::Day, 0).unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '1': a leading precision of 0 is not allowed, it must be at \
+                 least 1"
+                    .into()
+            )
+        );
+    }
+
+    #[test]
+    fn test_fractional_seconds_precision() {
+        let with_precision = |value: &str, precision: u64| {
+            let options = DateTimeParseOptions {
+                fractional_seconds_precision: Some(precision),
+                ..Default::default()
+            };
+            Parser::parse_interval_string_with_options(value, &DateTimeField::Second, &options)
+        };
+
+        let pdt = with_precision("1.5", 0).unwrap();
+        assert_eq!((pdt.second, pdt.nano), (Some(2), Some(0)));
+        let pdt = with_precision("1.4", 0).unwrap();
+        assert_eq!((pdt.second, pdt.nano), (Some(1), Some(0)));
+
+        let pdt = with_precision("1.23456", 3).unwrap();
+        assert_eq!((pdt.second, pdt.nano), (Some(1), Some(235_000_000)));
+        let pdt = with_precision("1.9999", 3).unwrap();
+        assert_eq!((pdt.second, pdt.nano), (Some(2), Some(0)));
+        let pdt = with_precision("1.123456789", 9).unwrap();
+        assert_eq!((pdt.second, pdt.nano), (Some(1), Some(123_456_789)));
+
+        // values without a fraction are untouched
+        let pdt = with_precision("1", 3).unwrap();
+        assert_eq!((pdt.second, pdt.nano), (Some(1), None));
+
+        assert_eq!(
+            with_precision("1.5", 10).unwrap_err(),
+            ParserError::ParserError(
+                "fractional seconds precision must be between 0 and 9, got 10".into()
+            )
+        );
+    }
+
+    #[test]
+    fn test_embedded_precision_marker() {
+        let options = DateTimeParseOptions {
+            embedded_precision: true,
+            ..Default::default()
+        };
+        let pdt = Parser::parse_interval_string_with_options(
+            "1.2345 (3)",
+            &DateTimeField::Second,
+            &options,
+        )
+        .unwrap();
+        assert_eq!((pdt.second, pdt.nano), (Some(1), Some(235_000_000)));
+        let pdt = Parser::parse_interval_string_with_options(
+            "1.2344(3)",
+            &DateTimeField::Second,
+            &options,
+        )
+        .unwrap();
+        assert_eq!((pdt.second, pdt.nano), (Some(1), Some(234_000_000)));
+        let pdt =
+            Parser::parse_interval_string_with_options("1.2345", &DateTimeField::Second, &options)
+                .unwrap();
+        assert_eq!((pdt.second, pdt.nano), (Some(1), Some(234_500_000)));
+
+        assert!(Parser::parse_interval_string_with_options(
+            "1.2 (x)",
+            &DateTimeField::Second,
+            &options
+        )
+        .is_err());
+        assert!(Parser::parse_interval_string_with_options(
+            "1.2 3)",
+            &DateTimeField::Second,
+            &options
+        )
+        .is_err());
+        assert!(Parser::parse_interval_string_with_options(
+            "(3)",
+            &DateTimeField::Second,
+            &options
+        )
+        .is_err());
+
+        // by default the marker is not special
+        assert_eq!(
+            Parser::parse_interval_string("1.2345 (3)", &DateTimeField::Second).unwrap_err(),
+            ParserError::TokenizerError("Invalid character at offset 7 in 1.2345 (3): '('".into())
+        );
+    }
+
+    #[test]
+    fn test_parse_iso8601_duration() {
+        let pdt = Parser::parse_unqualified_interval_string("P1Y2M3DT4H5M6.7S").unwrap();
+        assert_eq!(
+            pdt,
+            ParsedDateTime {
+                year: Some(1),
+                month: Some(2),
+                day: Some(3),
+                hour: Some(4),
+                minute: Some(5),
+                second: Some(6),
+                nano: Some(700_000_000),
+                nano_digits: Some(1),
+                ..Default::default()
+            }
+        );
+
+        let pdt = Parser::parse_unqualified_interval_string("-PT90S").unwrap();
+        assert_eq!(
+            pdt,
+            ParsedDateTime {
+                is_positive: false,
+                second: Some(90),
+                ..Default::default()
+            }
+        );
+
+        let failure_test_cases = [
+            "P", "PT", "P1DT", "P1", "PD", "P1H", "PT1D", "P1D1D", "P1.5Y", "P1Dx",
+        ];
+        for test in failure_test_cases.iter() {
+            match Parser::parse_interval_string(test, &DateTimeField::Year) {
+                Ok(pdt) => panic!("expected {} to fail, parsed {:?}", test, pdt),
+                Err(e) => println!("{}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_iso8601_month_or_minute() {
+        use DateTimeField::*;
+        use IntervalToken::*;
+        // an `M` is a month before the `T` and a minute after it
+        for (value, tokens, month, minute) in &[
+            ("PT1M", vec![Num(1), Unit(Minute)], None, Some(1)),
+            ("P1M", vec![Num(1), Unit(Month)], Some(1), None),
+            (
+                "P1MT1M",
+                vec![Num(1), Unit(Month), Num(1), Unit(Minute)],
+                Some(1),
+                Some(1),
+            ),
+            (
+                "P2MT3M",
+                vec![Num(2), Unit(Month), Num(3), Unit(Minute)],
+                Some(2),
+                Some(3),
+            ),
+        ] {
+            assert_eq!(
+                &tokenize_iso8601_duration(value).unwrap(),
+                tokens,
+                "tokenizing {}",
+                value
+            );
+            let pdt = Parser::parse_unqualified_interval_string(value).unwrap();
+            assert_eq!(
+                (pdt.month, pdt.minute),
+                (*month, *minute),
+                "parsing {}",
+                value
+            );
+        }
+        // the month may only come once, however many `M`s there are
+        assert!(Parser::parse_unqualified_interval_string("P1M1M").is_err());
+        assert!(Parser::parse_unqualified_interval_string("PT1M1M").is_err());
+    }
+
+    #[test]
+    fn test_parse_iso8601_zero_duration() {
+        let pdt = Parser::parse_interval_string("P0D", &DateTimeField::Day).unwrap();
+        assert_eq!(
+            pdt,
+            ParsedDateTime {
+                day: Some(0),
+                ..Default::default()
+            }
+        );
+        assert!(pdt.is_zero());
+
+        let pdt = Parser::parse_interval_string("PT0S", &DateTimeField::Second).unwrap();
+        assert_eq!(
+            pdt,
+            ParsedDateTime {
+                second: Some(0),
+                ..Default::default()
+            }
+        );
+        assert!(pdt.is_zero());
+
+        let pdt = Parser::parse_interval_string("P1D", &DateTimeField::Day).unwrap();
+        assert!(!pdt.is_zero());
+
+        assert_eq!(
+            Parser::parse_interval_string("P", &DateTimeField::Day).unwrap_err(),
+            ParserError::ParserError("Invalid interval 'P': no components were provided".into())
+        );
+    }
+
+    #[test]
+    fn test_oracle_day_to_second() {
+        let parse = |value| Parser::parse_interval_string(value, &DateTimeField::Day).unwrap();
+        assert_eq!(
+            parse("+03 04:05:06.789"),
+            ParsedDateTime {
+                day: Some(3),
+                hour: Some(4),
+                minute: Some(5),
+                second: Some(6),
+                nano: Some(789_000_000),
+                nano_digits: Some(3),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            parse("-00 01:00:00"),
+            ParsedDateTime {
+                is_positive: false,
+                day: Some(0),
+                hour: Some(1),
+                minute: Some(0),
+                second: Some(0),
+                ..Default::default()
+            }
+        );
+        assert!(parse("+00 00:00:00.000000").is_zero());
+        // only a leading day is a count, a day after the months is a date
+        assert!(Parser::parse_interval_string("1-2-0", &DateTimeField::Year).is_err());
+    }
+
+    #[test]
+    fn test_parse_interval_list() {
+        assert_eq!(
+            Parser::parse_interval_list(&["1 day", "2 hours", "3 mins"], DateTimeField::Day)
+                .unwrap(),
+            vec![
+                ParsedDateTime {
+                    day: Some(1),
+                    ..Default::default()
+                },
+                ParsedDateTime {
+                    hour: Some(2),
+                    ..Default::default()
+                },
+                ParsedDateTime {
+                    minute: Some(3),
+                    ..Default::default()
+                },
+            ]
+        );
+        assert_eq!(
+            Parser::parse_interval_list(&[], DateTimeField::Day).unwrap(),
+            vec![]
+        );
+        let err = Parser::parse_interval_list(&["1 day", "2 fortnights", "x"], DateTimeField::Day)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ParserError::ParserError(format!(
+                "interval 1 of the list: {}",
+                match Parser::parse_unqualified_interval_string("2 fortnights") {
+                    Err(ParserError::ParserError(s)) => s,
+                    other => panic!("unexpected {:?}", other),
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_infer_leading_field() {
+        use DateTimeField::*;
+        for (value, field) in &[
+            ("30", Second),
+            ("-30.5", Second),
+            ("1:00", Hour),
+            ("-1:00:00.5", Hour),
+            ("1-2", Year),
+            ("-1-2 3:04", Year),
+            ("1 2:00", Day),
+            ("1 day 2 hours", Second),
+            ("P1Y2M", Second),
+        ] {
+            assert_eq!(
+                &Parser::infer_interval_leading_field(value),
+                field,
+                "inferring {}",
+                value
+            );
+        }
+        let parse = |value| {
+            Parser::parse_interval_string(value, &Parser::infer_interval_leading_field(value))
+                .unwrap()
+        };
+        assert_eq!(
+            parse("30"),
+            ParsedDateTime {
+                second: Some(30),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            parse("1:00"),
+            ParsedDateTime {
+                hour: Some(1),
+                minute: Some(0),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            parse("1-2"),
+            ParsedDateTime {
+                year: Some(1),
+                month: Some(2),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_too_many_fields() {
+        assert!(Parser::parse_interval_string("1-2-3 4:5:6.7 8", &DateTimeField::Year).is_err());
+        assert!(Parser::parse_interval_string("1 2 3", &DateTimeField::Second).is_err());
+    }
+
+    #[test]
+    fn test_field_range_errors() {
+        assert_eq!(
+            Parser::parse_interval_string("0-5", &DateTimeField::Month).unwrap_err(),
+            ParserError::ParserError("Invalid Month 0 in '0-5': month must be >= 1".into())
+        );
+        assert_eq!(
+            Parser::parse_interval_string("1-2-0", &DateTimeField::Year).unwrap_err(),
+            ParserError::ParserError("Invalid Day 0 in '1-2-0': day must be >= 1".into())
+        );
+    }
+
+    #[test]
+    fn test_strict_year_to_month() {
+        let year = ParsedDateTime {
+            year: Some(2023),
+            ..Default::default()
+        };
+        assert_eq!(
+            Parser::parse_interval_string("2023", &DateTimeField::Year).unwrap(),
+            year
+        );
+
+        let options = DateTimeParseOptions {
+            strict_year_to_month: true,
+            ..Default::default()
+        };
+        let parse = |value| {
+            Parser::parse_interval_string_with_options(value, &DateTimeField::Year, &options)
+        };
+        assert_eq!(
+            parse("2023").unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '2023': a YEAR TO MONTH value must be written as <years>-<months>"
+                    .into()
+            )
+        );
+        assert!(parse("-2023").is_err());
+        assert_eq!(
+            parse("2023-1").unwrap(),
+            ParsedDateTime {
+                month: Some(1),
+                ..year.clone()
+            }
+        );
+        assert_eq!(
+            parse("-2023-1").unwrap(),
+            ParsedDateTime {
+                is_positive: false,
+                month: Some(1),
+                ..year
+            }
+        );
+    }
+
+    #[test]
+    fn test_compact_time() {
+        let options = DateTimeParseOptions {
+            compact_time: true,
+            ..Default::default()
+        };
+        let compact = |value| {
+            Parser::parse_interval_string_with_options(value, &DateTimeField::Hour, &options)
+                .unwrap()
+        };
+        let hours = |hour| ParsedDateTime {
+            hour: Some(hour),
+            ..Default::default()
+        };
+        assert_eq!(
+            compact("1203"),
+            ParsedDateTime {
+                hour: Some(12),
+                minute: Some(3),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            compact("-120305"),
+            ParsedDateTime {
+                is_positive: false,
+                hour: Some(12),
+                minute: Some(3),
+                second: Some(5),
+                ..Default::default()
+            }
+        );
+        // other lengths, and other fields, are read as usual
+        assert_eq!(compact("12345"), hours(12345));
+        assert_eq!(compact("12:03"), compact("1203"));
+
+        let default = |value| Parser::parse_interval_string(value, &DateTimeField::Hour).unwrap();
+        assert_eq!(default("1203"), hours(1203));
+        assert_eq!(default("120305"), hours(120_305));
+
+        // there is nothing to carry minutes or seconds of 60 or more into
+        let compact_err = |value| {
+            Parser::parse_interval_string_with_options(value, &DateTimeField::Hour, &options)
+                .unwrap_err()
+        };
+        assert_eq!(
+            compact_err("2560"),
+            ParserError::ParserError(
+                "Invalid Minute 60 in '2560': minute must be between 0 and 59".into()
+            )
+        );
+        assert_eq!(
+            compact_err("123099"),
+            ParserError::ParserError(
+                "Invalid Second 99 in '123099': second must be between 0 and 59".into()
+            )
+        );
+        assert_eq!(compact("235959").second, Some(59));
+        for value in &["0160", "010160", "016001", "-0199"] {
+            let err =
+                Parser::parse_interval_string_with_kind(value, &DateTimeField::Hour, &options)
+                    .unwrap_err();
+            assert_eq!(err.kind, ErrorKind::OutOfRange, "parsing {:?}", value);
+        }
+    }
+
+    #[test]
+    fn test_meridiem() {
+        let parse = |value| Parser::parse_interval_string(value, &DateTimeField::Hour);
+        let clock = |hour, minute| ParsedDateTime {
+            hour: Some(hour),
+            minute,
+            ..Default::default()
+        };
+        assert_eq!(parse("12 AM").unwrap(), clock(0, None));
+        assert_eq!(parse("12 PM").unwrap(), clock(12, None));
+        assert_eq!(parse("4:05 PM").unwrap(), clock(16, Some(5)));
+        assert_eq!(parse("4:05pm").unwrap(), parse("4:05 PM").unwrap());
+        assert_eq!(parse("11:59 am").unwrap(), clock(11, Some(59)));
+        assert_eq!(
+            parse("13:00 PM").unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '13:00 PM': the hour must be between 1 and 12 with AM or PM, \
+                 got 13"
+                    .into()
+            )
+        );
+        assert!(parse("0:30 AM").is_err());
+        assert!(parse("PM").is_err());
+    }
+
+    #[test]
+    fn test_group_separator_option() {
+        let grouped = |separator| DateTimeParseOptions {
+            group_separator: Some(separator),
+            ..Default::default()
+        };
+        let parse = |value, field, options: &DateTimeParseOptions| {
+            Parser::parse_interval_string_with_options(value, field, options)
+        };
+        let day = &DateTimeField::Day;
+        assert_eq!(
+            parse("1_000 days", day, &grouped('_')).unwrap().day,
+            Some(1000)
+        );
+        assert_eq!(
+            parse("1,000 seconds", day, &grouped(',')).unwrap().second,
+            Some(1000)
+        );
+        assert_eq!(
+            parse("1_000_000", &DateTimeField::Second, &grouped('_'))
+                .unwrap()
+                .second,
+            Some(1_000_000)
+        );
+        assert_eq!(
+            parse("1,000 days, 2 hours", day, &grouped(',')).unwrap(),
+            parse("1000 days 2 hours", day, &Default::default()).unwrap()
+        );
+        // only a separator between digits is removed
+        assert!(parse("_1000", day, &grouped('_')).is_err());
+        assert!(parse("1000_", day, &grouped('_')).is_err());
+        assert!(parse("1__000", day, &grouped('_')).is_err());
+        // between groups of three digits
+        assert_eq!(
+            parse("1,5", day, &grouped(',')).unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '1,5': the group separator ',' at offset 1 must be between \
+                 groups of three digits"
+                    .into()
+            )
+        );
+        assert!(parse("1,5 seconds", day, &grouped(',')).is_err());
+        assert!(parse("12,3456", day, &grouped(',')).is_err());
+        assert!(parse("1234,567", day, &grouped(',')).is_err());
+        assert!(parse("1_00_000", day, &grouped('_')).is_err());
+        assert!(parse("1,2345", day, &grouped(',')).is_err());
+        assert!(parse("12,345,6", day, &grouped(',')).is_err());
+        assert_eq!(
+            parse("1,234,567", day, &grouped(',')).unwrap().day,
+            Some(1_234_567)
+        );
+        assert_eq!(
+            parse("12,345,678 seconds", day, &grouped(','))
+                .unwrap()
+                .second,
+            Some(12_345_678)
+        );
+        // and only when it is asked for
+        assert!(parse("1_000 days", day, &Default::default()).is_err());
+        assert!(parse("1,000 seconds", day, &Default::default()).is_err());
+
+        assert_eq!(
+            parse("1", day, &grouped('\'')).unwrap_err(),
+            ParserError::ParserError("the group separator must be '_' or ',', got '\\''".into())
+        );
+        let ambiguous = DateTimeParseOptions {
+            decimal_comma: true,
+            ..grouped(',')
+        };
+        assert_eq!(
+            parse("1,000", day, &ambiguous).unwrap_err(),
+            ParserError::ParserError(
+                "',' can't be both the group separator and the decimal point".into()
+            )
+        );
+        let decimal_comma = DateTimeParseOptions {
+            decimal_comma: true,
+            ..grouped('_')
+        };
+        assert_eq!(
+            parse("1_000,5", &DateTimeField::Second, &decimal_comma)
+                .unwrap()
+                .nano,
+            Some(500_000_000)
+        );
+    }
+
+    #[test]
+    fn test_decimal_comma() {
+        let options = DateTimeParseOptions {
+            decimal_comma: true,
+            ..Default::default()
+        };
+        let parse =
+            |value, field| Parser::parse_interval_string_with_options(value, &field, &options);
+        let one_and_a_half = ParsedDateTime {
+            second: Some(1),
+            nano: Some(500_000_000),
+            nano_digits: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(
+            parse("1,5 seconds", DateTimeField::Second).unwrap(),
+            one_and_a_half
+        );
+        assert_eq!(parse("1,5", DateTimeField::Second).unwrap(), one_and_a_half);
+        assert_eq!(
+            parse("1:2,5", DateTimeField::Minute).unwrap(),
+            Parser::parse_interval_string("1:2.5", &DateTimeField::Minute).unwrap()
+        );
+        // the comma is no longer a separator
+        assert_eq!(
+            parse("1 day, 2 hours", DateTimeField::Day).unwrap_err(),
+            ParserError::TokenizerError(
+                "Invalid interval '1 day, 2 hours': the ',' at offset 5 can't separate the \
+                 parts, the comma is the decimal separator in this mode"
+                    .into()
+            )
+        );
+        assert_eq!(
+            parse("2 hours, 30 minutes", DateTimeField::Hour).unwrap_err(),
+            ParserError::TokenizerError(
+                "Invalid interval '2 hours, 30 minutes': the ',' at offset 7 can't separate the \
+                 parts, the comma is the decimal separator in this mode"
+                    .into()
+            )
+        );
+        assert!(parse("1,day", DateTimeField::Day).is_err());
+
+        // by default a comma is only allowed between compound parts
+        let parse = |value, field| Parser::parse_interval_string(value, &field);
+        assert!(parse("1,5", DateTimeField::Second).is_err());
+        assert_eq!(
+            parse("1,5 seconds", DateTimeField::Second).unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval part in '1,5 seconds': expected a unit after 1 but found the \
+                 number 5"
+                    .into()
+            )
+        );
+        assert_eq!(
+            parse("1 day 2", DateTimeField::Day).unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval part in '1 day 2': expected a unit after 2 but found the end \
+                 of the value"
+                    .into()
+            )
+        );
+        assert_eq!(
+            parse("1 day, 2 hours", DateTimeField::Day).unwrap(),
+            parse("1 day 2 hours", DateTimeField::Day).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_leading_plus() {
+        assert_eq!(
+            Parser::parse_unqualified_interval_string("+5 days").unwrap(),
+            Parser::parse_unqualified_interval_string("5 days").unwrap()
+        );
+        assert_eq!(
+            Parser::parse_interval_string("+1-2", &DateTimeField::Year).unwrap(),
+            ParsedDateTime {
+                year: Some(1),
+                month: Some(2),
+                ..Default::default()
+            }
+        );
+        // only a leading plus is a sign
+        assert!(Parser::parse_interval_string("1+2", &DateTimeField::Year).is_err());
+        assert!(Parser::parse_interval_string("+-1", &DateTimeField::Year).is_err());
+        assert!(Parser::parse_interval_string("1 day +", &DateTimeField::Year).is_err());
+    }
+
+    #[test]
+    fn test_fractional_days() {
+        let parse = |value| Parser::parse_interval_string(value, &DateTimeField::Day).unwrap();
+        assert_eq!(
+            parse("-0.5"),
+            ParsedDateTime {
+                is_positive: false,
+                day: Some(0),
+                hour: Some(12),
+                minute: Some(0),
+                second: Some(0),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            parse("-1.25"),
+            ParsedDateTime {
+                is_positive: false,
+                day: Some(1),
+                hour: Some(6),
+                minute: Some(0),
+                second: Some(0),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            parse("1.000001"),
+            ParsedDateTime {
+                day: Some(1),
+                hour: Some(0),
+                minute: Some(0),
+                second: Some(0),
+                nano: Some(86_400_000),
+                ..Default::default()
+            }
+        );
+        // a fraction can only be on its own
+        assert!(Parser::parse_interval_string("1.5 2", &DateTimeField::Day).is_err());
+    }
+
+    #[test]
+    fn test_weeks() {
+        let parse = |value| Parser::parse_interval_string(value, &DateTimeField::Week);
+        assert_eq!(
+            parse("2").unwrap(),
+            ParsedDateTime {
+                day: Some(14),
+                ..Default::default()
+            }
+        );
+        // a fraction of a week is days, and the fraction of a day is time
+        assert_eq!(
+            parse("1.5").unwrap(),
+            ParsedDateTime {
+                day: Some(10),
+                hour: Some(12),
+                minute: Some(0),
+                second: Some(0),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            parse("-0.1").unwrap(),
+            ParsedDateTime {
+                is_positive: false,
+                day: Some(0),
+                hour: Some(16),
+                minute: Some(48),
+                second: Some(0),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            parse("1 2").unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '1 2': a WEEK value must be a single number".to_string()
+            )
+        );
+        assert!(parse(&u64::MAX.to_string()).is_err());
+    }
+
+    #[test]
+    fn test_compound_weeks_and_quarters() {
+        let parse = |value: &str| Parser::parse_unqualified_interval_string(value);
+        // weeks are seven days, and quarters three months
+        assert_eq!(
+            parse("2 weeks").unwrap(),
+            ParsedDateTime {
+                day: Some(14),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            parse("1 quarter 1 month 1 week 1 day").unwrap(),
+            ParsedDateTime {
+                month: Some(4),
+                day: Some(8),
+                ..Default::default()
+            }
+        );
+        assert_eq!(parse("1 week -2 days").unwrap(), parse("5 days").unwrap());
+        assert_eq!(
+            parse("-1 qtr").unwrap(),
+            ParsedDateTime {
+                is_positive: false,
+                month: Some(3),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            parse("1 week 2 weeks").unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '1 week 2 weeks': WEEK specified more than once".into()
+            )
+        );
+        assert!(parse(&format!("{} weeks", u64::MAX)).is_err());
+        assert!(parse("1.5 weeks").is_err());
+        // and are within the qualifiers of the days and months
+        assert!(Parser::parse_interval_string("2 weeks 3 hours", &DateTimeField::Day).is_ok());
+        assert!(Parser::parse_interval_string("1 quarter", &DateTimeField::Year).is_ok());
+        assert!(Parser::parse_interval_string("2 weeks", &DateTimeField::Hour).is_err());
+    }
+
+    #[test]
+    fn test_quarters() {
+        let parse = |value| Parser::parse_interval_string(value, &DateTimeField::Quarter);
+        assert_eq!(
+            parse("2").unwrap(),
+            ParsedDateTime {
+                month: Some(6),
+                ..Default::default()
+            }
+        );
+        // the fraction of a month becomes 30-day days, like postgres
+        assert_eq!(
+            parse("1.5").unwrap(),
+            ParsedDateTime {
+                month: Some(4),
+                day: Some(15),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            parse("-0.1").unwrap(),
+            ParsedDateTime {
+                is_positive: false,
+                month: Some(0),
+                day: Some(9),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            parse("1-2").unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '1-2': a QUARTER value must be a single number".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_date_time_separator() {
+        let spaced = Parser::parse_interval_string("1-2-3 4:5:6", &DateTimeField::Year).unwrap();
+        for value in &["1-2-3T4:5:6", "1-2-3t4:5:6"] {
+            assert_eq!(
+                Parser::parse_interval_string(value, &DateTimeField::Year).unwrap(),
+                spaced
+            );
+        }
+        assert_eq!(
+            Parser::parse_interval_string("3T4:5", &DateTimeField::Day).unwrap(),
+            Parser::parse_interval_string("3 4:5", &DateTimeField::Day).unwrap()
+        );
+        // a `T` is still only allowed where a space would be
+        assert!(Parser::parse_interval_string("1T2-3", &DateTimeField::Year).is_err());
+        // and doesn't get in the way of ISO 8601 durations
+        assert_eq!(
+            Parser::parse_unqualified_interval_string("PT1M").unwrap(),
+            ParsedDateTime {
+                minute: Some(1),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_interval_field_limits() {
+        let parse = |value: &str, field| Parser::parse_interval_string(value, &field);
+        let max_days = u64::MAX / (24 * 60 * 60);
+        let max_hours = u64::MAX / (60 * 60);
+        assert_eq!(
+            parse(&max_days.to_string(), DateTimeField::Day)
+                .unwrap()
+                .day,
+            Some(max_days)
+        );
+        assert_eq!(
+            parse(&format!("{} days", max_hours), DateTimeField::Day).unwrap_err(),
+            ParserError::ParserError(format!(
+                "Invalid interval '{} days': interval field out of range, the days, hours, \
+                 minutes and seconds add up to more than {} seconds",
+                max_hours,
+                u64::MAX
+            ))
+        );
+        assert!(parse(&(max_days + 1).to_string(), DateTimeField::Day).is_err());
+        assert_eq!(
+            parse(&max_hours.to_string(), DateTimeField::Hour)
+                .unwrap()
+                .hour,
+            Some(max_hours)
+        );
+        assert!(parse(&(max_hours + 1).to_string(), DateTimeField::Hour).is_err());
+        // the fields add up
+        assert!(parse(&format!("{} 7:00:15", max_days), DateTimeField::Day).is_ok());
+        assert!(parse(&format!("{} 7:00:16", max_days), DateTimeField::Day).is_err());
+
+        assert_eq!(
+            parse(&u64::MAX.to_string(), DateTimeField::Second)
+                .unwrap()
+                .second,
+            Some(u64::MAX)
+        );
+        assert_eq!(
+            parse("18446744073709551616", DateTimeField::Second).unwrap_err(),
+            ParserError::ParserError(
+                "interval field out of range: 18446744073709551616 at index 0 is more than \
+                 18446744073709551615"
+                    .into()
+            )
+        );
+        assert_eq!(
+            parse("99999999999999999999 days", DateTimeField::Day).unwrap_err(),
+            ParserError::ParserError(
+                "interval field out of range: 99999999999999999999 at index 0 is more than \
+                 18446744073709551615"
+                    .into()
+            )
+        );
+
+        let max_years = i64::MAX as u64 / 12;
+        assert!(parse(&format!("{}-7", max_years), DateTimeField::Year).is_ok());
+        assert!(parse(&format!("{}-8", max_years), DateTimeField::Year).is_err());
+    }
+
+    #[test]
+    fn test_compound_unit_abbreviations() {
+        let parse = |value| Parser::parse_unqualified_interval_string(value).unwrap();
+        for (abbreviated, full) in &[
+            ("1 yr", "1 year"),
+            ("2 yrs", "2 years"),
+            ("1 mon", "1 month"),
+            ("2 mons", "2 months"),
+            ("1 hr", "1 hour"),
+            ("2 hrs", "2 hours"),
+            ("1 min", "1 minute"),
+            ("2 mins", "2 minutes"),
+            ("1 sec", "1 second"),
+            ("2.5 secs", "2.5 seconds"),
+            ("1 Hr 30 MINS", "1 hour 30 minutes"),
+        ] {
+            assert_eq!(parse(abbreviated), parse(full), "parsing {}", abbreviated);
+        }
+        assert_eq!(
+            Parser::parse_unqualified_interval_string("3 hrz").unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '3 hrz': unknown unit 'hrz' at offset 2, did you mean 'hr'?"
+                    .into()
+            )
+        );
+    }
+
+    #[test]
+    fn test_compound_separators() {
+        let parse = |value| Parser::parse_unqualified_interval_string(value);
+        assert_eq!(
+            parse("1 hour and 30 minutes").unwrap(),
+            parse("1 hour 30 minutes").unwrap()
+        );
+        assert_eq!(
+            parse("1 day, 2 hours").unwrap(),
+            parse("1 day 2 hours").unwrap()
+        );
+        assert_eq!(
+            parse("1 year, 2 months AND 3 days").unwrap(),
+            parse("1 year 2 months 3 days").unwrap()
+        );
+        assert_eq!(
+            parse("and").unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval 'and': the 'and' at offset 0 must join two parts".into()
+            )
+        );
+        for value in &[
+            "1 hour and",
+            "and 1 hour",
+            "1 hour and and 2 minutes",
+            "1 and 2 hours",
+        ] {
+            assert!(parse(value).is_err(), "parsing {}", value);
+        }
+    }
+
+    #[test]
+    fn test_sign_after_space() {
+        let parse = |value, field| Parser::parse_interval_string(value, &field);
+        // with unit words, the sign is for the part it is in front of, and
+        // may be apart from its number
+        for value in &["1 day - 2 hours", "1 day -2 hours"] {
+            let pdt = parse(value, DateTimeField::Day).unwrap();
+            assert_eq!(
+                (pdt.days().unwrap(), pdt.signed_hours().unwrap()),
+                (1, -2),
+                "parsing {:?}",
+                value
+            );
+        }
+        assert_eq!(
+            parse("1 day - 2 hours", DateTimeField::Day).unwrap(),
+            parse("1 day -2 hours", DateTimeField::Day).unwrap()
+        );
+        assert_eq!(
+            parse("-1 day - 2 hours", DateTimeField::Day).unwrap(),
+            parse("-1 day -2 hours", DateTimeField::Day).unwrap()
+        );
+        let half_hour = ParsedDateTime {
+            hour: Some(0),
+            minute: Some(30),
+            ..Default::default()
+        };
+        assert_eq!(
+            parse("1 hour - 30 minutes", DateTimeField::Hour).unwrap(),
+            half_hour
+        );
+        assert_eq!(
+            parse("1 hour -30 minutes", DateTimeField::Hour).unwrap(),
+            half_hour
+        );
+
+        // a positional value only has one sign, at its start
+        assert_eq!(
+            parse("1 -2:00", DateTimeField::Day).unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '1 -2:00': the '-' at offset 2 follows a space, but only the \
+                 whole value may have a sign, before its leading field"
+                    .into()
+            )
+        );
+        for value in &["1 - 2:00", "1 +2:00", "1-2 -3"] {
+            let err = Parser::parse_interval_string_with_kind(
+                value,
+                &DateTimeField::Year,
+                &DateTimeParseOptions::default(),
+            )
+            .unwrap_err();
+            assert_eq!(err.kind, ErrorKind::UnexpectedChar, "parsing {:?}", value);
+        }
+    }
+
+    #[test]
+    fn test_compound_part_signs() {
+        let parse = |value| Parser::parse_unqualified_interval_string(value);
+        let pdt = |value, field| Parser::parse_interval_string(value, &field).unwrap();
+        // a sign is only for the part it is in front of
+        let signed = |value| {
+            let pdt = parse(value).unwrap();
+            (
+                pdt.total_months().unwrap(),
+                pdt.days().unwrap(),
+                pdt.signed_hours().unwrap(),
+            )
+        };
+        assert_eq!(signed("2 days -3 hours"), (0, 2, -3));
+        assert_eq!(signed("-1 mon +15 days"), (-1, 15, 0));
+        assert_eq!(signed("-1 day 2 hours"), (0, -1, 2));
+        assert_eq!(signed("1 year -1 day"), (12, -1, 0));
+        assert_eq!(
+            parse("-1 day -2 hours").unwrap(),
+            pdt("-1 2", DateTimeField::Day)
+        );
+        assert_eq!(
+            parse("2 days -3 hours").unwrap(),
+            ParsedDateTime {
+                day: Some(2),
+                hour: Some(3),
+                part_signs: Some(PartSigns {
+                    months: true,
+                    days: true,
+                    time: false,
+                }),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            parse("-1 day 2 hours").unwrap(),
+            (pdt("-1", DateTimeField::Day) + pdt("2", DateTimeField::Hour)).unwrap()
+        );
+        let folded = [
+            ("1 hour -30 minutes", "0:30", DateTimeField::Hour),
+            ("-1 hour +30 minutes", "-0:30", DateTimeField::Hour),
+            ("1 year -2 months", "0-10", DateTimeField::Year),
+            (
+                "2 hours -30 minutes 10 seconds",
+                "1:30:10",
+                DateTimeField::Hour,
+            ),
+            ("1 minute -0.5 seconds", "0:59.5", DateTimeField::Minute),
+            ("1 day 1 hour -30 minutes", "1 0:30", DateTimeField::Day),
+        ];
+        for (value, expected, field) in &folded {
+            let parsed = parse(value).unwrap();
+            assert!(
+                parsed.semantically_eq(&pdt(expected, field.clone())),
+                "parsing {}: {:?}",
+                value,
+                parsed
+            );
+        }
+        // a fraction is spread with the sign of its own part
+        assert_eq!(signed("-1.5 days 1 hour"), (0, -1, -11));
+        assert_eq!(
+            parse("1 day -").unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval part in '1 day -': expected a number after the sign".into()
+            )
+        );
+        assert!(parse("1 day --2 hours").is_err());
+    }
+
+    #[test]
+    fn test_units_within_qualifier() {
+        use DateTimeField::*;
+        for (value, field) in &[
+            ("1 year 2 months", Year),
+            ("2 months", Year),
+            ("1 day 2 hours", Day),
+            ("30 minutes", Hour),
+            ("P1DT2H", Day),
+            ("PT1M", Minute),
+        ] {
+            assert!(
+                Parser::parse_interval_string(value, field).is_ok(),
+                "parsing {:?} {}",
+                value,
+                field
+            );
+        }
+        for (value, field) in &[
+            ("1 day", Hour),
+            ("1 day 2 hours", Year),
+            ("1 month", Day),
+            ("P1D", Hour),
+            ("P1Y", Month),
+        ] {
+            let err = Parser::parse_interval_string_with_kind(
+                value,
+                field,
+                &DateTimeParseOptions::default(),
+            )
+            .unwrap_err();
+            assert_eq!(err.kind, ErrorKind::InvalidLayout, "parsing {:?}", value);
+        }
+        assert_eq!(
+            Parser::parse_interval_string("1 day", &Hour).unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '1 day': the unit in the value, DAY, is not within HOUR TO \
+                 SECOND, the range of the qualifier"
+                    .into()
+            )
+        );
+
+        // the last field of the qualifier ends the range
+        let to_hour = DateTimeParseOptions {
+            last_field: Some(Hour),
+            ..Default::default()
+        };
+        assert!(
+            Parser::parse_interval_string_with_options("1 day 2 hours", &Day, &to_hour).is_ok()
+        );
+        assert!(
+            Parser::parse_interval_string_with_options("1 day 2 minutes", &Day, &to_hour).is_err()
+        );
+
+        // without a qualifier, any unit goes
+        assert_eq!(
+            Parser::parse_unqualified_interval_string("1 year 2 days 3 seconds").unwrap(),
+            ParsedDateTime {
+                year: Some(1),
+                day: Some(2),
+                second: Some(3),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            Parser::parse_unqualified_interval_string("90").unwrap(),
+            Parser::parse_interval_string("90", &Second).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_compound_interval() {
+        let parse = |value| Parser::parse_unqualified_interval_string(value);
+        assert_eq!(
+            parse("day").unwrap(),
+            ParsedDateTime {
+                day: Some(1),
+                ..Default::default()
+            }
+        );
+        assert_eq!(parse("Days").unwrap(), parse("day").unwrap());
+        assert_eq!(
+            parse("2 days").unwrap(),
+            ParsedDateTime {
+                day: Some(2),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            parse("-1 day 2 hours 3.5 seconds").unwrap(),
+            ParsedDateTime {
+                is_positive: false,
+                day: Some(1),
+                hour: Some(2),
+                second: Some(3),
+                nano: Some(500_000_000),
+                nano_digits: Some(1),
+                part_signs: Some(PartSigns {
+                    months: false,
+                    days: false,
+                    time: true,
+                }),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            parse("1 year month").unwrap(),
+            ParsedDateTime {
+                year: Some(1),
+                month: Some(1),
+                ..Default::default()
+            }
+        );
+
+        assert_eq!(
+            parse("tomorrow").unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval 'tomorrow': the special value 'tomorrow' is not supported".into()
+            )
+        );
+        assert_eq!(
+            parse("1 fortnight").unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '1 fortnight': unknown unit 'fortnight' at offset 2, expected \
+                 one of year, quarter, month, week, day, hour, minute or second, or their \
+                 plurals or abbreviations"
+                    .into()
+            )
+        );
+        assert_eq!(
+            parse("2 huors").unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '2 huors': unknown unit 'huors' at offset 2, did you mean \
+                 'hours'?"
+                    .into()
+            )
+        );
+        assert!(parse("1 day day").is_err());
+        assert!(parse("1 2 days").is_err());
+        assert!(parse("1.5 years").is_err());
+    }
+
+    /// The splitter as it was before it was made a single pass, which
+    /// searched the value again for each kind of cut
+    fn split_timestamp_string_by_search(value: &str) -> (&str, Option<&str>) {
+        fn split_at(value: &str, cut: usize) -> (&str, Option<&str>) {
+            let (first, second) = value.split_at(cut);
+            let second = second.trim();
+            (first.trim(), Some(second).filter(|tz| !tz.is_empty()))
+        }
+
+        // First we need to see if the string contains " +" or " -" because
+        // timestamps can come in a format YYYY-MM-DD {+|-}<tz> (where the
+        // timezone string can have colons)
+        let cut = value.find(" +").or_else(|| value.find(" -"));
+
+        if let Some(cut) = cut {
+            return split_at(value, cut);
+        }
+
+        // If we have a hh:mm:dd component, we need to go past that to see if we can find a tz
+        let colon = value.find(':');
+
+        if let Some(colon) = colon {
+            let substring = value.get(colon..);
+            if let Some(substring) = substring {
+                let tz = substring.find(|c: char| {
+                    (c == '-') || (c == '+') || (c == ' ') || c.is_ascii_alphabetic()
+                });
+
+                if let Some(tz) = tz {
+                    return split_at(value, colon + tz);
+                }
+            }
+
+            return (value.trim(), None);
+        } else {
+            // We don't have a time, so the only formats available are
+            // YYY-mm-dd<tz> or YYYY-MM-dd <tz>
+            // Numeric offset timezones need to be separated from the ymd by a space
+            let cut = value.find(|c: char| (c == ' ') || c.is_ascii_alphabetic());
+
+            if let Some(cut) = cut {
+                return split_at(value, cut);
+            }
+
+            return (value.trim(), None);
+        }
+    }
+
+    #[test]
+    fn test_split_timestamp_string_single_pass() {
+        let values = [
+            "1969-06-01 10:10:10.410 UTC",
+            "1969-06-01 10:10:10.410+4:00",
+            "1969-06-01 10:10:10.410-4:00",
+            "1969-06-01 10:10:10.410 +4:00",
+            "1969-06-01 10:10:10.410 -4:00",
+            "1969-06-01 10:10:10.410+4 :00",
+            "1969-06-01 10:10-4:00",
+            "1969-06-01 UTC",
+            "1969-06-01 +4",
+            "1969-06-01 -4",
+            "1969-06-01Z",
+            "1969-06-01",
+            "   1969-06-01 10:10:10.413   ",
+            "1969-06-01    +4  ",
+            // a `+` after a space is used even when a `-` after one comes first
+            "1969-06-01 -4 +5",
+            "1969-06-01 10:10 -4 +5",
+            "10:10:10 America/New_York",
+            "1 day",
+            "-1 -2",
+            " +1",
+            "+1",
+            ":",
+            "",
+            "  ",
+            "1969-06-01 10:10:10é",
+            "é 10:10",
+        ];
+        for value in values.iter() {
+            assert_eq!(
+                split_timestamp_string(value),
+                split_timestamp_string_by_search(value),
+                "{:?}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_split_timestamp_string() {
+        let test_cases = [
+            (
+                "1969-06-01 10:10:10.410 UTC",
+                "1969-06-01 10:10:10.410",
+                "UTC",
+            ),
+            (
+                "1969-06-01 10:10:10.410+4:00",
+                "1969-06-01 10:10:10.410",
+                "+4:00",
+            ),
+            (
+                "1969-06-01 10:10:10.410-4:00",
+                "1969-06-01 10:10:10.410",
+                "-4:00",
+            ),
+            ("1969-06-01 10:10:10.410", "1969-06-01 10:10:10.410", ""),
+            ("1969-06-01 10:10:10.410+4", "1969-06-01 10:10:10.410", "+4"),
+            ("1969-06-01 10:10:10.410-4", "1969-06-01 10:10:10.410", "-4"),
+            ("1969-06-01 10:10:10+4:00", "1969-06-01 10:10:10", "+4:00"),
+            ("1969-06-01 10:10:10-4:00", "1969-06-01 10:10:10", "-4:00"),
+            ("1969-06-01 10:10:10 UTC", "1969-06-01 10:10:10", "UTC"),
+            ("1969-06-01 10:10:10", "1969-06-01 10:10:10", ""),
+            ("1969-06-01 10:10+4:00", "1969-06-01 10:10", "+4:00"),
+            ("1969-06-01 10:10-4:00", "1969-06-01 10:10", "-4:00"),
+            ("1969-06-01 10:10 UTC", "1969-06-01 10:10", "UTC"),
+            ("1969-06-01 10:10", "1969-06-01 10:10", ""),
+            ("1969-06-01 UTC", "1969-06-01", "UTC"),
+            ("1969-06-01 +4:00", "1969-06-01", "+4:00"),
+            ("1969-06-01 -4:00", "1969-06-01", "-4:00"),
+            ("1969-06-01 +4", "1969-06-01", "+4"),
+            ("1969-06-01 -4", "1969-06-01", "-4"),
+            ("1969-06-01", "1969-06-01", ""),
+            ("1969-06-01 10:10:10.410Z", "1969-06-01 10:10:10.410", "Z"),
+            ("1969-06-01 10:10:10.410z", "1969-06-01 10:10:10.410", "z"),
+            ("1969-06-01Z", "1969-06-01", "Z"),
+            ("1969-06-01z", "1969-06-01", "z"),
+            ("1969-06-01 10:10:10.410   ", "1969-06-01 10:10:10.410", ""),
+            (
+                "1969-06-01     10:10:10.410   ",
+                "1969-06-01     10:10:10.410",
+                "",
+            ),
+            ("   1969-06-01 10:10:10.412", "1969-06-01 10:10:10.412", ""),
+            (
+                "   1969-06-01 10:10:10.413   ",
+                "1969-06-01 10:10:10.413",
+                "",
+            ),
+            (
+                "1969-06-01 10:10:10.410 +4:00",
+                "1969-06-01 10:10:10.410",
+                "+4:00",
+            ),
+            (
+                "1969-06-01 10:10:10.410+4 :00",
+                "1969-06-01 10:10:10.410",
+                "+4 :00",
+            ),
+            (
+                "1969-06-01 10:10:10.410      +4:00",
+                "1969-06-01 10:10:10.410",
+                "+4:00",
+            ),
+            (
+                "1969-06-01 10:10:10.410+4:00     ",
+                "1969-06-01 10:10:10.410",
+                "+4:00",
+            ),
+            (
+                "1969-06-01 10:10:10.410  Z  ",
+                "1969-06-01 10:10:10.410",
+                "Z",
+            ),
+            ("1969-06-01    +4  ", "1969-06-01", "+4"),
+            ("1969-06-01   Z   ", "1969-06-01", "Z"),
+        ];
+
+        for test in test_cases.iter() {
+            let (ts, tz) = split_timestamp_string(test.0);
+
+            assert_eq!(ts, test.1);
+            let expected_tz = Some(test.2).filter(|tz| !tz.is_empty());
+            assert_eq!(tz, expected_tz, "timezone of {:?}", test.0);
+        }
+    }
+
+    #[test]
+    fn test_parse_timestamp_string_timezone() {
+        let tz = |value, parse_timezone| {
+            Parser::parse_timestamp_string(value, parse_timezone)
+                .unwrap()
+                .timezone_offset_second
+        };
+        assert_eq!(tz("2020-01-01 12:00:00+05:30", true), Some(19_800));
+        assert_eq!(tz("2020-01-01 12:00:00+05:30", false), None);
+        assert_eq!(tz("2020-01-01 12:00:00", true), None);
+        assert_eq!(tz("2020-01-01   ", true), None);
+
+        // a sign after whitespace starts the timezone, as does one right
+        // after the time
+        assert_eq!(tz("2020-01-01 12:00:00 +05:30", true), Some(19_800));
+        assert_eq!(tz("2020-01-01 12:00:00 -05:30", true), Some(-19_800));
+        assert_eq!(tz("2020-01-01 12:00:00-05:30", true), Some(-19_800));
+
+        // whitespace may follow the offset, but not split it
+        assert_eq!(tz("2020-01-01 12:00:00+05:30 ", true), Some(19_800));
+        assert_eq!(tz("2020-01-01 12:00:00 +05:30\t", true), Some(19_800));
+        assert_eq!(
+            Parser::parse_timestamp_string("2020-01-01 12:00:00+05 :30", true),
+            Err(ParserError::ParserError(
+                "Error parsing timezone string (+05 :30): expected an offset like +HH:MM, +HHMM \
+                 or +HH, or Z"
+                    .into()
+            ))
+        );
+        let pdt = Parser::parse_timestamp_string("2020-01-01 12:00:00 +05:30", true).unwrap();
+        assert_eq!(
+            (pdt.hour, pdt.minute, pdt.second),
+            (Some(12), Some(0), Some(0))
+        );
+    }
+
+    #[test]
+    fn test_split_timezone_after_space() {
+        for value in &[
+            "2020-01-01 12:00:00+05:30",
+            "2020-01-01 12:00:00 +05:30",
+            "2020-01-01 12:00:00  +05:30",
+        ] {
+            assert_eq!(
+                split_timestamp_string(value),
+                ("2020-01-01 12:00:00", Some("+05:30")),
+                "{:?}",
+                value
+            );
+        }
+        assert_eq!(
+            split_timestamp_string("2020-01-01 12:00:00 -05:30"),
+            ("2020-01-01 12:00:00", Some("-05:30"))
+        );
+        // without a time, the offset needs the space
+        assert_eq!(
+            split_timestamp_string("2020-01-01 +05:30"),
+            ("2020-01-01", Some("+05:30"))
+        );
+        assert_eq!(split_timestamp_string("2020-01-01"), ("2020-01-01", None));
+    }
+
+    #[test]
+    fn test_datetime_special_keywords() {
+        for (value, special) in &[
+            ("now", DateTimeSpecial::Now),
+            ("today", DateTimeSpecial::Today),
+            ("Tomorrow", DateTimeSpecial::Tomorrow),
+            (" YESTERDAY ", DateTimeSpecial::Yesterday),
+        ] {
+            assert_eq!(
+                Parser::parse_timestamp_or_special(value, true),
+                Ok(DateTimeOrSpecial::Special(*special))
+            );
+            assert!(Parser::parse_timestamp_string(value, true).is_err());
+            let err = Parser::parse_interval_string_with_kind(
+                value,
+                &DateTimeField::Second,
+                &DateTimeParseOptions::default(),
+            )
+            .unwrap_err();
+            assert_eq!(err.kind, ErrorKind::Unsupported);
+            assert_eq!(
+                err.error,
+                ParserError::ParserError(format!(
+                    "Invalid interval '{}': the special value '{}' is not supported",
+                    value, special
+                ))
+            );
+        }
+        assert_eq!(DateTimeSpecial::Yesterday.day_offset(), Some(-1));
+        assert_eq!(DateTimeSpecial::Now.day_offset(), None);
+
+        assert_eq!(
+            Parser::parse_timestamp_or_special("2020-01-02 03:04:05", false),
+            Ok(DateTimeOrSpecial::DateTime(
+                Parser::parse_timestamp_string("2020-01-02 03:04:05", false).unwrap()
+            ))
+        );
+        assert!(Parser::parse_timestamp_or_special("nowish", false).is_err());
+    }
+
+    #[test]
+    fn test_timezone_not_permitted() {
+        for (value, field) in &[
+            ("12:00:00+05:30", DateTimeField::Hour),
+            ("1 12:00+05", DateTimeField::Day),
+            ("2020-01-01 12:00:00+05:30", DateTimeField::Year),
+        ] {
+            let err = Parser::parse_interval_string_with_kind(
+                value,
+                field,
+                &DateTimeParseOptions::default(),
+            )
+            .unwrap_err();
+            assert_eq!(err.kind, ErrorKind::Unsupported, "{}", value);
+            assert_eq!(
+                err.error,
+                ParserError::ParserError(format!(
+                    "Invalid interval '{}': a timezone offset is not permitted here, but the '+' \
+                     after the time starts one",
+                    value
+                ))
+            );
+        }
+        // a character that can't be in any value is still an invalid character
+        assert_eq!(
+            Parser::parse_interval_string("12:00:00!", &DateTimeField::Hour),
+            Err(ParserError::TokenizerError(
+                "Invalid character at offset 8 in 12:00:00!: '!'".into()
+            ))
+        );
+        // a timestamp that is parsed without its timezone still ignores it
+        let pdt = Parser::parse_timestamp_string("2020-01-01 12:00:00+05:30", false).unwrap();
+        assert_eq!((pdt.hour, pdt.timezone_offset_second), (Some(12), None));
+    }
+
+    #[test]
+    fn test_parse_timezone_offset_second() {
+        let test_cases = [
+            ("+0:00", 0),
+            ("-0:00", 0),
+            ("+0:000000", 0),
+            ("+000000:00", 0),
+            ("+000000:000000", 0),
+            ("+0", 0),
+            ("+00", 0),
+            ("+000", 0),
+            ("+0000", 0),
+            ("+00000000", 0),
+            ("+0000001:000000", 3600),
+            ("+0000000:000001", 60),
+            ("+0000001:000001", 3660),
+            ("+4:00", 14400),
+            ("-4:00", -14400),
+            ("+2:30", 9000),
+            ("-5:15", -18900),
+            ("+0:20", 1200),
+            ("-0:20", -1200),
+            ("+5", 18000),
+            ("-5", -18000),
+            ("+05", 18000),
+            ("-05", -18000),
+            ("+500", 18000),
+            ("-500", -18000),
+            ("+530", 19800),
+            ("-530", -19800),
+            ("+050", 3000),
+            ("-050", -3000),
+            ("+15", 54000),
+            ("-15", -54000),
+            ("+1515", 54900),
+            ("+015", 900),
+            ("-015", -900),
+            ("+0015", 900),
+            ("-0015", -900),
+            ("+00015", 900),
+            ("-00015", -900),
+            ("+005", 300),
+            ("-005", -300),
+            ("+0000005", 300),
+            ("+00000100", 3600),
+            ("Z", 0),
+            ("z", 0),
+            ("UTC", 0),
+        ];
+
+        for test in test_cases.iter() {
+            match parse_timezone_offset_second(test.0) {
+                Ok(tz_offset) => {
+                    let expected: i64 = test.1 as i64;
+
+                    println!("{} {}", expected, tz_offset);
+                    assert_eq!(tz_offset, expected);
+                }
+                Err(e) => panic!(
+                    "Test failed when expected to pass test case: {} error: {}",
+                    test.0, e
+                ),
+            }
+        }
+
+        let failure_test_cases = [
+            "+25:00", "+120:00", "+0:61", "+0:500", " 12:30", "+-12:30", "+2525", "+2561",
+            "+255900", "+25", "+5::30", "+5:30:", "+5:30:16", "+5:", "++5:00", "--5:00", " UTC",
+            "a", "zzz", "ZZZ", "ZZ Top", " +", " -", " ", "1", "12", "1234",
         ];
 
-        for test in failure_test_cases.iter() {
-            match parse_timezone_offset_second(test) {
-                Ok(t) => panic!("Test passed when expected to fail test case: {} parsed tz offset (seconds): {}", test, t),
-                Err(e) => println!("{}", e),
+        for test in failure_test_cases.iter() {
+            match parse_timezone_offset_second(test) {
+                Ok(t) => panic!("Test passed when expected to fail test case: {} parsed tz offset (seconds): {}", test, t),
+                Err(e) => println!("{}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_standalone_timezone_offset() {
+        assert_eq!(Parser::parse_timezone_offset("+05:30"), Ok(19_800));
+        assert_eq!(Parser::parse_timezone_offset("-08:00"), Ok(-28_800));
+        assert_eq!(
+            Parser::parse_timezone_offset("Europe/Paris"),
+            Err(ParserError::ParserError(
+                "Error parsing timezone string (Europe/Paris): named timezones are not \
+                 supported. Failed to parse Europe/Paris at token index 0"
+                    .to_string()
+            ))
+        );
+        assert_eq!(
+            Parser::parse_timezone_offset("+5:30:16"),
+            Err(ParserError::ParserError(
+                "Error parsing timezone string (+5:30:16): expected an offset like +HH:MM, \
+                 +HHMM or +HH, or Z"
+                    .to_string()
+            ))
+        );
+        assert_eq!(
+            Parser::parse_timezone_offset("+25:00"),
+            Err(ParserError::ParserError(
+                "Error parsing timezone string (+25:00): timezone hour invalid 25".to_string()
+            ))
+        );
+        assert!(Parser::parse_timezone_offset("+05;30").is_err());
+
+        for value in &["+05:30 ", "+05:30  ", "+05:30\t", "+0530 "] {
+            assert_eq!(
+                Parser::parse_timezone_offset(value),
+                Ok(19_800),
+                "{:?}",
+                value
+            );
+        }
+        assert_eq!(Parser::parse_timezone_offset("Z "), Ok(0));
+        for value in &["+05 :30", "+05: 30", "+05 30 "] {
+            assert_eq!(
+                Parser::parse_timezone_offset(value),
+                Err(ParserError::ParserError(format!(
+                    "Error parsing timezone string ({}): expected an offset like +HH:MM, +HHMM \
+                     or +HH, or Z",
+                    value
+                ))),
+                "{:?}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_timezone_minutes() {
+        assert_eq!(Parser::parse_timezone_minutes("-480"), Ok(-28_800));
+        assert_eq!(Parser::parse_timezone_minutes("+330"), Ok(19_800));
+        assert_eq!(Parser::parse_timezone_minutes("330"), Ok(19_800));
+        assert_eq!(Parser::parse_timezone_minutes("-0"), Ok(0));
+        assert_eq!(Parser::parse_timezone_minutes("840"), Ok(50_400));
+        for value in &["841", "-900", "99999999999999999999"] {
+            assert_eq!(
+                Parser::parse_timezone_minutes(value),
+                Err(ParserError::ParserError(format!(
+                    "Error parsing timezone string ({}): the offset must be at most 840 minutes \
+                     from UTC",
+                    value
+                )))
+            );
+        }
+        for value in &["", "-", "+-5", "5:30", "-08:00"] {
+            assert!(Parser::parse_timezone_minutes(value).is_err(), "{}", value);
+        }
+    }
+
+    #[test]
+    fn test_hour_only_timezone_offsets() {
+        for value in &["+5", "+05", "+5:00", "+05:00", "+0500"] {
+            assert_eq!(
+                Parser::parse_timezone_offset(value),
+                Ok(18_000),
+                "{}",
+                value
+            );
+        }
+        assert_eq!(Parser::parse_timezone_offset("-5"), Ok(-18_000));
+        for value in &["+5:", "-05:"] {
+            assert_eq!(
+                Parser::parse_timezone_offset(value),
+                Err(ParserError::ParserError(format!(
+                    "Error parsing timezone string ({}): missing minutes after the ':'",
+                    value
+                )))
+            );
+        }
+    }
+
+    #[test]
+    fn test_timezone_aliases() {
+        for name in &["utc", "UTC", "GMT", "gmt", "Zulu", "ZULU", "UT", "Z", "z"] {
+            assert_eq!(Parser::parse_timezone_offset(name), Ok(0), "{}", name);
+        }
+        assert_eq!(
+            Parser::parse_timestamp_string("2020-01-02 03:04:05 Gmt", true)
+                .unwrap()
+                .timezone_offset_second,
+            Some(0)
+        );
+        assert_eq!(
+            Parser::parse_timezone_offset("XYZ"),
+            Err(ParserError::ParserError(
+                "Error parsing timezone string (XYZ): named timezones are not supported. \
+                 Failed to parse XYZ at token index 0"
+                    .to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_second_decimal_point() {
+        let parse = |value| Parser::parse_interval_string(value, &DateTimeField::Second);
+        assert_eq!(
+            parse("1.2.3").unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '1.2.3': unexpected second decimal point at offset 3, only one \
+                 field may have a fraction"
+                    .into()
+            )
+        );
+        assert_eq!(
+            parse("1..2").unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '1..2': unexpected second decimal point at offset 2, only one \
+                 field may have a fraction"
+                    .into()
+            )
+        );
+        let options = DateTimeParseOptions {
+            decimal_comma: true,
+            ..Default::default()
+        };
+        assert!(Parser::parse_interval_string_with_options(
+            "1,2.3",
+            &DateTimeField::Second,
+            &options
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_parsed_datetime_from_int() {
+        use DateTimeField::*;
+
+        for field in &[Year, Quarter, Month, Week, Day, Hour, Minute, Second] {
+            for value in &[7, -7, 0] {
+                assert_eq!(
+                    Parser::parsed_datetime_from_int(*value, field.clone()).ok(),
+                    Parser::parse_interval_string(&value.to_string(), field).ok(),
+                    "{} {}",
+                    value,
+                    field
+                );
+            }
+        }
+        assert_eq!(
+            Parser::parsed_datetime_from_int(-2, Quarter).unwrap(),
+            ParsedDateTime {
+                is_positive: false,
+                month: Some(6),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            Parser::parsed_datetime_from_int(i64::MIN, Second).unwrap(),
+            ParsedDateTime {
+                is_positive: false,
+                second: Some(1 << 63),
+                ..Default::default()
+            }
+        );
+        assert!(Parser::parsed_datetime_from_int(0, Month).is_err());
+        assert!(Parser::parsed_datetime_from_int(i64::MAX, Year).is_err());
+    }
+
+    #[test]
+    fn test_date_parts_after_time_parts() {
+        for (value, separator) in &[("1:2-3", ':'), ("1.2-3", '.'), ("1 2:3-4", ':')] {
+            assert_eq!(
+                Parser::parse_interval_string(value, &DateTimeField::Year),
+                Err(ParserError::ParserError(format!(
+                    "Invalid interval '{}': a '-' follows a '{}', but the date parts must come \
+                     before the time parts",
+                    value, separator
+                )))
+            );
+        }
+    }
+
+    #[test]
+    fn test_clock_time_hint() {
+        let message = |value, field| match Parser::parse_interval_string(value, &field) {
+            Err(ParserError::ParserError(message)) => message,
+            other => panic!("expected {} to fail to parse, got {:?}", value, other),
+        };
+        assert!(message("1:2:3", DateTimeField::Month).ends_with(
+            "\nhint: '1:2:3' looks like a time, but MONTH is a date field; a time needs a time \
+             leading field, like HOUR TO SECOND"
+        ));
+        assert!(message("-1:2", DateTimeField::Year).ends_with(
+            "\nhint: '-1:2' looks like a time, but YEAR is a date field; a time needs a time \
+             leading field, like HOUR TO MINUTE or MINUTE TO SECOND"
+        ));
+        // a value with date parts, or a time leading field, gets no hint
+        assert!(!message("1-2:3", DateTimeField::Month).contains("hint"));
+        assert!(!message("1:2:3:4", DateTimeField::Hour).contains("hint"));
+        assert!(Parser::parse_interval_string("1:2:3", &DateTimeField::Hour).is_ok());
+    }
+
+    #[test]
+    fn test_fraction_on_last_field() {
+        let parse = |value, field| Parser::parse_interval_string(value, &field);
+        for (value, field) in &[
+            ("1.5:30", DateTimeField::Hour),
+            ("1.5:30", DateTimeField::Minute),
+            ("1 2.5:30", DateTimeField::Day),
+            ("1.5 2:30", DateTimeField::Day),
+            ("-1.5:30:00", DateTimeField::Hour),
+        ] {
+            assert_eq!(
+                parse(value, field.clone()),
+                Err(ParserError::ParserError(format!(
+                    "Invalid interval '{}': a fractional part is only allowed on seconds, the \
+                     last field of a {} interval",
+                    value, field
+                )))
+            );
+        }
+        assert_eq!(
+            parse("4:05.5", DateTimeField::Minute).unwrap(),
+            ParsedDateTime {
+                minute: Some(4),
+                second: Some(5),
+                nano: Some(500_000_000),
+                nano_digits: Some(1),
+                ..Default::default()
+            }
+        );
+        assert!(parse("1:02:03.5", DateTimeField::Hour).is_ok());
+        // the fraction is on the minutes, which are the last field here
+        assert!(parse("4:05.5", DateTimeField::Hour).is_err());
+        // a date part after the fraction is reported as being out of order
+        assert_eq!(
+            parse("1.5-2", DateTimeField::Year).unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '1.5-2': a '-' follows a '.', but the date parts must come \
+                 before the time parts"
+                    .into()
+            )
+        );
+    }
+
+    #[test]
+    fn test_error_kinds() {
+        use ErrorKind::*;
+
+        for (value, field, kind) in &[
+            ("1!", DateTimeField::Second, UnexpectedChar),
+            ("1.2.3", DateTimeField::Second, UnexpectedChar),
+            ("1:2-3", DateTimeField::Hour, InvalidLayout),
+            ("1 1:1", DateTimeField::Hour, InvalidLayout),
+            ("0", DateTimeField::Month, OutOfRange),
+            ("13 PM", DateTimeField::Hour, OutOfRange),
+            ("99999999999999999999", DateTimeField::Second, Overflow),
+            ("9223372036854775807 years", DateTimeField::Year, Overflow),
+            ("", DateTimeField::Second, EmptyField),
+            ("P", DateTimeField::Second, EmptyField),
+            ("1 day 2 days", DateTimeField::Day, DuplicateField),
+            ("1 fortnight", DateTimeField::Second, UnknownUnit),
+            ("1 day now", DateTimeField::Day, Unsupported),
+        ] {
+            let options = DateTimeParseOptions::default();
+            let err = Parser::parse_interval_string_with_kind(value, field, &options).unwrap_err();
+            assert_eq!(&err.kind, kind, "parsing {:?}: {}", value, err);
+            // the kind doesn't change the error itself
+            assert_eq!(
+                Parser::parse_interval_string(value, field).unwrap_err(),
+                err.error
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_interval_string_for_dialect() {
+        use crate::dialect::{GenericDialect, MySqlDialect, PostgreSqlDialect};
+
+        let parse = |value, dialect: &dyn crate::dialect::Dialect| {
+            Parser::parse_interval_string_for_dialect(value, &DateTimeField::Day, dialect)
+        };
+        for value in &["1 day 2 hours", "P1DT2H"] {
+            assert!(parse(value, &PostgreSqlDialect {}).is_ok(), "{}", value);
+            assert!(parse(value, &GenericDialect {}).is_ok(), "{}", value);
+        }
+        assert_eq!(
+            parse("1 day 2 hours", &MySqlDialect {}).unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '1 day 2 hours': MySQL intervals can't have unit words, the \
+                 unit goes after the value, as in INTERVAL '1' DAY"
+                    .into()
+            )
+        );
+        assert_eq!(
+            parse("P1DT2H", &MySqlDialect {}).unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval 'P1DT2H': MySQL intervals can't be ISO 8601 durations".into()
+            )
+        );
+        for dialect in &[
+            &MySqlDialect {} as &dyn crate::dialect::Dialect,
+            &PostgreSqlDialect {},
+        ] {
+            assert_eq!(
+                parse("1 2:00", *dialect).unwrap(),
+                Parser::parse_interval_string("1 2:00", &DateTimeField::Day).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_repeated_signs() {
+        let parse = |value, field| Parser::parse_interval_string(value, &field);
+        // a sign, then the years, a separator and the months
+        assert_eq!(
+            parse("-9-5", DateTimeField::Year).unwrap(),
+            ParsedDateTime {
+                is_positive: false,
+                year: Some(9),
+                month: Some(5),
+                ..Default::default()
+            }
+        );
+        for value in &["--5", "+-5", "-+5", "++5", "--9-5"] {
+            assert_eq!(
+                parse(value, DateTimeField::Year).unwrap_err(),
+                ParserError::ParserError(format!(
+                    "Invalid interval '{}': only a single sign is allowed, before the leading \
+                     field",
+                    value
+                ))
+            );
+        }
+        for value in &["--5 days", "1 day +-2 hours", "1 day --2 hours"] {
+            assert_eq!(
+                parse(value, DateTimeField::Day).unwrap_err(),
+                ParserError::ParserError(format!(
+                    "Invalid interval '{}': only a single sign is allowed, before each part",
+                    value
+                ))
+            );
+        }
+        assert!(parse("-5 days -2 hours", DateTimeField::Day).is_ok());
+        assert!(parse("--P1D", DateTimeField::Day).is_err());
+    }
+
+    #[test]
+    fn test_fractional_minutes_and_hours() {
+        let parse = |value, field| Parser::parse_interval_string(value, &field).unwrap();
+        let time = |hour: Option<u64>, minute, second, nano| ParsedDateTime {
+            hour,
+            minute: Some(minute),
+            second: Some(second),
+            nano,
+            ..Default::default()
+        };
+        for (value, field, expected) in &[
+            (
+                "1.5 minutes",
+                DateTimeField::Minute,
+                time(None, 1, 30, None),
+            ),
+            ("1.5", DateTimeField::Minute, time(None, 1, 30, None)),
+            (
+                "1.25 hours",
+                DateTimeField::Hour,
+                time(Some(1), 15, 0, None),
+            ),
+            ("1.25", DateTimeField::Hour, time(Some(1), 15, 0, None)),
+            (
+                "0.001 hours",
+                DateTimeField::Hour,
+                time(Some(0), 0, 3, Some(600_000_000)),
+            ),
+            (
+                "0.001",
+                DateTimeField::Hour,
+                time(Some(0), 0, 3, Some(600_000_000)),
+            ),
+            // a billionth of a minute is 60 nanoseconds
+            (
+                "0.000000001 minutes",
+                DateTimeField::Minute,
+                time(None, 0, 0, Some(60)),
+            ),
+            // the spread fraction is added to the smaller units
+            (
+                "1.25 hours 10 minutes",
+                DateTimeField::Hour,
+                time(Some(1), 25, 0, None),
+            ),
+            (
+                "1.5 minutes 45 seconds",
+                DateTimeField::Minute,
+                time(None, 1, 75, None),
+            ),
+        ] {
+            assert_eq!(&parse(value, field.clone()), expected, "{}", value);
+        }
+        for value in &["1.5 days", "P1.5D"] {
+            assert_eq!(
+                parse(value, DateTimeField::Day),
+                ParsedDateTime {
+                    day: Some(1),
+                    hour: Some(12),
+                    minute: Some(0),
+                    second: Some(0),
+                    ..Default::default()
+                },
+                "{}",
+                value
+            );
+        }
+        assert_eq!(
+            parse("-1.5 minutes", DateTimeField::Minute),
+            ParsedDateTime {
+                is_positive: false,
+                ..time(None, 1, 30, None)
+            }
+        );
+        // the fraction is taken away along with the minute it is on
+        assert_eq!(
+            parse("2 hours -1.5 minutes", DateTimeField::Hour),
+            time(Some(1), 58, 30, None)
+        );
+        assert_eq!(
+            Parser::parse_unqualified_interval_string("1.5 months").unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '1.5 months': only DAY, HOUR, MINUTE and SECOND may have a \
+                 fractional part, not MONTH"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_error_phases() {
+        use ErrorPhase::*;
+
+        for (value, field, phase) in &[
+            // the characters can't be read
+            ("1!", DateTimeField::Second, Tokenize),
+            ("1.2.3", DateTimeField::Second, Tokenize),
+            ("99999999999999999999", DateTimeField::Second, Tokenize),
+            ("1 fortnight", DateTimeField::Second, Tokenize),
+            ("P1X", DateTimeField::Second, Tokenize),
+            ("P1.12345S", DateTimeField::Second, Tokenize),
+            // they can, but the value makes no sense
+            ("0", DateTimeField::Month, Build),
+            ("1:2-3", DateTimeField::Hour, Build),
+            ("13 PM", DateTimeField::Hour, Build),
+            ("1 day 2 days", DateTimeField::Second, Build),
+            ("9223372036854775807 years", DateTimeField::Second, Build),
+            ("", DateTimeField::Second, Build),
+        ] {
+            let options = DateTimeParseOptions::default();
+            let err = Parser::parse_interval_string_with_kind(value, field, &options).unwrap_err();
+            assert_eq!(&err.phase, phase, "parsing {:?}: {}", value, err);
+        }
+
+        // an out of range month is read fine
+        let err = Parser::parse_interval_string_with_kind(
+            "0",
+            &DateTimeField::Month,
+            &Default::default(),
+        )
+        .unwrap_err();
+        assert_eq!((err.kind, err.phase), (ErrorKind::OutOfRange, Build));
+        let err = |value| parse_timezone_offset_second(value).unwrap_err().phase;
+        assert_eq!(err("+99999999999999999999:00"), Tokenize);
+        assert_eq!(err("+25:00"), Build);
+        let (_, errors) = tokenize_interval_collect("1x 2.3.4", &Default::default());
+        assert!(errors.iter().all(|e| e.phase == Tokenize));
+    }
+
+    #[test]
+    fn test_layout_error_snippet() {
+        let err = |value, field| match Parser::parse_interval_string(value, &field) {
+            Err(ParserError::ParserError(msg)) => msg,
+            other => panic!("{} is {:?}", value, other),
+        };
+        let msg = err("-12 345-6", DateTimeField::Day);
+        assert!(
+            msg.ends_with("\n-12 345-6\n       ^"),
+            "the snippet is missing from {}",
+            msg
+        );
+        // long values are cut down to the part around the problem
+        let msg = err("1-2-3 4:5:6 7 8 9 10 11", DateTimeField::Year);
+        assert!(
+            msg.ends_with("\n...-2-3 4:5:6 7 8 9 10 1...\n             ^"),
+            "the snippet is missing from {}",
+            msg
+        );
+        assert_eq!(context_snippet("12345", 1..4), "12345\n ^^^");
+    }
+
+    #[test]
+    fn test_integer_fast_path() {
+        use DateTimeField::*;
+
+        let compact = DateTimeParseOptions {
+            compact_time: true,
+            strict_year_to_month: true,
+            ..Default::default()
+        };
+        for options in &[DateTimeParseOptions::default(), compact] {
+            for field in &[Year, Quarter, Month, Week, Day, Hour, Minute, Second] {
+                for value in &[
+                    "0",
+                    "-0",
+                    "1",
+                    "30",
+                    "-5",
+                    "+7",
+                    "1203",
+                    "007",
+                    "18446744073709551615",
+                    "18446744073709551616",
+                ] {
+                    let fast = build_integer_interval(value, field, options);
+                    let general = tokenize_interval(value, options).and_then(|toks| {
+                        build_parsed_datetime(toks.into_iter().map(Ok), field, value, options)
+                    });
+                    match fast {
+                        Some(pdt) => assert_eq!(Ok(pdt), general, "{} {}", value, field),
+                        None => assert!(
+                            general.is_err()
+                                || field == &Quarter
+                                || field == &Week
+                                || (field == &Hour && options.compact_time),
+                            "{} {} should have taken the fast path",
+                            value,
+                            field
+                        ),
+                    }
+                }
+            }
+        }
+        for value in &["", "-", "1.5", "1 ", "--1", "1-2", "1a"] {
+            assert_eq!(
+                build_integer_interval(value, &Second, &DateTimeParseOptions::default()),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_options() {
+        assert_eq!(DateTimeParseOptions::default().validate(), Ok(()));
+        let clock = DateTimeParseOptions {
+            clock_fields: true,
+            leap_second: true,
+            end_of_day: true,
+            ..Default::default()
+        };
+        assert_eq!(clock.validate(), Ok(()));
+
+        for (options, message) in &[
+            (
+                DateTimeParseOptions {
+                    leap_second: true,
+                    ..Default::default()
+                },
+                "the leap_second option only applies to clock fields, but clock_fields is not set",
+            ),
+            (
+                DateTimeParseOptions {
+                    end_of_day: true,
+                    ..Default::default()
+                },
+                "the end_of_day option only applies to clock fields, but clock_fields is not set",
+            ),
+            (
+                DateTimeParseOptions {
+                    strict_precision: true,
+                    fraction_rounding: Some(FractionRounding::Round),
+                    ..Default::default()
+                },
+                "the fraction_rounding option does nothing with strict_precision, unless a \
+                 fractional seconds precision is given",
+            ),
+            (
+                DateTimeParseOptions {
+                    fractional_seconds_precision: Some(10),
+                    ..Default::default()
+                },
+                "fractional seconds precision must be between 0 and 9, got 10",
+            ),
+        ] {
+            let err = options.validate().unwrap_err();
+            assert_eq!(err.error, ParserError::ParserError(message.to_string()));
+            // and every value parsed with them is rejected the same way
+            let parsed =
+                Parser::parse_interval_string_with_kind("1", &DateTimeField::Hour, options);
+            assert_eq!(parsed.unwrap_err().error, err.error);
+        }
+        assert_eq!(
+            DateTimeParseOptions {
+                leap_second: true,
+                ..Default::default()
+            }
+            .validate()
+            .unwrap_err()
+            .kind,
+            ErrorKind::Unsupported
+        );
+        // rounding still applies to a precision under strict_precision
+        let rounded = DateTimeParseOptions {
+            strict_precision: true,
+            fraction_rounding: Some(FractionRounding::Truncate),
+            fractional_seconds_precision: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(rounded.validate(), Ok(()));
+        assert_eq!(
+            Parser::parse_interval_string_with_options("1.99", &DateTimeField::Second, &rounded)
+                .unwrap()
+                .nano,
+            Some(900_000_000)
+        );
+    }
+
+    #[test]
+    fn test_leap_second_option() {
+        let clock = DateTimeParseOptions {
+            clock_fields: true,
+            ..Default::default()
+        };
+        let leap = DateTimeParseOptions {
+            leap_second: true,
+            ..clock.clone()
+        };
+        let parse = |value, options: &DateTimeParseOptions| {
+            Parser::parse_interval_string_with_options(value, &DateTimeField::Hour, options)
+        };
+        assert_eq!(
+            parse("23:59:60", &leap).unwrap(),
+            ParsedDateTime {
+                hour: Some(23),
+                minute: Some(59),
+                second: Some(60),
+                ..Default::default()
+            }
+        );
+        assert!(parse("23:59:60.5", &leap).is_ok());
+        assert_eq!(
+            parse("23:59:61", &leap).unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '23:59:61': SECOND 61 is out of range for a time of day, it \
+                 must be at most 60"
+                    .into()
+            )
+        );
+        assert_eq!(
+            parse("23:59:60", &clock).unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '23:59:60': SECOND 60 is out of range for a time of day, it \
+                 must be at most 59"
+                    .into()
+            )
+        );
+        // the leap second is only for seconds
+        assert!(parse("23:60:00", &leap).is_err());
+    }
+
+    #[test]
+    fn test_strict_precision_option() {
+        let strict = DateTimeParseOptions {
+            strict_precision: true,
+            ..Default::default()
+        };
+        let lenient = DateTimeParseOptions::default();
+        let parse =
+            |value, options| Parser::parse_unqualified_interval_string_with_kind(value, options);
+        // the digits past the ninth are dropped, not rounded
+        let pdt = parse("1.123456789912", &lenient).unwrap();
+        assert_eq!((pdt.second, pdt.nano), (Some(1), Some(123_456_789)));
+        assert_eq!(pdt.nano_digits, Some(9));
+        let pdt = parse("PT1.123456789012S", &lenient).unwrap();
+        assert_eq!(pdt.nano, Some(123_456_789));
+        let pdt = parse("1 minute 1.123456789012 seconds", &lenient).unwrap();
+        assert_eq!(pdt.nano, Some(123_456_789));
+
+        let err = parse("1.123456789012", &strict).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::OutOfRange);
+        assert_eq!(err.phase, ErrorPhase::Tokenize);
+        assert_eq!(
+            err.error,
+            ParserError::ParserError(
+                "Invalid interval '1.123456789012': the fraction 123456789012 has 12 digits, but \
+                 at most 9 are kept, to the nanosecond"
+                    .into()
+            )
+        );
+        assert!(parse("PT1.123456789012S", &strict).is_err());
+        assert_eq!(
+            parse("1.123456789", &strict).unwrap().nano,
+            Some(123_456_789)
+        );
+    }
+
+    #[test]
+    fn test_fraction_rounding_option() {
+        let parse = |value, rounding, precision| {
+            let options = DateTimeParseOptions {
+                fraction_rounding: rounding,
+                fractional_seconds_precision: precision,
+                ..Default::default()
+            };
+            let pdt = Parser::parse_unqualified_interval_string_with_kind(value, &options).unwrap();
+            (pdt.signs().time, pdt.second, pdt.nano)
+        };
+        use FractionRounding::*;
+        for (value, rounding, expected) in &[
+            ("1.9999999999", None, (true, Some(1), Some(999_999_999))),
+            (
+                "1.9999999999",
+                Some(Truncate),
+                (true, Some(1), Some(999_999_999)),
+            ),
+            ("1.9999999999", Some(Round), (true, Some(2), Some(0))),
+            (
+                "1.9999999994",
+                Some(Round),
+                (true, Some(1), Some(999_999_999)),
+            ),
+            ("1.0000000005", Some(Round), (true, Some(1), Some(1))),
+            ("1.9999999991", Some(Ceil), (true, Some(2), Some(0))),
+            (
+                "1.9999999999",
+                Some(Floor),
+                (true, Some(1), Some(999_999_999)),
+            ),
+            (
+                "-1.9999999991",
+                Some(Ceil),
+                (false, Some(1), Some(999_999_999)),
+            ),
+            ("-1.9999999991", Some(Floor), (false, Some(2), Some(0))),
+            (
+                "1.1234567890",
+                Some(Ceil),
+                (true, Some(1), Some(123_456_789)),
+            ),
+            ("99.9999999999", Some(Round), (true, Some(100), Some(0))),
+            // the sign of the days is not the sign of the seconds
+            (
+                "-1 day 2.9999999999 seconds",
+                Some(Floor),
+                (true, Some(2), Some(999_999_999)),
+            ),
+            (
+                "1 day -2.9999999999 seconds",
+                Some(Floor),
+                (false, Some(3), Some(0)),
+            ),
+        ] {
+            assert_eq!(parse(value, *rounding, None), *expected, "{}", value);
+        }
+        // a carry goes on into the minutes' digits, since the seconds
+        // aren't bounded by default
+        assert_eq!(
+            Parser::parse_interval_string_with_kind(
+                "1:59.9999999999",
+                &DateTimeField::Minute,
+                &DateTimeParseOptions {
+                    fraction_rounding: Some(Round),
+                    ..Default::default()
+                },
+            )
+            .map(|pdt| (pdt.minute, pdt.second, pdt.nano)),
+            Ok((Some(1), Some(60), Some(0)))
+        );
+
+        // the same modes apply to a fractional seconds precision
+        for (value, rounding, expected) in &[
+            ("1.25", None, (true, Some(1), Some(300_000_000))),
+            ("1.25", Some(Truncate), (true, Some(1), Some(200_000_000))),
+            ("1.25", Some(Round), (true, Some(1), Some(300_000_000))),
+            ("1.21", Some(Ceil), (true, Some(1), Some(300_000_000))),
+            ("1.29", Some(Floor), (true, Some(1), Some(200_000_000))),
+            ("-1.21", Some(Ceil), (false, Some(1), Some(200_000_000))),
+            ("-1.21", Some(Floor), (false, Some(1), Some(300_000_000))),
+            ("1.99", Some(Ceil), (true, Some(2), Some(0))),
+        ] {
+            assert_eq!(parse(value, *rounding, Some(1)), *expected, "{}", value);
+        }
+    }
+
+    #[test]
+    fn test_parse_interval_with_warnings() {
+        let parse = |value, field| Parser::parse_interval_with_warnings(value, &field).unwrap();
+        let (pdt, warnings) = parse("90 seconds", DateTimeField::Second);
+        assert_eq!(pdt.second, Some(90));
+        assert_eq!(
+            warnings,
+            vec![
+                "Interval '90 seconds' is not normalized: it has 60 or more minutes or seconds, \
+                  and is the same as '00:01:30'"
+                    .to_string()
+            ]
+        );
+        assert_eq!(
+            parse("0 12:00", DateTimeField::Day).1,
+            vec!["Interval '0 12:00' starts with a DAY of 0, which adds nothing".to_string()]
+        );
+        assert_eq!(
+            parse("0 days", DateTimeField::Day).1,
+            vec!["Interval '0 days' is zero".to_string()]
+        );
+        for (value, field) in &[
+            ("1:30", DateTimeField::Minute),
+            ("1 day 2 hours", DateTimeField::Day),
+            ("1 00:30", DateTimeField::Day),
+        ] {
+            assert_eq!(
+                parse(value, field.clone()).1,
+                Vec::<String>::new(),
+                "{}",
+                value
+            );
+        }
+        assert!(Parser::parse_interval_with_warnings("1 fortnight", &DateTimeField::Day).is_err());
+    }
+
+    #[test]
+    fn test_max_magnitude_option() {
+        let bounded = DateTimeParseOptions {
+            max_magnitude: Some(ParsedDateTime::builder().years(10).build().unwrap()),
+            ..Default::default()
+        };
+        let parse =
+            |value, field| Parser::parse_interval_string_with_options(value, field, &bounded);
+        let year = &DateTimeField::Year;
+        assert_eq!(parse("5 years", year).unwrap().year, Some(5));
+        assert!(parse("10", year).is_ok());
+        assert!(parse("-10", year).is_ok());
+        assert!(parse("3650 days", &DateTimeField::Day).is_ok());
+        assert!(parse("9 years 11 months", year).is_ok());
+        assert_eq!(
+            parse("9999 years", year).unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '9999 years': it is longer than the most that is allowed, 10 \
+                 years"
+                    .into()
+            )
+        );
+        assert_eq!(
+            Parser::parse_interval_string_with_kind("-11", year, &bounded)
+                .unwrap_err()
+                .kind,
+            ErrorKind::OutOfRange
+        );
+        assert!(parse("3651 days", &DateTimeField::Day).is_err());
+        assert!(parse("10 years 1 second", year).is_err());
+        // without a bound, only the fields limit the value
+        assert!(Parser::parse_interval_string("9999 years", year).is_ok());
+    }
+
+    #[test]
+    fn test_unit_suffixes_option() {
+        let suffixes = DateTimeParseOptions {
+            unit_suffixes: true,
+            ..Default::default()
+        };
+        let parse =
+            |value| Parser::parse_unqualified_interval_string_with_options(value, &suffixes);
+        assert_eq!(
+            parse("5h30m").unwrap(),
+            ParsedDateTime {
+                hour: Some(5),
+                minute: Some(30),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            parse("90s").unwrap(),
+            ParsedDateTime {
+                second: Some(90),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            parse("500ms").unwrap(),
+            ParsedDateTime {
+                second: Some(0),
+                nano: Some(500_000_000),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            parse("2d").unwrap(),
+            ParsedDateTime {
+                day: Some(2),
+                ..Default::default()
+            }
+        );
+        // the parts are added up, and a fraction is spread over the smaller units
+        assert_eq!(
+            parse("-1.5h250us1500ns").unwrap(),
+            ParsedDateTime {
+                is_positive: false,
+                hour: Some(1),
+                minute: Some(30),
+                second: Some(0),
+                nano: Some(251_500),
+                ..Default::default()
+            }
+        );
+        assert_eq!(parse("1s1500ms").unwrap().second, Some(2));
+        assert_eq!(
+            parse("5h30").unwrap_err(),
+            ParserError::TokenizerError(
+                "Invalid interval '5h30': the number at offset 2 needs a unit after it, like h, \
+                 m or s"
+                    .into()
+            )
+        );
+        let err = Parser::parse_unqualified_interval_string_with_kind("5x", &suffixes).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::UnknownUnit);
+        assert_eq!(
+            err.error,
+            ParserError::TokenizerError(
+                "Invalid interval '5x': unknown unit 'x' at offset 1, expected d, h, m, s, ms, \
+                 us or ns"
+                    .into()
+            )
+        );
+        // other values are read as usual
+        assert_eq!(parse("1 day").unwrap().day, Some(1));
+        assert_eq!(parse("1.5").unwrap().nano, Some(500_000_000));
+        // and without the option the suffixes are not units
+        assert!(Parser::parse_unqualified_interval_string("5h30m").is_err());
+    }
+
+    #[test]
+    fn test_record_qualifier_option() {
+        let roundtrip = |value: &str, qualifier: &str| {
+            let mut fields = qualifier
+                .split(" TO ")
+                .map(|field| DateTimeField::from_keyword(field).unwrap());
+            let leading_field = fields.next().unwrap();
+            let options = DateTimeParseOptions {
+                last_field: fields.next(),
+                record_qualifier: true,
+                ..Default::default()
+            };
+            let pdt = Parser::parse_interval_string_with_options(value, &leading_field, &options)
+                .unwrap();
+            let rebuilt = match (pdt.leading_field(), pdt.trailing_field()) {
+                (Some(leading), Some(trailing)) => format!("{} TO {}", leading, trailing),
+                (Some(leading), None) => leading.to_string(),
+                (None, _) => panic!("no qualifier was recorded for '{}'", value),
+            };
+            assert_eq!(rebuilt, qualifier);
+            pdt
+        };
+        roundtrip("1 2:03:04", "DAY TO SECOND");
+        roundtrip("1-2", "YEAR TO MONTH");
+        roundtrip("4:05", "HOUR TO MINUTE");
+        roundtrip("3", "QUARTER");
+        assert_eq!(roundtrip("5", "MINUTE").minute, Some(5));
+
+        // nothing is kept by default
+        let pdt = Parser::parse_interval_string("1 2:03:04", &DateTimeField::Day).unwrap();
+        assert_eq!((pdt.leading_field(), pdt.trailing_field()), (None, None));
+    }
+
+    #[test]
+    fn test_end_of_day_option() {
+        let clock = DateTimeParseOptions {
+            clock_fields: true,
+            ..Default::default()
+        };
+        let end_of_day = DateTimeParseOptions {
+            end_of_day: true,
+            ..clock.clone()
+        };
+        let normalized = DateTimeParseOptions {
+            normalize: true,
+            ..end_of_day.clone()
+        };
+        let parse = |value, field, options: &DateTimeParseOptions| {
+            Parser::parse_interval_string_with_options(value, field, options)
+        };
+        let hour = &DateTimeField::Hour;
+        assert_eq!(
+            parse("24:00:00", hour, &end_of_day).unwrap(),
+            ParsedDateTime {
+                hour: Some(24),
+                minute: Some(0),
+                second: Some(0),
+                ..Default::default()
+            }
+        );
+        assert!(parse("24:00", hour, &end_of_day).is_ok());
+        assert_eq!(
+            parse("24:00:01", hour, &end_of_day).unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '24:00:01': HOUR 24 is out of range for a time of day, it \
+                 must be at most 23"
+                    .into()
+            )
+        );
+        assert!(parse("24:00:00.000001", hour, &end_of_day).is_err());
+        assert!(parse("24:01:00", hour, &end_of_day).is_err());
+        assert!(parse("25:00:00", hour, &end_of_day).is_err());
+        assert!(parse("24:00:00", hour, &clock).is_err());
+
+        assert_eq!(
+            parse("24:00:00", hour, &normalized).unwrap(),
+            ParsedDateTime {
+                day: Some(1),
+                hour: Some(0),
+                minute: Some(0),
+                second: Some(0),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            parse("3 24:00:00", &DateTimeField::Day, &normalized).unwrap(),
+            parse("4 00:00:00", &DateTimeField::Day, &clock).unwrap()
+        );
+        assert!(parse("24:00:01", hour, &normalized).is_err());
+
+        let year = &DateTimeField::Year;
+        for (value, next_day) in &[
+            ("2020-01-15 24:00:00", "2020-01-16 00:00:00"),
+            ("2020-02-29 24:00:00", "2020-03-01 00:00:00"),
+            ("2020-12-31 24:00:00", "2021-01-01 00:00:00"),
+        ] {
+            assert_eq!(
+                parse(value, year, &normalized).unwrap(),
+                parse(next_day, year, &clock).unwrap(),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_clock_fields_option() {
+        let clock = DateTimeParseOptions {
+            clock_fields: true,
+            ..Default::default()
+        };
+        let parse = |value, field, options: &DateTimeParseOptions| {
+            Parser::parse_interval_string_with_options(value, &field, options)
+        };
+        let default = DateTimeParseOptions::default();
+        assert_eq!(
+            parse("25:00:00", DateTimeField::Hour, &default).unwrap(),
+            ParsedDateTime {
+                hour: Some(25),
+                minute: Some(0),
+                second: Some(0),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            parse("25:00:00", DateTimeField::Hour, &clock).unwrap_err(),
+            ParserError::ParserError(
+                "Invalid interval '25:00:00': HOUR 25 is out of range for a time of day, it must \
+                 be at most 23"
+                    .into()
+            )
+        );
+        assert!(parse("1 23:60:00", DateTimeField::Day, &clock).is_err());
+        assert!(parse("1 23:60:00", DateTimeField::Day, &default).is_ok());
+        assert!(parse("1 0:0:60", DateTimeField::Day, &clock).is_err());
+        assert_eq!(
+            parse("1 23:59:59.9", DateTimeField::Day, &clock).unwrap(),
+            parse("1 23:59:59.9", DateTimeField::Day, &default).unwrap()
+        );
+        // durations without a colon are not held to a clock
+        for (value, field) in &[
+            ("25", DateTimeField::Hour),
+            ("90", DateTimeField::Minute),
+            ("1 day 25 hours", DateTimeField::Second),
+        ] {
+            assert_eq!(
+                parse(value, field.clone(), &clock),
+                parse(value, field.clone(), &default),
+                "parsing {}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_normalize_option() {
+        let normalized = DateTimeParseOptions {
+            normalize: true,
+            ..Default::default()
+        };
+        let parse = |value, options: &DateTimeParseOptions| {
+            Parser::parse_interval_string_with_options(value, &DateTimeField::Second, options)
+                .unwrap()
+        };
+        assert_eq!(
+            parse("90061", &DateTimeParseOptions::default()),
+            ParsedDateTime {
+                second: Some(90061),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            parse("90061", &normalized),
+            ParsedDateTime {
+                hour: Some(25),
+                minute: Some(1),
+                second: Some(1),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            parse("-90061.5", &normalized),
+            ParsedDateTime {
+                is_positive: false,
+                hour: Some(25),
+                minute: Some(1),
+                second: Some(1),
+                nano: Some(500_000_000),
+                nano_digits: Some(1),
+                ..Default::default()
+            }
+        );
+        assert_eq!(parse("59", &normalized).second, Some(59));
+        assert_eq!(parse("59", &normalized).minute, None);
+    }
+
+    #[test]
+    fn test_input_limits() {
+        let parse = |value: &str, options| {
+            Parser::parse_unqualified_interval_string_with_kind(value, &options)
+        };
+        let huge = "1".repeat(10 * 1024 * 1024);
+        let err = parse(&huge, DateTimeParseOptions::default()).unwrap_err();
+        assert_eq!(err.kind, ErrorKind::TooLong);
+        assert_eq!(
+            err.error,
+            ParserError::ParserError(
+                "Invalid interval: the value is 10485760 bytes long, but at most 4096 are allowed"
+                    .to_string()
+            )
+        );
+
+        let zeros = format!("1 day {}1 seconds", "0".repeat(300));
+        assert_eq!(
+            parse(&zeros, DateTimeParseOptions::default()),
+            Err(DateTimeError::new(
+                ErrorKind::TooLong,
+                ParserError::ParserError(
+                    "Invalid interval: the number at offset 6 has more than 256 digits".to_string()
+                )
+            ))
+        );
+        // but leading zeros are fine under the limit
+        let options = DateTimeParseOptions {
+            max_digits: 400,
+            ..Default::default()
+        };
+        assert_eq!(parse(&zeros, options).unwrap().second, Some(1));
+
+        let options = DateTimeParseOptions {
+            max_length: 4,
+            ..Default::default()
+        };
+        assert!(parse("1.23", options.clone()).is_ok());
+        assert_eq!(
+            parse("12.34", options).unwrap_err().kind,
+            ErrorKind::TooLong
+        );
+    }
+
+    #[test]
+    fn test_negative_zero_is_positive() {
+        let parse = |value, field| Parser::parse_interval_string(value, &field).unwrap();
+        assert_eq!(
+            parse("-0", DateTimeField::Hour),
+            parse("0", DateTimeField::Hour)
+        );
+        assert!(parse("-0", DateTimeField::Hour).is_positive);
+        assert_eq!(
+            parse("-0:00:00", DateTimeField::Hour),
+            ParsedDateTime {
+                hour: Some(0),
+                minute: Some(0),
+                second: Some(0),
+                ..Default::default()
             }
+        );
+        assert!(parse("-0 days", DateTimeField::Day).is_positive);
+        // including when rounding is what makes it zero
+        let options = DateTimeParseOptions {
+            fractional_seconds_precision: Some(3),
+            ..Default::default()
+        };
+        let pdt =
+            Parser::parse_unqualified_interval_string_with_options("-0.0001", &options).unwrap();
+        assert!(pdt.is_positive);
+        // non-zero negatives keep their sign
+        assert!(!parse("-0:00:01", DateTimeField::Hour).is_positive);
+        assert!(!parse("-0.001", DateTimeField::Second).is_positive);
+    }
+
+    #[test]
+    fn test_debug_tokenize_interval() {
+        let render = |value| {
+            Parser::debug_tokenize_interval(value)
+                .unwrap()
+                .iter()
+                .map(|tok| tok.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        assert_eq!(render("-1-2 3:04:05.060"), "- 1 - 2 _ 3 : 4 : 5 . 060");
+        assert_eq!(render("P1DT2.5S"), "1 DAY 2 . 5 SECOND");
+        assert_eq!(render("1 day 2 hours"), "1 DAY 2 HOUR");
+        assert_eq!(
+            Parser::debug_tokenize_interval("1:2").unwrap(),
+            vec![
+                IntervalToken::Num(1),
+                IntervalToken::Colon,
+                IntervalToken::Num(2)
+            ]
+        );
+        assert!(Parser::debug_tokenize_interval("1!").is_err());
+        assert_eq!(IntervalToken::Nanos(500_000_000, 0).to_string(), "5");
+    }
+
+    #[test]
+    fn test_timezone_offsets_with_minutes() {
+        // Nepal and the Chatham Islands are 45 minutes off of the hour
+        for (with_colon, compact, seconds) in &[
+            ("+05:45", "+0545", 20_700),
+            ("+12:45", "+1245", 45_900),
+            ("-09:30", "-0930", -34_200),
+        ] {
+            assert_eq!(Parser::parse_timezone_offset(with_colon), Ok(*seconds));
+            assert_eq!(Parser::parse_timezone_offset(compact), Ok(*seconds));
+        }
+        let pdt = Parser::parse_timestamp_string("2020-01-01 12:00:00+0545", true).unwrap();
+        assert_eq!(pdt.timezone_offset_second, Some(20_700));
+    }
+
+    const RANDOM_CASES: u32 = 2_000;
+
+    /// The characters the values in `test_random_strings_do_not_panic` are
+    /// made of, which are the ones the tokenizers look for and a few others
+    const ALPHABET: &[char] = &[
+        '0', '1', '2', '5', '9', '-', '+', ':', '.', ',', ' ', 'T', 'P', 'Y', 'M', 'D', 'H', 'S',
+        'Z', 'd', 'a', 'y', 's', 'h', 'o', 'u', 'r', 'm', 'p', '(', ')', '\u{e9}',
+    ];
+
+    const FIELDS: &[DateTimeField] = &[
+        DateTimeField::Year,
+        DateTimeField::Quarter,
+        DateTimeField::Month,
+        DateTimeField::Day,
+        DateTimeField::Hour,
+        DateTimeField::Minute,
+        DateTimeField::Second,
+    ];
+
+    /// A fraction of a second as its digits and how many there are, with no
+    /// fraction at all for 0 digits
+    fn fraction() -> impl Strategy<Value = Option<(u32, u32)>> {
+        (0..10_u32).prop_flat_map(|digits| {
+            (0..10_u32.pow(digits)).prop_map(move |f| Some((f, digits)).filter(|_| digits > 0))
+        })
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(RANDOM_CASES))]
+
+        #[test]
+        fn test_random_intervals_round_trip(
+            is_positive: bool,
+            y in 0..10_000_u64,
+            mo in 1..=12_u64,
+            d in 1..=31_u64,
+            h in 0..24_u64,
+            mi in 0..60_u64,
+            s in 0..60_u64,
+            fraction in fraction(),
+        ) {
+            let expected = ParsedDateTime {
+                is_positive,
+                year: Some(y),
+                month: Some(mo),
+                day: Some(d),
+                hour: Some(h),
+                minute: Some(mi),
+                second: Some(s),
+                nano: fraction.map(|(f, digits)| f * 10_u32.pow(9 - digits)),
+                nano_digits: fraction.map(|(_, digits)| digits as u8),
+                ..Default::default()
+            };
+            let fraction = match fraction {
+                Some((f, digits)) => format!(".{:0width$}", f, width = digits as usize),
+                None => String::new(),
+            };
+            let sign = if is_positive { "" } else { "-" };
+
+            let positional = format!(
+                "{}{}-{}-{} {}:{}:{}{}",
+                sign, y, mo, d, h, mi, s, fraction
+            );
+            prop_assert_eq!(
+                Parser::parse_interval_string(&positional, &DateTimeField::Year),
+                Ok(expected.clone()),
+                "parsing {:?}",
+                positional
+            );
+
+            // each part carries its own sign
+            let compound = format!(
+                "{sign}{} years {sign}{} months {sign}{} days {sign}{} hours {sign}{} minutes \
+                 {sign}{}{} seconds",
+                y, mo, d, h, mi, s, fraction,
+                sign = sign
+            );
+            prop_assert_eq!(
+                Parser::parse_unqualified_interval_string(&compound),
+                Ok(expected.clone()),
+                "parsing {:?}",
+                compound
+            );
+
+            let iso = format!("{}P{}Y{}M{}DT{}H{}M{}{}S", sign, y, mo, d, h, mi, s, fraction);
+            prop_assert_eq!(
+                Parser::parse_unqualified_interval_string(&iso),
+                Ok(expected),
+                "parsing {:?}",
+                iso
+            );
+        }
+
+        #[test]
+        fn test_random_strings_do_not_panic(
+            value in collection::vec(sample::select(ALPHABET), 0..24)
+                .prop_map(|chars| chars.into_iter().collect::<String>()),
+            field in sample::select(FIELDS),
+        ) {
+            let options = DateTimeParseOptions {
+                embedded_precision: true,
+                decimal_comma: true,
+                compact_time: true,
+                ..Default::default()
+            };
+            // only the absence of a panic is being checked
+            let _ = Parser::parse_interval_string(&value, &field);
+            let _ = Parser::parse_interval_string_with_options(&value, &field, &options);
+            let _ = Parser::lint_interval(&value, &field);
+            let _ = Parser::parse_timestamp_string(&value, true);
+            let _ = Parser::parse_timezone_offset(&value);
         }
     }
 }
@@ -0,0 +1,413 @@
+//! Best-effort diagnostics for interval values, see [`Parser::lint_interval`]
+//!
+//! [`Parser::lint_interval`]: crate::parser::Parser::lint_interval
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::ops::Range;
+
+use super::{
+    is_compound_interval, is_iso8601_duration, layout_msg, potential_interval_tokens, IntervalToken,
+};
+use crate::parser::{DateTimeField, Parser, ParserError};
+
+/// How serious an [`IntervalIssue`] is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IssueSeverity {
+    /// The value will not parse
+    Error,
+    /// The value parses, but probably not the way it was meant to
+    Warning,
+}
+
+/// A problem found in an interval value by [`Parser::lint_interval`]
+///
+/// [`Parser::lint_interval`]: crate::parser::Parser::lint_interval
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalIssue {
+    pub severity: IssueSeverity,
+    /// The byte range of the value that the issue is about
+    pub span: Range<usize>,
+    pub message: String,
+}
+
+impl IntervalIssue {
+    fn error(span: Range<usize>, message: String) -> IntervalIssue {
+        IntervalIssue {
+            severity: IssueSeverity::Error,
+            span,
+            message,
+        }
+    }
+
+    fn warning(span: Range<usize>, message: String) -> IntervalIssue {
+        IntervalIssue {
+            severity: IssueSeverity::Warning,
+            span,
+            message,
+        }
+    }
+}
+
+/// A run of digits or a single other character, and where it is in the value
+#[derive(Debug)]
+enum Piece {
+    Digits(Range<usize>),
+    Char(char, usize),
+}
+
+pub(crate) fn lint_interval(value: &str, leading_field: &DateTimeField) -> Vec<IntervalIssue> {
+    let mut issues = vec![];
+    lint_whitespace(value, &mut issues);
+
+    if !is_iso8601_duration(value.trim()) && !is_compound_interval(value) {
+        let start = value.len() - value.trim_start().len();
+        lint_positional(value, start, leading_field, &mut issues);
+    }
+
+    if !issues.iter().any(|i| i.severity == IssueSeverity::Error) {
+        if let Err(ParserError::ParserError(msg)) | Err(ParserError::TokenizerError(msg)) =
+            Parser::parse_interval_string(value, leading_field)
+        {
+            issues.push(IntervalIssue::error(0..value.len(), msg));
+        }
+    }
+    issues.sort_by_key(|issue| (issue.span.start, issue.span.end));
+    issues
+}
+
+/// Whitespace around the value, and runs of more than one space within it
+fn lint_whitespace(value: &str, issues: &mut Vec<IntervalIssue>) {
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return;
+    }
+    let start = value.len() - value.trim_start().len();
+    if start > 0 {
+        issues.push(IntervalIssue::warning(
+            0..start,
+            "leading whitespace".to_string(),
+        ));
+    }
+    let end = start + trimmed.len();
+    if end < value.len() {
+        issues.push(IntervalIssue::warning(
+            end..value.len(),
+            "trailing whitespace".to_string(),
+        ));
+    }
+    let mut run_start = None;
+    for (i, chr) in trimmed.char_indices() {
+        match (chr == ' ', run_start) {
+            (true, None) => run_start = Some(i),
+            (false, Some(run)) => {
+                if i - run > 1 {
+                    issues.push(IntervalIssue::warning(
+                        start + run..start + i,
+                        "more than one space between fields".to_string(),
+                    ));
+                }
+                run_start = None;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Walk a positional value like `1-2 3:4:5.6` along the layout for the
+/// `leading_field`, checking every field that can be placed
+fn lint_positional(
+    value: &str,
+    offset: usize,
+    leading_field: &DateTimeField,
+    issues: &mut Vec<IntervalIssue>,
+) {
+    let mut pieces = vec![];
+    let mut chars = value[offset..].trim_end().char_indices().peekable();
+    while let Some((i, chr)) = chars.next() {
+        if !chr.is_ascii_digit() {
+            pieces.push(Piece::Char(chr, offset + i));
+            continue;
+        }
+        let mut end = i + 1;
+        while let Some((j, c)) = chars.peek() {
+            if !c.is_ascii_digit() {
+                break;
+            }
+            end = j + 1;
+            chars.next();
+        }
+        pieces.push(Piece::Digits(offset + i..offset + end));
+    }
+
+    let mut pieces = pieces.into_iter().peekable();
+    if let Some(Piece::Char('-', _)) | Some(Piece::Char('+', _)) = pieces.peek() {
+        pieces.next();
+    }
+    let template = potential_interval_tokens(leading_field);
+    let mut expected = template.iter();
+    let mut fields = vec![leading_field.clone()];
+    fields.extend(leading_field.clone());
+    let mut fields = fields.into_iter();
+    let mut numbers_seen = 0;
+    let mut in_structure = true;
+    let mut last_was_space = false;
+    for piece in pieces {
+        match piece {
+            Piece::Char(chr, i) if !"-+ :.Tt".contains(chr) => {
+                issues.push(IntervalIssue::error(
+                    i..i + chr.len_utf8(),
+                    format!("invalid character {:?}", chr),
+                ));
+            }
+            // Runs of spaces have already been reported
+            Piece::Char(' ', _) if last_was_space => {}
+            _ if !in_structure => {}
+            Piece::Char(chr, i) => {
+                last_was_space = chr == ' ';
+                let expected_tok = expected.next();
+                let matches = match (chr, expected_tok) {
+                    ('-', Some(IntervalToken::Dash))
+                    | (' ', Some(IntervalToken::Space))
+                    | ('T', Some(IntervalToken::Space))
+                    | ('t', Some(IntervalToken::Space))
+                    | (':', Some(IntervalToken::Colon))
+                    | ('.', Some(IntervalToken::Dot)) => true,
+                    // A lone `DAY` value may have a fraction
+                    ('.', _) => {
+                        if leading_field == &DateTimeField::Day && numbers_seen == 1 {
+                            let rest = value[i + 1..].trim_end();
+                            if rest.bytes().all(|b| b.is_ascii_digit()) {
+                                lint_fraction(i + 1..i + 1 + rest.len(), issues);
+                                return;
+                            }
+                        }
+                        issues.push(IntervalIssue::error(
+                            i..i + 1,
+                            "only the seconds may have a fraction".to_string(),
+                        ));
+                        in_structure = false;
+                        continue;
+                    }
+                    _ => false,
+                };
+                if !matches {
+                    issues.push(IntervalIssue::error(
+                        i..i + chr.len_utf8(),
+                        match expected_tok {
+                            Some(tok) => format!(
+                                "unexpected {:?}, {} values expect {} here",
+                                chr,
+                                leading_field,
+                                layout_msg(core::slice::from_ref(tok))
+                            ),
+                            None => format!(
+                                "unexpected {:?} after the last field of a {} value",
+                                chr, leading_field
+                            ),
+                        },
+                    ));
+                    in_structure = false;
+                }
+            }
+            Piece::Digits(span) => {
+                last_was_space = false;
+                match expected.next() {
+                    Some(IntervalToken::Num(_)) => {
+                        numbers_seen += 1;
+                        if let Some(field) = fields.next() {
+                            lint_field(value, span, &field, leading_field, issues);
+                        }
+                    }
+                    Some(IntervalToken::Nanos(_, _)) => lint_fraction(span, issues),
+                    _ => {
+                        issues.push(IntervalIssue::error(
+                            span,
+                            format!("too many fields for a {} value", leading_field),
+                        ));
+                        in_structure = false;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Range checks for a single numeric field
+fn lint_field(
+    value: &str,
+    span: Range<usize>,
+    field: &DateTimeField,
+    leading_field: &DateTimeField,
+    issues: &mut Vec<IntervalIssue>,
+) {
+    use DateTimeField::*;
+
+    let num: u64 = match value[span.clone()].parse() {
+        Ok(num) => num,
+        Err(e) => {
+            issues.push(IntervalIssue::error(span, format!("{} is {}", field, e)));
+            return;
+        }
+    };
+    let is_leading = field == leading_field;
+    let limit = match field {
+        Month | Day if num < 1 && !(field == &Day && is_leading) => {
+            issues.push(IntervalIssue::error(
+                span,
+                format!("{} must be >= 1", field),
+            ));
+            return;
+        }
+        Day if leading_field <= &Month => {
+            issues.push(IntervalIssue::warning(
+                span,
+                format!(
+                    "{} in a {} value, which is read loosely as a day-time value",
+                    field, leading_field
+                ),
+            ));
+            return;
+        }
+        _ if is_leading => return,
+        Year | Quarter | Week | Day => return,
+        Month => 12,
+        Hour => 24,
+        Minute | Second => 60,
+    };
+    if num >= limit {
+        issues.push(IntervalIssue::warning(
+            span,
+            format!(
+                "{} {} is out of range, it should be less than {}",
+                field, num, limit
+            ),
+        ));
+    }
+}
+
+fn lint_fraction(span: Range<usize>, issues: &mut Vec<IntervalIssue>) {
+    let digits = span.end - span.start;
+    if digits > 9 {
+        issues.push(IntervalIssue::error(
+            span,
+            format!(
+                "the fraction has {} digits, but at most 9 (nanoseconds) are kept",
+                digits
+            ),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn summary(value: &str, leading_field: DateTimeField) -> Vec<(IssueSeverity, &str, String)> {
+        lint_interval(value, &leading_field)
+            .into_iter()
+            .map(|issue| (issue.severity, &value[issue.span], issue.message))
+            .collect()
+    }
+
+    #[test]
+    fn test_lint_messy_interval() {
+        use IssueSeverity::*;
+        assert_eq!(
+            summary(" 1-13-0  25:61:30.1234567891 ", DateTimeField::Year),
+            vec![
+                (Warning, " ", "leading whitespace".to_string()),
+                (
+                    Warning,
+                    "13",
+                    "MONTH 13 is out of range, it should be less than 12".to_string()
+                ),
+                (Error, "0", "DAY must be >= 1".to_string()),
+                (
+                    Warning,
+                    "  ",
+                    "more than one space between fields".to_string()
+                ),
+                (
+                    Warning,
+                    "25",
+                    "HOUR 25 is out of range, it should be less than 24".to_string()
+                ),
+                (
+                    Warning,
+                    "61",
+                    "MINUTE 61 is out of range, it should be less than 60".to_string()
+                ),
+                (
+                    Error,
+                    "1234567891",
+                    "the fraction has 10 digits, but at most 9 (nanoseconds) are kept".to_string()
+                ),
+                (Warning, " ", "trailing whitespace".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lint_structure() {
+        use IssueSeverity::*;
+        assert_eq!(
+            summary("1:2!:3:4", DateTimeField::Hour),
+            vec![
+                (Error, "!", "invalid character '!'".to_string()),
+                (
+                    Error,
+                    ":",
+                    "unexpected ':', HOUR values expect Dot here".to_string()
+                ),
+            ]
+        );
+        assert_eq!(
+            summary("9-5 4:3", DateTimeField::Month),
+            vec![(
+                Warning,
+                "5",
+                "DAY in a MONTH value, which is read loosely as a day-time value".to_string()
+            )]
+        );
+        assert_eq!(
+            summary("1.5 2", DateTimeField::Hour),
+            vec![(
+                Error,
+                ".",
+                "only the seconds may have a fraction".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_lint_clean_intervals() {
+        for (value, field) in &[
+            ("1-2", DateTimeField::Year),
+            ("-1 2:03:04.5", DateTimeField::Day),
+            ("1.25", DateTimeField::Day),
+            ("90", DateTimeField::Minute),
+            ("1 day 2 hours", DateTimeField::Day),
+            ("P1DT2H", DateTimeField::Day),
+        ] {
+            assert_eq!(lint_interval(value, field), vec![], "linting {}", value);
+        }
+        // errors from the other formats come from the parser
+        assert_eq!(
+            summary("1 fortnight", DateTimeField::Second),
+            vec![(
+                IssueSeverity::Error,
+                "1 fortnight",
+                "Invalid interval '1 fortnight': unknown unit 'fortnight' at offset 2, expected \
+                 one of year, quarter, month, week, day, hour, minute or second, or their \
+                 plurals or abbreviations"
+                    .to_string()
+            )]
+        );
+    }
+}
@@ -10,7 +10,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fmt::Debug;
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, string::ToString, vec, vec::Vec};
+use core::fmt::Debug;
 
 use super::ast::*;
 use super::dialect::*;
@@ -53,7 +55,7 @@ impl TestedDialects {
         self.one_of_identical_results(|dialect| {
             let mut tokenizer = Tokenizer::new(dialect, sql);
             let tokens = tokenizer.tokenize().unwrap();
-            f(&mut Parser::new(tokens))
+            f(&mut Parser::new_with_dialect(tokens, dialect))
         })
     }
 
@@ -16,13 +16,19 @@
 //!
 //! The tokens then form the input for the parser, which outputs an Abstract Syntax Tree (AST).
 
-use std::iter::Peekable;
-use std::str::Chars;
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::iter::Peekable;
+use core::str::Chars;
 
 use super::dialect::keywords::ALL_KEYWORDS;
 use super::dialect::Dialect;
-use std::error::Error;
-use std::fmt;
+use core::fmt;
 
 /// SQL Token enumeration
 #[derive(Debug, Clone, PartialEq)]
@@ -264,7 +270,8 @@ impl fmt::Display for TokenizerError {
     }
 }
 
-impl Error for TokenizerError {}
+#[cfg(feature = "std")]
+impl std::error::Error for TokenizerError {}
 
 /// SQL Tokenizer
 pub struct Tokenizer<'a> {
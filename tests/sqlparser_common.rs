@@ -984,6 +984,18 @@ fn parse_extract() {
     );
 }
 
+#[test]
+fn parse_extract_only_fields() {
+    // these can be extracted, but are not units an interval can be given in
+    for field in &["EPOCH", "ISODOW", "ISOYEAR", "DOW", "DOY"] {
+        verified_stmt(&format!("SELECT EXTRACT({} FROM d)", field));
+        assert_eq!(
+            parse_sql_statements(&format!("SELECT INTERVAL '1' {}", field)).unwrap_err(),
+            ParserError::ParserError(format!("Expected date/time field, found: {}", field)),
+        );
+    }
+}
+
 #[test]
 fn parse_create_table() {
     let sql = "CREATE TABLE uk_cities (\
@@ -1379,11 +1391,11 @@ fn parse_literal_date() {
     );
 
     assert_eq!(
-        ParserError::ParserError("Invalid Month 0 in 0-00-00".into()),
+        ParserError::ParserError("Invalid Month 0 in '0-00-00': month must be >= 1".into()),
         parse_sql_statements("SELECT DATE '0-00-00'").unwrap_err(),
     );
     assert_eq!(
-        ParserError::ParserError("Invalid Day 0 in 0-01-00".into()),
+        ParserError::ParserError("Invalid Day 0 in '0-01-00': day must be >= 1".into()),
         parse_sql_statements("SELECT DATE '0-01-00'").unwrap_err(),
     );
     assert_eq!(
@@ -1601,7 +1613,10 @@ fn parse_literal_interval_error_messages() {
     let result = parse_sql_statements("SELECT INTERVAL '1 1-1' DAY");
     assert_eq!(
         ParserError::ParserError(
-            "Invalid interval part at offset 3: '1 1-1' provided Dash but expected Colon"
+            "Invalid interval part at offset 3: '1 1-1' provided '-' but expected ':'; \
+             DAY intervals are laid out as Num Space Num Colon Num Colon Num Dot Nanos\n\
+             1 1-1\n   \
+             ^"
                 .to_string()
         ),
         result.unwrap_err()
@@ -1610,7 +1625,10 @@ fn parse_literal_interval_error_messages() {
     let result = parse_sql_statements("SELECT INTERVAL '1 1:1' HOUR");
     assert_eq!(
         ParserError::ParserError(
-            "Invalid interval part at offset 1: '1 1:1' provided Space but expected Colon"
+            "Invalid interval part at offset 1: '1 1:1' provided ' ' but expected ':'; \
+             HOUR intervals are laid out as Num Colon Num Colon Num Dot Nanos\n\
+             1 1:1\n \
+             ^"
                 .to_string(),
         ),
         result.unwrap_err()
@@ -1629,6 +1647,7 @@ fn parse_literal_interval_with_character_precision() {
                 minute: Some(1),
                 second: Some(1),
                 nano: Some(10_000_000),
+                nano_digits: Some(2),
                 ..dflt()
             },
             leading_field: DateTimeField::Minute,
@@ -1756,19 +1775,13 @@ fn parse_literal_interval_durationlike() {
         None,
     );
 
-    let mut iv = single_iv();
-    iv.value = "1 4:5".into();
-    iv.parsed.day = Some(1);
-    iv.parsed.hour = Some(4);
-    iv.parsed.minute = Some(5);
-    iv.leading_field = DateTimeField::Day;
-    iv.last_field = Some(DateTimeField::Hour);
-    verify_interval(
-        "SELECT INTERVAL '1 4:5' DAY TO HOUR",
-        iv.clone(),
-        dur_secs(3600 * 24 + 4 * 3600),
-        Some("100800s"),
-        Some("The interval string '1 4:5' specifies MINUTEs but the requested precision would truncate to HOUR"),
+    // a value may not go on past the last field of its qualifier
+    assert_eq!(
+        parse_sql_statements("SELECT INTERVAL '1 4:5' DAY TO HOUR").unwrap_err(),
+        ParserError::ParserError(
+            "Invalid interval '1 4:5': a DAY TO HOUR interval may not have fields after the HOUR"
+                .to_string()
+        )
     );
     let mut iv = single_iv();
     iv.value = "1 2:3".into();
@@ -1817,6 +1830,7 @@ fn parse_literal_interval_durationlike() {
     iv.parsed.minute = Some(1);
     iv.parsed.second = Some(1);
     iv.parsed.nano = Some(100_000_000);
+    iv.parsed.nano_digits = Some(1);
     verify_interval(
         "SELECT INTERVAL '1 1:1:1.1' DAY TO SECOND",
         iv.clone(),
@@ -1907,6 +1921,332 @@ fn parse_literal_interval_durationlike() {
     );
 }
 
+#[test]
+fn parse_literal_interval_signs() {
+    use std::time::Duration;
+
+    // The sign comes before the leading field and applies to the whole value
+    let mut iv = single_iv();
+    iv.value = "-1-2".into();
+    iv.leading_field = DateTimeField::Year;
+    iv.last_field = Some(DateTimeField::Month);
+    iv.parsed.is_positive = false;
+    iv.parsed.year = Some(1);
+    iv.parsed.month = Some(2);
+    verify_interval(
+        "SELECT INTERVAL '-1-2' YEAR TO MONTH",
+        iv,
+        Interval::Months(-14),
+        None,
+        None,
+    );
+
+    let mut iv = single_iv();
+    iv.value = "-1 2:03:04".into();
+    iv.leading_field = DateTimeField::Day;
+    iv.last_field = Some(DateTimeField::Second);
+    iv.parsed.is_positive = false;
+    iv.parsed.day = Some(1);
+    iv.parsed.hour = Some(2);
+    iv.parsed.minute = Some(3);
+    iv.parsed.second = Some(4);
+    verify_interval(
+        "SELECT INTERVAL '-1 2:03:04' DAY TO SECOND",
+        iv,
+        Interval::Duration {
+            is_positive: false,
+            duration: Duration::from_secs(93_784),
+        },
+        None,
+        None,
+    );
+
+    let mut iv = single_iv();
+    iv.value = "-10:30".into();
+    iv.last_field = Some(DateTimeField::Minute);
+    iv.parsed.is_positive = false;
+    iv.parsed.hour = Some(10);
+    iv.parsed.minute = Some(30);
+    verify_interval(
+        "SELECT INTERVAL '-10:30' HOUR TO MINUTE",
+        iv,
+        Interval::Duration {
+            is_positive: false,
+            duration: Duration::from_secs(37_800),
+        },
+        None,
+        None,
+    );
+
+    let mut iv = single_iv();
+    iv.value = "-1:02.5".into();
+    iv.leading_field = DateTimeField::Minute;
+    iv.last_field = Some(DateTimeField::Second);
+    iv.parsed.is_positive = false;
+    iv.parsed.minute = Some(1);
+    iv.parsed.second = Some(2);
+    iv.parsed.nano = Some(500_000_000);
+    iv.parsed.nano_digits = Some(1);
+    verify_interval(
+        "SELECT INTERVAL '-1:02.5' MINUTE TO SECOND",
+        iv,
+        Interval::Duration {
+            is_positive: false,
+            duration: Duration::from_millis(62_500),
+        },
+        None,
+        None,
+    );
+
+    // A sign anywhere else is an error
+    for sql in &[
+        "SELECT INTERVAL '1--2' YEAR TO MONTH",
+        "SELECT INTERVAL '1 -2:03' DAY TO MINUTE",
+        "SELECT INTERVAL '10:-30' HOUR TO MINUTE",
+    ] {
+        assert!(parse_sql_statements(sql).is_err(), "{} should fail", sql);
+    }
+    assert_eq!(
+        parse_sql_statements("SELECT INTERVAL '--1' DAY").unwrap_err(),
+        ParserError::ParserError(
+            "Invalid interval '--1': only a single sign is allowed, before the leading field"
+                .into()
+        )
+    );
+    assert_eq!(
+        parse_sql_statements("SELECT INTERVAL '- 1' DAY").unwrap_err(),
+        ParserError::ParserError(
+            "Invalid interval '- 1': the sign must immediately precede the leading field".into()
+        )
+    );
+}
+
+#[test]
+fn parse_literal_interval_quarter() {
+    let mut iv = single_iv();
+    iv.value = "2".into();
+    iv.leading_field = DateTimeField::Quarter;
+    iv.parsed.month = Some(6);
+    verify_interval(
+        "SELECT INTERVAL '2' QUARTER",
+        iv,
+        Interval::Months(6),
+        None,
+        None,
+    );
+}
+
+#[test]
+fn parse_literal_interval_week() {
+    use std::time::Duration;
+
+    let mut iv = single_iv();
+    iv.value = "2".into();
+    iv.leading_field = DateTimeField::Week;
+    iv.parsed.day = Some(14);
+    verify_interval(
+        "SELECT INTERVAL '2' WEEK",
+        iv,
+        Interval::Duration {
+            is_positive: true,
+            duration: Duration::from_secs(14 * 24 * 60 * 60),
+        },
+        None,
+        None,
+    );
+}
+
+#[test]
+fn parse_literal_interval_unit_and_qualifier() {
+    one_statement_parses_to("SELECT INTERVAL '-30 day'", "SELECT INTERVAL '-30' DAY");
+    one_statement_parses_to("SELECT INTERVAL '2 days'", "SELECT INTERVAL '2' DAY");
+    // compound and ISO values take their qualifier from the units they contain
+    one_statement_parses_to(
+        "SELECT INTERVAL '1 day 2 hours'",
+        "SELECT INTERVAL '1 day 2 hours' DAY TO HOUR",
+    );
+    one_statement_parses_to("SELECT INTERVAL 'P1D'", "SELECT INTERVAL 'P1D' DAY");
+    // a qualifier is allowed if the units in the value are within it
+    verified_only_select("SELECT INTERVAL '1 day' DAY");
+    verified_only_select("SELECT INTERVAL '1 day 2 hours' DAY TO HOUR");
+    verified_only_select("SELECT INTERVAL 'P1DT2H' DAY");
+    assert_eq!(
+        parse_sql_statements("SELECT INTERVAL '1 day' HOUR").unwrap_err(),
+        ParserError::ParserError(
+            "Invalid interval '1 day': the unit in the value, DAY, is not within HOUR TO SECOND, \
+             the range of the qualifier"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        parse_sql_statements("SELECT INTERVAL '1 day 2 minutes' DAY TO HOUR").unwrap_err(),
+        ParserError::ParserError(
+            "Invalid interval '1 day 2 minutes': the unit in the value, MINUTE, is not within \
+             DAY TO HOUR, the range of the qualifier"
+                .to_string()
+        )
+    );
+    assert!(parse_sql_statements("SELECT INTERVAL '1 day 2 hours' YEAR").is_err());
+    assert!(parse_sql_statements("SELECT INTERVAL 'P1D' HOUR").is_err());
+}
+
+#[test]
+fn parse_literal_interval_past_last_field() {
+    verified_only_select("SELECT INTERVAL '1 2:03' DAY TO MINUTE");
+    verified_only_select("SELECT INTERVAL '1 2' DAY TO MINUTE");
+    for (sql, qualifier) in &[
+        ("SELECT INTERVAL '1 2:03:04' DAY TO MINUTE", "DAY TO MINUTE"),
+        ("SELECT INTERVAL '1 2:03' DAY TO HOUR", "DAY TO HOUR"),
+        ("SELECT INTERVAL '2:03:04' HOUR TO MINUTE", "HOUR TO MINUTE"),
+    ] {
+        let value = sql.split('\'').nth(1).unwrap();
+        let last = qualifier.rsplit(' ').next().unwrap();
+        assert_eq!(
+            parse_sql_statements(sql).unwrap_err(),
+            ParserError::ParserError(format!(
+                "Invalid interval '{}': a {} interval may not have fields after the {}",
+                value, qualifier, last
+            ))
+        );
+    }
+}
+
+#[test]
+fn parse_literal_interval_qualifier_case() {
+    for field in &[
+        "YEAR", "QUARTER", "MONTH", "WEEK", "DAY", "HOUR", "MINUTE", "SECOND",
+    ] {
+        let canonical = format!("SELECT INTERVAL '1' {}", field);
+        let lower = field.to_lowercase();
+        let capitalized = format!("{}{}", &field[..1], &lower[1..]);
+        for spelling in &[lower.clone(), capitalized] {
+            one_statement_parses_to(&format!("SELECT INTERVAL '1'  {}", spelling), &canonical);
+        }
+    }
+    one_statement_parses_to(
+        "SELECT INTERVAL '1-2' year to Month",
+        "SELECT INTERVAL '1-2' YEAR TO MONTH",
+    );
+    one_statement_parses_to("SELECT INTERVAL '3 Days'", "SELECT INTERVAL '3' DAY");
+}
+
+#[test]
+fn parse_literal_interval_keeps_leading_zeros() {
+    // the value is printed back as it was written, padding and all
+    let select = verified_only_select("SELECT INTERVAL '01-02' YEAR TO MONTH");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Value(Value::Interval(iv)) => {
+            assert_eq!(iv.value, "01-02");
+            assert_eq!((iv.parsed.year, iv.parsed.month), (Some(1), Some(2)));
+        }
+        v => panic!("expected an interval, found {:?}", v),
+    }
+    verified_only_select("SELECT INTERVAL '00:00:09' HOUR TO SECOND");
+}
+
+#[test]
+fn parse_literal_interval_public_surface() {
+    // a tool outside of the crate can read both the parsed value and the
+    // tokens it was built from
+    let select = verified_only_select("SELECT INTERVAL '-1 2:03' DAY TO MINUTE");
+    let iv = match expr_from_projection(only(&select.projection)) {
+        Expr::Value(Value::Interval(iv)) => iv,
+        v => panic!("expected an interval, found {:?}", v),
+    };
+    assert_eq!(
+        (iv.parsed.day, iv.parsed.hour, iv.parsed.minute),
+        (Some(1), Some(2), Some(3))
+    );
+    assert_eq!(iv.parsed.positivity(), -1);
+    assert_eq!(
+        iv.parsed.fields_set(),
+        vec![DateTimeField::Day, DateTimeField::Hour, DateTimeField::Minute]
+    );
+    assert_eq!(
+        Parser::debug_tokenize_interval(&iv.value).unwrap(),
+        vec![
+            IntervalToken::Dash,
+            IntervalToken::Num(1),
+            IntervalToken::Space,
+            IntervalToken::Num(2),
+            IntervalToken::Colon,
+            IntervalToken::Num(3),
+        ]
+    );
+}
+
+#[test]
+fn parse_literal_interval_matches_parse_interval_string() {
+    use DateTimeField::*;
+
+    for (value, field) in &[
+        ("1-2", Year),
+        ("-1 2:03:04.5", Day),
+        ("1:30", Hour),
+        ("90", Second),
+        ("-0", Minute),
+        (".5", Second),
+        ("P1DT2H", Day),
+    ] {
+        let select = verified_only_select(&format!("SELECT INTERVAL '{}' {}", value, field));
+        match expr_from_projection(only(&select.projection)) {
+            Expr::Value(Value::Interval(iv)) => assert_eq!(
+                iv.parsed,
+                Parser::parse_interval_string(value, field).unwrap(),
+                "{} {}",
+                value,
+                field
+            ),
+            v => panic!("expected an interval, found {:?}", v),
+        }
+    }
+}
+
+#[test]
+fn parse_literal_interval_to_qualified_sql() {
+    use DateTimeField::*;
+
+    for (value, leading, trailing, sql) in &[
+        (
+            "1 2:3:4.50",
+            Day,
+            Some(Second),
+            "INTERVAL '1 2:03:04.50' DAY TO SECOND",
+        ),
+        ("-1-2", Year, Some(Month), "INTERVAL '-1-2' YEAR TO MONTH"),
+        ("5:30", Hour, Some(Minute), "INTERVAL '5:30' HOUR TO MINUTE"),
+        ("1:02:03", Hour, None, "INTERVAL '1:02:03' HOUR TO SECOND"),
+        ("90", Minute, None, "INTERVAL '90' MINUTE"),
+        ("-0.25", Second, None, "INTERVAL '-0.25' SECOND"),
+    ] {
+        let options = DateTimeParseOptions {
+            last_field: trailing.clone(),
+            ..Default::default()
+        };
+        let pdt = Parser::parse_interval_string_with_options(value, leading, &options).unwrap();
+        let qualified = pdt.to_qualified_sql(leading.clone(), trailing.clone());
+        assert_eq!(&qualified, sql);
+        let select = verified_only_select(&format!("SELECT {}", qualified));
+        match expr_from_projection(only(&select.projection)) {
+            Expr::Value(Value::Interval(iv)) => assert_eq!(iv.parsed, pdt, "{}", sql),
+            v => panic!("expected an interval, found {:?}", v),
+        }
+    }
+
+    // larger fields are folded into the leading one, smaller ones are left out
+    let pdt = Parser::parse_interval_string("1 2:30", &Day).unwrap();
+    assert_eq!(
+        pdt.to_qualified_sql(Hour, None),
+        "INTERVAL '26:30' HOUR TO MINUTE"
+    );
+    assert_eq!(
+        pdt.to_qualified_sql(Day, Some(Hour)),
+        "INTERVAL '1 2' DAY TO HOUR"
+    );
+    let pdt = Parser::parse_interval_string("1-2", &Year).unwrap();
+    assert_eq!(pdt.to_qualified_sql(Month, None), "INTERVAL '14' MONTH");
+}
+
 #[test]
 fn parse_simple_math_expr_plus() {
     let sql = "SELECT a + b, 2 + a, 2.5 + a, a_f + b_f, 2 + a_f, 2.5 + a_f FROM c";
@@ -97,6 +97,66 @@ fn parse_show_columns() {
     }
 }
 
+#[test]
+fn parse_integer_interval() {
+    let select = mysql().unverified_only_select("SELECT INTERVAL 1 DAY");
+    assert_eq!(
+        expr_from_projection(only(&select.projection)),
+        &Expr::Value(Value::Interval(IntervalValue {
+            value: "1".into(),
+            parsed: ParsedDateTime {
+                day: Some(1),
+                ..Default::default()
+            },
+            leading_field: DateTimeField::Day,
+            leading_precision: None,
+            last_field: None,
+            fractional_seconds_precision: None,
+        }))
+    );
+    mysql().one_statement_parses_to("SELECT INTERVAL -5 HOUR", "SELECT INTERVAL '-5' HOUR");
+    // a bare integer is built from the number token, and has the same value
+    // as the string would
+    for (integer, field) in &[
+        ("5", "HOUR"),
+        ("-5", "DAY"),
+        ("0", "SECOND"),
+        ("-0", "MINUTE"),
+        ("3", "QUARTER"),
+        ("2", "WEEK"),
+        ("12", "YEAR"),
+        ("9223372036854775807", "SECOND"),
+        ("99999999999999999999", "SECOND"),
+    ] {
+        let parse = |sql: String| {
+            mysql()
+                .parse_sql_statements(&sql)
+                .map(|statements| format!("{:?}", statements))
+        };
+        assert_eq!(
+            parse(format!("SELECT INTERVAL {} {}", integer, field)),
+            parse(format!("SELECT INTERVAL '{}' {}", integer, field)),
+            "INTERVAL {} {}",
+            integer,
+            field
+        );
+    }
+    mysql().one_statement_parses_to("SELECT INTERVAL '1:30' HOUR TO MINUTE", "");
+    assert!(mysql()
+        .parse_sql_statements("SELECT INTERVAL 1.5 DAY")
+        .is_err());
+
+    // the unit goes after the value
+    for sql in &[
+        "SELECT INTERVAL '1 day'",
+        "SELECT INTERVAL '1 day' DAY",
+        "SELECT INTERVAL 'P1D' DAY",
+    ] {
+        assert!(mysql().parse_sql_statements(sql).is_err(), "{}", sql);
+        assert!(all_dialects().parse_sql_statements(sql).is_ok(), "{}", sql);
+    }
+}
+
 fn mysql() -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(MySqlDialect {})],
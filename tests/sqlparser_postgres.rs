@@ -434,6 +434,39 @@ fn parse_json_ops() {
     }
 }
 
+#[test]
+fn parse_interval_grammar() {
+    pg().one_statement_parses_to("SELECT INTERVAL '1 day'", "SELECT INTERVAL '1' DAY");
+    pg().one_statement_parses_to("SELECT INTERVAL 'P1D' DAY", "");
+    // a value with its units in it needs no qualifier, and is shown with
+    // the fields it sets
+    pg_and_generic().one_statement_parses_to(
+        "SELECT INTERVAL '1 hour 30 minutes'",
+        "SELECT INTERVAL '1 hour 30 minutes' HOUR TO MINUTE",
+    );
+    pg_and_generic().one_statement_parses_to("SELECT INTERVAL 'P1D'", "SELECT INTERVAL 'P1D' DAY");
+    pg_and_generic().one_statement_parses_to(
+        "SELECT INTERVAL '1 year 2 days'",
+        "SELECT INTERVAL '1 year 2 days' YEAR TO DAY",
+    );
+    match pg().verified_expr("INTERVAL '1 hour 30 minutes' HOUR TO MINUTE") {
+        Expr::Value(Value::Interval(interval)) => assert_eq!(
+            (interval.parsed.hour, interval.parsed.minute),
+            (Some(1), Some(30))
+        ),
+        other => panic!("expected an interval, found {:?}", other),
+    }
+    // but other values still do
+    assert!(pg().parse_sql_statements("SELECT INTERVAL '1:30'").is_err());
+    // the value must be quoted
+    assert_eq!(
+        pg().parse_sql_statements("SELECT INTERVAL 1 DAY"),
+        Err(ParserError::ParserError(
+            "Expected literal string, found: 1".to_string()
+        ))
+    );
+}
+
 fn pg() -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(PostgreSqlDialect {})],